@@ -1,6 +1,7 @@
+use crate::components::form::use_form_control;
 use crate::use_unique_id;
 use dioxus_lib::prelude::*;
-use lucide_dioxus::Check;
+use lucide_dioxus::{Check, Minus};
 
 /// Checkbox size options
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
@@ -16,21 +17,73 @@ impl Default for CheckboxSize {
     }
 }
 
+// The classes of the checkbox box and its icon wrapper, shared with other components drawing a checkbox
+pub(crate) fn checkbox_classes(
+    size: CheckboxSize,
+    filled: bool,
+    disabled: bool,
+) -> (String, &'static str) {
+    // Determine size-specific classes
+    let (size_class, icon_size) = match size {
+        CheckboxSize::Small => ("h-4 w-4", "h-3 w-3"),
+        CheckboxSize::Medium => ("h-5 w-5", "h-4 w-4"),
+        CheckboxSize::Large => ("h-6 w-6", "h-5 w-5"),
+    };
+
+    // Build checkbox wrapper classes
+    let checkbox_class = format!(
+        "inline-flex shrink-0 items-center justify-center rounded border-2 transition-colors focus:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 {} {} {}",
+        size_class,
+        if filled {
+            "bg-primary border-primary"
+        } else {
+            "bg-background border-input hover:bg-accent/10"
+        },
+        if disabled {
+            "cursor-not-allowed opacity-50"
+        } else {
+            "cursor-pointer"
+        }
+    );
+    (checkbox_class, icon_size)
+}
+
 /// Props for the Checkbox component
 #[derive(Props, Clone, PartialEq)]
 pub struct CheckboxProps {
-    /// Whether the checkbox is checked
-    #[props(default = Signal::new(false))]
-    pub checked: Signal<bool>,
+    /// Whether the checkbox is checked. The checkbox updates this signal when toggled.
+    /// Leave unset to let the checkbox manage its own state.
+    #[props(default)]
+    pub checked: Option<Signal<bool>>,
+
+    /// Initial checked state when `checked` is not provided
+    #[props(default)]
+    pub default_checked: bool,
+
+    /// Whether the checkbox is in the mixed state, e.g. a "select all" header with some items selected
+    #[props(default)]
+    pub indeterminate: ReadOnlySignal<bool>,
 
     /// Callback for when the checkbox is toggled
     #[props(default)]
     pub on_checked_change: Option<EventHandler<bool>>,
 
+    /// Value submitted with the form, and the value of this checkbox inside a CheckboxGroup
+    #[props(default)]
+    pub value: Option<String>,
+
+    /// Inside a CheckboxGroup, check or clear every item and derive the state from them
+    #[props(default)]
+    pub select_all: bool,
+
     /// Whether the checkbox is disabled
     #[props(default)]
     pub disabled: bool,
 
+    /// Whether the checkbox must be checked to submit the form
+    #[props(default)]
+    pub required: bool,
+
     /// Size of the checkbox
     #[props(default)]
     pub size: CheckboxSize,
@@ -55,59 +108,128 @@ pub struct CheckboxProps {
     pub attributes: Vec<Attribute>,
 }
 
-/// A styled checkbox component that can be toggled on or off
+/// A styled checkbox component that can be toggled on or off.
+/// It toggles on click, Space and clicks on a `Label` whose `for_id` matches its id.
 #[component]
 pub fn Checkbox(props: CheckboxProps) -> Element {
+    // Take the id, name and ARIA state from the surrounding FormControl, if any
+    let control = use_form_control();
+
     // Generate unique ID if not provided
     let checkbox_id = use_unique_id();
-    let id = props.id.clone().unwrap_or_else(|| checkbox_id());
+    let id = props
+        .id
+        .clone()
+        .or_else(|| control.map(|control| control.control_id()))
+        .unwrap_or_else(|| checkbox_id());
+    let name = props
+        .name
+        .clone()
+        .or_else(|| control.and_then(|control| control.name()));
+    let is_invalid = control.is_some_and(|control| control.is_invalid());
+    let is_required = props.required || control.is_some_and(|control| control.is_required());
 
-    // Determine size-specific classes
-    let (size_class, icon_size) = match props.size {
-        CheckboxSize::Small => ("h-4 w-4", "h-3 w-3"),
-        CheckboxSize::Medium => ("h-5 w-5", "h-4 w-4"),
-        CheckboxSize::Large => ("h-6 w-6", "h-5 w-5"),
-    };
+    // Use the provided signal when controlled, otherwise keep the state internally
+    let internal_checked = use_signal(|| props.default_checked);
+    let mut checked = props.checked.unwrap_or(internal_checked);
 
-    // Build checkbox wrapper classes
-    let checkbox_class = format!(
-        "inline-flex items-center justify-center rounded border-2 transition-colors {} {} {}",
-        size_class,
-        if (props.checked)() {
-            "bg-primary border-primary"
-        } else {
-            "bg-background border-input hover:bg-accent/10"
-        },
-        if props.disabled {
-            "cursor-not-allowed opacity-50"
-        } else {
-            "cursor-pointer"
+    // Inside a CheckboxGroup, checkboxes with a value read and toggle the group selection.
+    // A "select all" checkbox is not an item of the group, even if it was given a value.
+    let group = try_use_context::<CheckboxGroupContext>();
+    let group_value = props
+        .value
+        .clone()
+        .filter(|_| group.is_some() && !props.select_all);
+
+    // Register the value so "select all" checkboxes know every item of the group
+    let registered_value = group_value.clone();
+    use_effect(move || {
+        if let (Some(group), Some(value)) = (group, &registered_value) {
+            group.register(value);
         }
-    );
+    });
+    let unregistered_value = group_value.clone();
+    use_drop(move || {
+        if let (Some(group), Some(value)) = (group, &unregistered_value) {
+            group.unregister(value);
+        }
+    });
+
+    let (is_checked, is_indeterminate) = match (group, &group_value) {
+        (Some(group), _) if props.select_all => (group.all_selected(), group.is_indeterminate()),
+        (Some(group), Some(value)) => (group.is_selected(value), false),
+        _ => (checked(), (props.indeterminate)()),
+    };
+    let disabled = props.disabled || group.is_some_and(|group| group.is_disabled());
+
+    // Group items submit under the group's name
+    let name = match (group, &group_value) {
+        (Some(group), Some(_)) => group.name(),
+        _ => name,
+    };
+
+    let (checkbox_class, icon_size) =
+        checkbox_classes(props.size, is_checked || is_indeterminate, disabled);
 
-    // Handle checkbox change
-    let mut checked = props.checked.clone();
+    // Handle checkbox change. Clicks come from the pointer, Space and associated labels.
+    let indeterminate = props.indeterminate;
+    let select_all = props.select_all;
+    let click_value = group_value.clone();
     let on_change = move |_| {
-        if !props.disabled {
-            if let Some(handler) = &props.on_checked_change {
-                let new_state = !checked();
+        if disabled {
+            return;
+        }
+        match (group, &click_value) {
+            (Some(group), _) if select_all => group.toggle_all(),
+            (Some(group), Some(value)) => group.toggle(value),
+            _ => {
+                // A mixed checkbox becomes checked when clicked
+                let new_state = indeterminate() || !checked();
                 checked.set(new_state);
-                handler.call(new_state);
+                if let Some(handler) = &props.on_checked_change {
+                    handler.call(new_state);
+                }
             }
         }
     };
 
+    // Checkboxes toggle on Space only, so keep Enter from activating the button
+    let on_key_down = move |event: KeyboardEvent| {
+        if event.key() == Key::Enter {
+            event.prevent_default();
+        }
+    };
+
     rsx! {
-        div {
+        button {
+            type: "button",
             class: checkbox_class,
             role: "checkbox",
-            "aria-checked": (props.checked)().to_string(),
+            "aria-checked": if is_indeterminate { "mixed".to_string() } else { is_checked.to_string() },
+            aria_label: props.aria_label.clone(),
+            aria_labelledby: control
+                .filter(|_| props.aria_label.is_none())
+                .map(|control| control.label_id()),
+            aria_describedby: control.and_then(|control| control.describedby()),
+            aria_invalid: is_invalid.then_some("true"),
+            aria_required: is_required.then_some("true"),
+            "data-state": if is_indeterminate { "indeterminate" } else if is_checked { "checked" } else { "unchecked" },
             id: id.clone(),
+            disabled: disabled,
             onclick: on_change,
-            tabindex: if !props.disabled { "0" } else { "-1" },
+            onkeydown: on_key_down,
+            ..props.attributes,
 
-            // Render indicator when checked
-            if (props.checked)() {
+            // Render indicator when checked or mixed
+            if is_indeterminate {
+                div {
+                    class: format!("flex items-center justify-center {}", icon_size),
+
+                    Minus {
+                        class: "text-primary-foreground"
+                    }
+                }
+            } else if is_checked {
                 div {
                     class: format!("flex items-center justify-center {}", icon_size),
 
@@ -116,18 +238,183 @@ pub fn Checkbox(props: CheckboxProps) -> Element {
                     }
                 }
             }
+        }
+
+        // Hidden input that mirrors the state for native form submission and validation
+        input {
+            type: "checkbox",
+            id: format!("{}-input", id),
+            name: name,
+            value: props.value.clone(),
+            checked: is_checked,
+            disabled: disabled,
+            required: is_required,
+            tabindex: "-1",
+            aria_hidden: "true",
+            class: "sr-only",
+            // The visible checkbox owns the interaction
+            onclick: move |event: MouseEvent| event.prevent_default(),
+        }
+    }
+}
+
+/// Selection state shared by the checkboxes of a CheckboxGroup
+#[derive(Clone, Copy, PartialEq)]
+pub struct CheckboxGroupContext {
+    selected: Signal<Vec<String>>,
+    items: Signal<Vec<String>>,
+    name: ReadOnlySignal<Option<String>>,
+    disabled: ReadOnlySignal<bool>,
+    on_value_change: Option<EventHandler<Vec<String>>>,
+}
+
+impl CheckboxGroupContext {
+    fn register(&self, value: &str) {
+        let mut items = self.items;
+        if !items.peek().iter().any(|item| item == value) {
+            items.write().push(value.to_string());
+        }
+    }
+
+    fn unregister(&self, value: &str) {
+        let mut items = self.items;
+        if let Ok(mut items) = items.try_write() {
+            items.retain(|item| item != value);
+        }
+    }
+
+    fn name(&self) -> Option<String> {
+        (self.name)()
+    }
 
-            // Hidden input for form submission
-            if let Some(name) = &props.name {
-                input {
-                    type: "checkbox",
-                    id: format!("{}-input", id),
-                    name: name.clone(),
-                    checked: (props.checked)(),
-                    disabled: props.disabled,
-                    class: "sr-only",
+    fn is_disabled(&self) -> bool {
+        (self.disabled)()
+    }
+
+    /// The currently selected values
+    pub fn selected(&self) -> Vec<String> {
+        (self.selected)()
+    }
+
+    /// Whether a value is selected
+    pub fn is_selected(&self, value: &str) -> bool {
+        self.selected
+            .read()
+            .iter()
+            .any(|selected| selected == value)
+    }
+
+    /// Whether every checkbox in the group is selected
+    pub fn all_selected(&self) -> bool {
+        let items = self.items.read();
+        !items.is_empty() && items.iter().all(|item| self.is_selected(item))
+    }
+
+    /// Whether some, but not all, checkboxes in the group are selected
+    pub fn is_indeterminate(&self) -> bool {
+        let items = self.items.read();
+        items.iter().any(|item| self.is_selected(item)) && !self.all_selected()
+    }
+
+    fn set_selected(&self, next: Vec<String>) {
+        let mut selected = self.selected;
+        selected.set(next.clone());
+        if let Some(handler) = &self.on_value_change {
+            handler.call(next);
+        }
+    }
+
+    /// Select or deselect a single value
+    pub fn toggle(&self, value: &str) {
+        let mut next = self.selected.peek().clone();
+        if let Some(index) = next.iter().position(|selected| selected == value) {
+            next.remove(index);
+        } else {
+            next.push(value.to_string());
+        }
+        self.set_selected(next);
+    }
+
+    /// Select every value, or clear them all if they are already selected
+    pub fn toggle_all(&self) {
+        let items = self.items.peek().clone();
+        let mut next = self.selected.peek().clone();
+        if self.all_selected() {
+            next.retain(|selected| !items.contains(selected));
+        } else {
+            for item in items {
+                if !next.contains(&item) {
+                    next.push(item);
                 }
             }
         }
+        self.set_selected(next);
+    }
+}
+
+/// Props for the CheckboxGroup component
+#[derive(Props, Clone, PartialEq)]
+pub struct CheckboxGroupProps {
+    /// The selected values. The group updates this signal when a checkbox is toggled.
+    /// Leave unset to let the group manage its own selection.
+    #[props(default)]
+    pub value: Option<Signal<Vec<String>>>,
+
+    /// Initially selected values when `value` is not provided
+    #[props(default)]
+    pub default_value: Vec<String>,
+
+    /// Callback for when the selection changes
+    #[props(default)]
+    pub on_value_change: Option<EventHandler<Vec<String>>>,
+
+    /// Name attribute shared by every checkbox for form submission
+    #[props(default)]
+    pub name: ReadOnlySignal<Option<String>>,
+
+    /// Whether every checkbox in the group is disabled
+    #[props(default)]
+    pub disabled: ReadOnlySignal<bool>,
+
+    /// Optional additional classes for the group
+    #[props(default)]
+    pub class: Option<String>,
+
+    #[props(extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+
+    pub children: Element,
+}
+
+/// Manages a list of selected values for the checkboxes inside it
+#[component]
+pub fn CheckboxGroup(props: CheckboxGroupProps) -> Element {
+    let items = use_signal(Vec::new);
+
+    // Use the provided signal when controlled, otherwise keep the selection internally
+    let internal_value = use_signal(|| props.default_value.clone());
+    let selected = props.value.unwrap_or(internal_value);
+
+    use_context_provider(|| CheckboxGroupContext {
+        selected,
+        items,
+        name: props.name,
+        disabled: props.disabled,
+        on_value_change: props.on_value_change,
+    });
+
+    let group_classes = vec!["flex flex-col gap-2", props.class.as_deref().unwrap_or("")]
+        .into_iter()
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    rsx! {
+        div {
+            class: group_classes,
+            role: "group",
+            ..props.attributes,
+            {props.children}
+        }
     }
 }
@@ -0,0 +1,575 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Red, green and blue channels between 0 and 255, with alpha between 0 and 1
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Rgba {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: f64,
+}
+
+/// Hue in degrees, saturation and lightness between 0 and 1, with alpha between 0 and 1
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Hsla {
+    pub h: f64,
+    pub s: f64,
+    pub l: f64,
+    pub a: f64,
+}
+
+/// Hue in degrees, saturation and value between 0 and 1, with alpha between 0 and 1
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Hsva {
+    pub h: f64,
+    pub s: f64,
+    pub v: f64,
+    pub a: f64,
+}
+
+/// The error returned when text is not a color
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParseColorError(String);
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\" is not a valid color", self.0)
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
+/// A color with an alpha channel.
+///
+/// The color is kept as hue, saturation and value, so the hue survives when a color picker
+/// passes through grays, and converts to RGB, HSL, hex and CSS on demand.
+///
+/// ```
+/// use lumen_blocks::components::color::Color;
+///
+/// let color: Color = "#3b82f6".parse().unwrap();
+/// assert_eq!(color.to_hex(), "#3b82f6");
+/// assert_eq!(color.to_css_rgb(), "rgb(59, 130, 246)");
+/// assert_eq!(color.to_css_variable(), "59 130 246");
+///
+/// let faded = color.with_alpha(0.5);
+/// assert_eq!(faded.to_hex(), "#3b82f680");
+/// assert_eq!("rgba(59, 130, 246, 0.5)".parse::<Color>().unwrap().to_rgba(), faded.to_rgba());
+///
+/// let red = Color::from_hsl(0.0, 1.0, 0.5);
+/// assert_eq!(red.to_hex(), "#ff0000");
+/// assert_eq!("hsl(0, 100%, 50%)".parse::<Color>().unwrap().to_hex(), "#ff0000");
+/// assert!("#12345".parse::<Color>().is_err());
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Color {
+    hue: f64,
+    saturation: f64,
+    value: f64,
+    alpha: f64,
+}
+
+impl Default for Color {
+    fn default() -> Self {
+        Self::BLACK
+    }
+}
+
+impl Color {
+    pub const BLACK: Color = Color {
+        hue: 0.0,
+        saturation: 0.0,
+        value: 0.0,
+        alpha: 1.0,
+    };
+
+    pub const WHITE: Color = Color {
+        hue: 0.0,
+        saturation: 0.0,
+        value: 1.0,
+        alpha: 1.0,
+    };
+
+    /// An opaque color from red, green and blue channels
+    pub fn from_rgb(r: u8, g: u8, b: u8) -> Self {
+        Self::from_rgba(Rgba { r, g, b, a: 1.0 })
+    }
+
+    /// A color from RGBA channels
+    pub fn from_rgba(rgba: Rgba) -> Self {
+        let r = rgba.r as f64 / 255.0;
+        let g = rgba.g as f64 / 255.0;
+        let b = rgba.b as f64 / 255.0;
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+
+        let hue = if delta == 0.0 {
+            0.0
+        } else if max == r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+        let saturation = if max == 0.0 { 0.0 } else { delta / max };
+
+        Self::from_hsva(Hsva {
+            h: hue,
+            s: saturation,
+            v: max,
+            a: rgba.a,
+        })
+    }
+
+    /// An opaque color from hue in degrees, saturation and lightness between 0 and 1
+    pub fn from_hsl(h: f64, s: f64, l: f64) -> Self {
+        Self::from_hsla(Hsla { h, s, l, a: 1.0 })
+    }
+
+    /// A color from HSLA channels
+    pub fn from_hsla(hsla: Hsla) -> Self {
+        let s = hsla.s.clamp(0.0, 1.0);
+        let l = hsla.l.clamp(0.0, 1.0);
+        let v = l + s * l.min(1.0 - l);
+        let sv = if v == 0.0 { 0.0 } else { 2.0 * (1.0 - l / v) };
+        Self::from_hsva(Hsva {
+            h: hsla.h,
+            s: sv,
+            v,
+            a: hsla.a,
+        })
+    }
+
+    /// A color from HSVA channels
+    pub fn from_hsva(hsva: Hsva) -> Self {
+        Self {
+            hue: hsva.h.rem_euclid(360.0),
+            saturation: hsva.s.clamp(0.0, 1.0),
+            value: hsva.v.clamp(0.0, 1.0),
+            alpha: hsva.a.clamp(0.0, 1.0),
+        }
+    }
+
+    /// The hue in degrees, from 0 up to 360
+    pub fn hue(&self) -> f64 {
+        self.hue
+    }
+
+    /// The HSV saturation between 0 and 1
+    pub fn saturation(&self) -> f64 {
+        self.saturation
+    }
+
+    /// The HSV value (brightness) between 0 and 1
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// The alpha between 0 (transparent) and 1 (opaque)
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// The same color with another hue
+    pub fn with_hue(self, hue: f64) -> Self {
+        Self::from_hsva(Hsva {
+            h: hue,
+            ..self.to_hsva()
+        })
+    }
+
+    /// The same hue with another HSV saturation and value
+    pub fn with_saturation_value(self, saturation: f64, value: f64) -> Self {
+        Self::from_hsva(Hsva {
+            s: saturation,
+            v: value,
+            ..self.to_hsva()
+        })
+    }
+
+    /// The same color with another alpha
+    pub fn with_alpha(self, alpha: f64) -> Self {
+        Self::from_hsva(Hsva {
+            a: alpha,
+            ..self.to_hsva()
+        })
+    }
+
+    /// The HSVA channels
+    pub fn to_hsva(&self) -> Hsva {
+        Hsva {
+            h: self.hue,
+            s: self.saturation,
+            v: self.value,
+            a: self.alpha,
+        }
+    }
+
+    /// The RGBA channels, rounded to whole numbers
+    pub fn to_rgba(&self) -> Rgba {
+        let c = self.value * self.saturation;
+        let sector = self.hue / 60.0;
+        let x = c * (1.0 - (sector.rem_euclid(2.0) - 1.0).abs());
+        let (r, g, b) = match sector as u32 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+        let m = self.value - c;
+        let channel = |value: f64| ((value + m) * 255.0).round().clamp(0.0, 255.0) as u8;
+        Rgba {
+            r: channel(r),
+            g: channel(g),
+            b: channel(b),
+            a: self.alpha,
+        }
+    }
+
+    /// The HSLA channels
+    pub fn to_hsla(&self) -> Hsla {
+        let l = self.value * (1.0 - self.saturation / 2.0);
+        let s = if l == 0.0 || l == 1.0 {
+            0.0
+        } else {
+            (self.value - l) / l.min(1.0 - l)
+        };
+        Hsla {
+            h: self.hue,
+            s,
+            l,
+            a: self.alpha,
+        }
+    }
+
+    /// Hex notation, e.g. `#3b82f6`, with a fourth byte for alpha when the color is translucent
+    pub fn to_hex(&self) -> String {
+        let Rgba { r, g, b, a } = self.to_rgba();
+        if a < 1.0 {
+            let alpha = (a * 255.0).round() as u8;
+            format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, alpha)
+        } else {
+            format!("#{:02x}{:02x}{:02x}", r, g, b)
+        }
+    }
+
+    /// CSS `rgb()` notation, or `rgba()` when the color is translucent
+    pub fn to_css_rgb(&self) -> String {
+        let Rgba { r, g, b, a } = self.to_rgba();
+        if a < 1.0 {
+            format!("rgba({}, {}, {}, {})", r, g, b, round_alpha(a))
+        } else {
+            format!("rgb({}, {}, {})", r, g, b)
+        }
+    }
+
+    /// CSS `hsl()` notation, or `hsla()` when the color is translucent
+    pub fn to_css_hsl(&self) -> String {
+        let Hsla { h, s, l, a } = self.to_hsla();
+        let (h, s, l) = (h.round(), (s * 100.0).round(), (l * 100.0).round());
+        if a < 1.0 {
+            format!("hsla({}, {}%, {}%, {})", h, s, l, round_alpha(a))
+        } else {
+            format!("hsl({}, {}%, {}%)", h, s, l)
+        }
+    }
+
+    /// The space separated channels used by the theme's CSS variables, e.g. `23 23 23`
+    /// for `--primary`. Alpha is left out.
+    pub fn to_css_variable(&self) -> String {
+        let Rgba { r, g, b, .. } = self.to_rgba();
+        format!("{} {} {}", r, g, b)
+    }
+
+    /// The WCAG relative luminance, between 0 for black and 1 for white
+    pub fn relative_luminance(&self) -> f64 {
+        let Rgba { r, g, b, .. } = self.to_rgba();
+        let linear = |channel: u8| {
+            let channel = channel as f64 / 255.0;
+            if channel <= 0.04045 {
+                channel / 12.92
+            } else {
+                ((channel + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b)
+    }
+
+    /// The WCAG contrast ratio with another color, between 1 and 21
+    pub fn contrast_ratio(&self, other: &Color) -> f64 {
+        let a = self.relative_luminance();
+        let b = other.relative_luminance();
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// Whether white text is more legible than black text on this color
+    pub fn is_dark(&self) -> bool {
+        self.contrast_ratio(&Color::WHITE) > self.contrast_ratio(&Color::BLACK)
+    }
+}
+
+fn round_alpha(alpha: f64) -> f64 {
+    (alpha * 100.0).round() / 100.0
+}
+
+fn parse_hex(hex: &str) -> Option<Rgba> {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let digits = match hex.len() {
+        // Short notation repeats every digit, e.g. `#f80` is `#ff8800`
+        3 | 4 => hex.chars().flat_map(|c| [c, c]).collect::<String>(),
+        6 | 8 => hex.to_string(),
+        _ => return None,
+    };
+    let byte = |index: usize| u8::from_str_radix(&digits[index * 2..index * 2 + 2], 16).ok();
+    Some(Rgba {
+        r: byte(0)?,
+        g: byte(1)?,
+        b: byte(2)?,
+        a: match digits.len() {
+            8 => byte(3)? as f64 / 255.0,
+            _ => 1.0,
+        },
+    })
+}
+
+// A number, or a percentage scaled to `percent_of`
+fn parse_number(text: &str, percent_of: f64) -> Option<f64> {
+    match text.strip_suffix('%') {
+        Some(percent) => percent
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .map(|value| value / 100.0 * percent_of),
+        None => text.parse::<f64>().ok(),
+    }
+    .filter(|value| value.is_finite())
+}
+
+fn parse_alpha(text: Option<&&str>) -> Option<f64> {
+    match text {
+        Some(text) => parse_number(text, 1.0),
+        None => Some(1.0),
+    }
+}
+
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    /// Parse hex (`#rgb`, `#rrggbb`, with optional alpha), `rgb()`, `rgba()`, `hsl()`, `hsla()`
+    /// or the space separated channels of a theme variable, e.g. `23 23 23`
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let error = || ParseColorError(text.to_string());
+        let trimmed = text.trim().to_lowercase();
+
+        if let Some(hex) = trimmed.strip_prefix('#') {
+            return parse_hex(hex).map(Self::from_rgba).ok_or_else(error);
+        }
+
+        let (function, arguments) = match trimmed.split_once('(') {
+            Some((function, rest)) => (function.trim(), rest.strip_suffix(')').ok_or_else(error)?),
+            None => ("", trimmed.as_str()),
+        };
+        let parts = arguments
+            .split([',', ' ', '/'])
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>();
+
+        // Space separated channels without a function are a theme variable, e.g. `23 23 23`
+        let is_rgb = (matches!(function, "rgb" | "rgba") && (3..=4).contains(&parts.len()))
+            || (function.is_empty() && parts.len() == 3);
+
+        match function {
+            _ if is_rgb => {
+                let channel = |part: &str| {
+                    parse_number(part, 255.0)
+                        .filter(|value| (0.0..=255.0).contains(value))
+                        .map(|value| value.round() as u8)
+                };
+                Ok(Self::from_rgba(Rgba {
+                    r: channel(parts[0]).ok_or_else(error)?,
+                    g: channel(parts[1]).ok_or_else(error)?,
+                    b: channel(parts[2]).ok_or_else(error)?,
+                    a: parse_alpha(parts.get(3)).ok_or_else(error)?,
+                }))
+            }
+            "hsl" | "hsla" if (3..=4).contains(&parts.len()) => {
+                let hue = parts[0]
+                    .strip_suffix("deg")
+                    .unwrap_or(parts[0])
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|hue| hue.is_finite())
+                    .ok_or_else(error)?;
+                let percent = |part: &str| {
+                    part.strip_suffix('%')
+                        .unwrap_or(part)
+                        .parse::<f64>()
+                        .ok()
+                        .filter(|value| (0.0..=100.0).contains(value))
+                        .map(|value| value / 100.0)
+                };
+                Ok(Self::from_hsla(Hsla {
+                    h: hue,
+                    s: percent(parts[1]).ok_or_else(error)?,
+                    l: percent(parts[2]).ok_or_else(error)?,
+                    a: parse_alpha(parts.get(3)).ok_or_else(error)?,
+                }))
+            }
+            // Hex without `#`, as typed into a hex field
+            "" if parts.len() == 1 => parse_hex(parts[0]).map(Self::from_rgba).ok_or_else(error),
+            _ => Err(error()),
+        }
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_hex())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str) -> Color {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn round_trips_hex() {
+        for hex in [
+            "#000000",
+            "#ffffff",
+            "#3b82f6",
+            "#facc15",
+            "#7f1d1d",
+            "#3b82f680",
+        ] {
+            assert_eq!(parse(hex).to_hex(), hex);
+        }
+        assert_eq!(parse("#abc").to_hex(), "#aabbcc");
+        assert_eq!(parse("#abcd").to_hex(), "#aabbccdd");
+        assert_eq!(parse("ABCDEF").to_hex(), "#abcdef");
+    }
+
+    #[test]
+    fn round_trips_rgb() {
+        for hex in ["#000000", "#3b82f6", "#facc15", "#3b82f680"] {
+            let color = parse(hex);
+            assert_eq!(parse(&color.to_css_rgb()).to_hex(), hex);
+        }
+        assert_eq!(parse("rgb(59, 130, 246)").to_hex(), "#3b82f6");
+        assert_eq!(parse("rgb(100%, 0%, 0%)").to_hex(), "#ff0000");
+        assert_eq!(parse("59 130 246").to_css_variable(), "59 130 246");
+    }
+
+    #[test]
+    fn round_trips_hsl() {
+        for hex in ["#000000", "#ffffff", "#ff0000", "#00ff00", "#ff8000"] {
+            assert_eq!(parse(&parse(hex).to_css_hsl()).to_hex(), hex);
+        }
+        assert_eq!(parse("#ff0000").to_css_hsl(), "hsl(0, 100%, 50%)");
+        assert_eq!(parse("hsl(120deg 100% 50%)").to_hex(), "#00ff00");
+        // Hues wrap around the circle
+        assert_eq!(parse("hsl(-120, 100%, 50%)").to_hex(), "#0000ff");
+        assert_eq!(parse("hsl(480, 100%, 50%)").to_hex(), "#00ff00");
+    }
+
+    #[test]
+    fn round_trips_hsv() {
+        let hsva = Hsva {
+            h: 210.0,
+            s: 0.5,
+            v: 0.8,
+            a: 0.25,
+        };
+        assert_eq!(Color::from_hsva(hsva).to_hsva(), hsva);
+        assert_eq!(
+            Color::from_rgb(255, 0, 0).to_hsva(),
+            Hsva {
+                h: 0.0,
+                s: 1.0,
+                v: 1.0,
+                a: 1.0,
+            }
+        );
+        // The hue survives passing through gray
+        let gray = Color::from_hsva(hsva).with_saturation_value(0.0, 0.5);
+        assert_eq!(gray.hue(), 210.0);
+        assert_eq!(gray.to_hex(), "#80808040");
+    }
+
+    #[test]
+    fn parses_alpha() {
+        assert_eq!(parse("rgba(59, 130, 246, 0.5)").alpha(), 0.5);
+        assert_eq!(parse("rgb(59 130 246 / 50%)").alpha(), 0.5);
+        assert_eq!(parse("hsla(0, 100%, 50%, 0.25)").alpha(), 0.25);
+        assert_eq!(parse("#ff000080").to_css_rgb(), "rgba(255, 0, 0, 0.5)");
+        assert_eq!(parse("#ff000080").to_css_hsl(), "hsla(0, 100%, 50%, 0.5)");
+        // Alpha outside 0 to 1 is clamped
+        assert_eq!(parse("rgba(0, 0, 0, 2)").alpha(), 1.0);
+        assert_eq!(Color::BLACK.with_alpha(-1.0).alpha(), 0.0);
+    }
+
+    #[test]
+    fn rejects_out_of_range_channels() {
+        for text in [
+            "rgb(256, 0, 0)",
+            "rgb(-1, 0, 0)",
+            "rgb(101%, 0%, 0%)",
+            "rgb(nan, 0, 0)",
+            "hsl(0, 101%, 50%)",
+            "hsl(0, 50%, -1%)",
+            "hsl(nan, 50%, 50%)",
+            "hsl(inf, 50%, 50%)",
+        ] {
+            assert!(text.parse::<Color>().is_err(), "{text} should not parse");
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_text() {
+        for text in [
+            "",
+            "#12345",
+            "#ggg",
+            "rgb(1, 2)",
+            "rgb(1, 2, 3",
+            "cmyk(0, 0, 0, 0)",
+            "blue",
+        ] {
+            assert!(text.parse::<Color>().is_err(), "{text} should not parse");
+        }
+        assert_eq!(
+            "blue".parse::<Color>().unwrap_err().to_string(),
+            "\"blue\" is not a valid color"
+        );
+    }
+
+    #[test]
+    fn computes_contrast() {
+        assert_eq!(Color::BLACK.contrast_ratio(&Color::WHITE), 21.0);
+        assert_eq!(Color::WHITE.contrast_ratio(&Color::BLACK), 21.0);
+        assert_eq!(parse("#3b82f6").contrast_ratio(&parse("#3b82f6")), 1.0);
+        // The lightest gray that meets WCAG AA on white
+        let ratio = parse("#767676").contrast_ratio(&Color::WHITE);
+        assert!((ratio - 4.54).abs() < 0.01, "{ratio}");
+    }
+
+    #[test]
+    fn detects_dark_colors() {
+        assert!(Color::BLACK.is_dark());
+        assert!(!Color::WHITE.is_dark());
+        assert!(parse("#1e3a8a").is_dark());
+        assert!(!parse("#facc15").is_dark());
+    }
+}
@@ -0,0 +1,629 @@
+use crate::components::color::{Color, Hsla, Rgba};
+use crate::components::form::use_form_control;
+use crate::components::input::{Input, InputSize};
+use crate::use_unique_id;
+use dioxus_lib::prelude::*;
+use std::rc::Rc;
+
+/// The text fields shown below a ColorPicker
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ColorFormat {
+    Hex,
+    Rgb,
+    Hsl,
+}
+
+impl Default for ColorFormat {
+    fn default() -> Self {
+        Self::Hex
+    }
+}
+
+impl ColorFormat {
+    fn label(&self) -> &'static str {
+        match self {
+            ColorFormat::Hex => "HEX",
+            ColorFormat::Rgb => "RGB",
+            ColorFormat::Hsl => "HSL",
+        }
+    }
+}
+
+// Drawn behind translucent colors so the alpha is visible
+const CHECKERBOARD: &str =
+    "repeating-conic-gradient(#d4d4d4 0% 25%, #ffffff 0% 50%) 50% / 10px 10px";
+
+const HUE_GRADIENT: &str = "linear-gradient(to right, #ff0000 0%, #ffff00 17%, #00ff00 33%, #00ffff 50%, #0000ff 67%, #ff00ff 83%, #ff0000 100%)";
+
+// Send the rest of a drag to the element, even when the pointer leaves it
+fn capture_pointer(element_id: &str, pointer_id: i32) {
+    let _ = document::eval(&format!(
+        r#"const element = document.getElementById("{}"); if (element) {{ element.setPointerCapture({}); }}"#,
+        element_id, pointer_id
+    ));
+}
+
+// How far an arrow key moves a slider, with Shift and PageUp/PageDown moving ten times as far
+fn key_delta(event: &KeyboardEvent, step: f64) -> Option<f64> {
+    let step = if event.modifiers().contains(Modifiers::SHIFT) {
+        step * 10.0
+    } else {
+        step
+    };
+    match event.key() {
+        Key::ArrowRight | Key::ArrowUp => Some(step),
+        Key::ArrowLeft | Key::ArrowDown => Some(-step),
+        Key::PageUp => Some(step * 10.0),
+        Key::PageDown => Some(-step * 10.0),
+        _ => None,
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ColorPickerProps {
+    /// The current color. The picker updates this signal as the color changes.
+    /// Leave unset to let the picker manage its own state.
+    #[props(default)]
+    value: Option<Signal<Color>>,
+
+    /// Initial color when `value` is not provided
+    #[props(default)]
+    default_value: Color,
+
+    /// Callback with the color when it changes
+    #[props(default)]
+    on_value_change: Option<EventHandler<Color>>,
+
+    /// Whether to show the alpha slider and alpha fields
+    #[props(default = true)]
+    show_alpha: bool,
+
+    /// Preset colors shown as swatches below the fields
+    #[props(default)]
+    swatches: Vec<Color>,
+
+    /// The text fields shown first. The user can switch between hex, RGB and HSL.
+    #[props(default)]
+    format: ColorFormat,
+
+    /// Whether the picker is disabled
+    #[props(default)]
+    disabled: bool,
+
+    /// Name of the hidden input for form submission. The hex value is submitted.
+    #[props(default)]
+    name: String,
+
+    /// Optional ID for the picker
+    #[props(default)]
+    id: Option<String>,
+
+    /// Accessible label for the picker
+    #[props(default)]
+    aria_label: Option<String>,
+
+    /// Optional additional classes for the container
+    #[props(default)]
+    class: Option<String>,
+
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+/// A color picker with a saturation and brightness area, hue and alpha sliders, hex, RGB and HSL
+/// fields and preset swatches
+#[component]
+pub fn ColorPicker(props: ColorPickerProps) -> Element {
+    // Take the id, name and label from the surrounding FormControl, if any
+    let control = use_form_control();
+
+    // Generate unique ID if not provided, the area and sliders capture the pointer through it
+    let picker_id = use_unique_id();
+    let id = props
+        .id
+        .clone()
+        .or_else(|| control.map(|control| control.control_id()))
+        .unwrap_or_else(|| picker_id());
+    let area_id = format!("{}-area", id);
+
+    let name = if props.name.is_empty() {
+        control
+            .and_then(|control| control.name())
+            .unwrap_or_default()
+    } else {
+        props.name.clone()
+    };
+
+    // Use the provided signal when controlled, otherwise keep the color internally
+    let internal_value = use_signal(|| props.default_value);
+    let mut value = props.value.unwrap_or(internal_value);
+    let color = value();
+
+    let on_value_change = props.on_value_change;
+    let set_color = use_callback(move |next: Color| {
+        if *value.peek() == next {
+            return;
+        }
+        value.set(next);
+        if let Some(handler) = &on_value_change {
+            handler.call(next);
+        }
+    });
+
+    let mut format = use_signal(|| props.format);
+    // The field being typed into and its text, shown until the field loses focus
+    let mut editing = use_signal(|| None::<(&'static str, String)>);
+
+    // Saturation runs left to right and brightness bottom to top
+    let mut area_ref: Signal<Option<Rc<MountedData>>> = use_signal(|| None);
+    let mut area_size = use_signal(|| (1.0, 1.0));
+    let mut area_dragging = use_signal(|| false);
+    let move_area = move |x: f64, y: f64| {
+        let (width, height) = *area_size.peek();
+        let current = *value.peek();
+        let saturation = (x / width.max(1.0)).clamp(0.0, 1.0);
+        let brightness = 1.0 - (y / height.max(1.0)).clamp(0.0, 1.0);
+        set_color.call(current.with_saturation_value(saturation, brightness));
+    };
+
+    let disabled = props.disabled;
+    let capture_id = area_id.clone();
+    let handle_area_down = move |event: PointerEvent| {
+        let capture_id = capture_id.clone();
+        async move {
+            if disabled {
+                return;
+            }
+            capture_pointer(&capture_id, event.pointer_id());
+            area_dragging.set(true);
+            if let Some(area) = area_ref()
+                && let Ok(rect) = area.get_client_rect().await
+            {
+                area_size.set((rect.width(), rect.height()));
+            }
+            let point = event.element_coordinates();
+            move_area(point.x, point.y);
+        }
+    };
+
+    let handle_area_key = move |event: KeyboardEvent| {
+        if disabled {
+            return;
+        }
+        let current = *value.peek();
+        let (saturation, brightness) = (current.saturation(), current.value());
+        let delta = key_delta(&event, 0.01);
+        let next = match event.key() {
+            Key::ArrowLeft | Key::ArrowRight => delta.map(|delta| (saturation + delta, brightness)),
+            Key::ArrowUp | Key::ArrowDown | Key::PageUp | Key::PageDown => {
+                delta.map(|delta| (saturation, brightness + delta))
+            }
+            Key::Home => Some((0.0, brightness)),
+            Key::End => Some((1.0, brightness)),
+            _ => None,
+        };
+        if let Some((saturation, brightness)) = next {
+            event.prevent_default();
+            set_color.call(current.with_saturation_value(saturation, brightness));
+        }
+    };
+
+    let Rgba { r, g, b, a } = color.to_rgba();
+    let Hsla { h, s, l, .. } = color.to_hsla();
+    let hue = color.hue();
+    let saturation_percent = (color.saturation() * 100.0).round();
+    let brightness_percent = (color.value() * 100.0).round();
+    let alpha_percent = (a * 100.0).round();
+
+    // Text fields as (key, label, current text, width class)
+    let fields: Vec<(&'static str, &'static str, String, &'static str)> = match format() {
+        ColorFormat::Hex => vec![("hex", "Hex", color.to_hex(), "w-28")],
+        ColorFormat::Rgb => vec![
+            ("r", "Red", r.to_string(), "w-14"),
+            ("g", "Green", g.to_string(), "w-14"),
+            ("b", "Blue", b.to_string(), "w-14"),
+        ],
+        ColorFormat::Hsl => vec![
+            ("h", "Hue", h.round().to_string(), "w-14"),
+            ("s", "Saturation", (s * 100.0).round().to_string(), "w-14"),
+            ("l", "Lightness", (l * 100.0).round().to_string(), "w-14"),
+        ],
+    }
+    .into_iter()
+    .chain(
+        (props.show_alpha && format() != ColorFormat::Hex)
+            .then(|| ("a", "Alpha", alpha_percent.to_string(), "w-14")),
+    )
+    .collect();
+
+    // Apply a field as the user types, ignoring text that is not a color yet
+    let apply_field = move |key: &'static str, text: String| {
+        editing.set(Some((key, text.clone())));
+        let current = *value.peek();
+        let number = text.trim().trim_end_matches('%').parse::<f64>().ok();
+        let rgba = current.to_rgba();
+        let hsla = current.to_hsla();
+        let channel = |number: f64| number.round().clamp(0.0, 255.0) as u8;
+        let next = match key {
+            "hex" => text.parse::<Color>().ok(),
+            "r" => number.map(|n| {
+                Color::from_rgba(Rgba {
+                    r: channel(n),
+                    ..rgba
+                })
+            }),
+            "g" => number.map(|n| {
+                Color::from_rgba(Rgba {
+                    g: channel(n),
+                    ..rgba
+                })
+            }),
+            "b" => number.map(|n| {
+                Color::from_rgba(Rgba {
+                    b: channel(n),
+                    ..rgba
+                })
+            }),
+            "h" => number.map(|n| current.with_hue(n.clamp(0.0, 360.0))),
+            "s" => number.map(|n| {
+                Color::from_hsla(Hsla {
+                    s: n / 100.0,
+                    ..hsla
+                })
+            }),
+            "l" => number.map(|n| {
+                Color::from_hsla(Hsla {
+                    l: n / 100.0,
+                    ..hsla
+                })
+            }),
+            "a" => number.map(|n| current.with_alpha(n / 100.0)),
+            _ => None,
+        };
+        if let Some(next) = next {
+            set_color.call(next);
+        }
+    };
+
+    let current_hex = color.to_hex();
+    let opaque_css = format!("rgb({}, {}, {})", r, g, b);
+    let area_style = format!(
+        "background: linear-gradient(to top, #000000, transparent), linear-gradient(to right, #ffffff, transparent), hsl({}, 100%, 50%); touch-action: none;",
+        hue
+    );
+    let area_thumb_style = format!(
+        "left: {}%; top: {}%; background-color: {};",
+        color.saturation() * 100.0,
+        (1.0 - color.value()) * 100.0,
+        opaque_css
+    );
+    let alpha_track = format!(
+        "linear-gradient(to right, transparent, {}), {}",
+        opaque_css, CHECKERBOARD
+    );
+    let preview_style = format!(
+        "background: linear-gradient({}, {}), {};",
+        color.to_css_rgb(),
+        color.to_css_rgb(),
+        CHECKERBOARD
+    );
+    let area_value_text = format!(
+        "Saturation {}%, brightness {}%",
+        saturation_percent, brightness_percent
+    );
+    let hue_text = format!("{} degrees", hue.round());
+    let alpha_text = format!("{}%", alpha_percent);
+
+    let container_classes = vec![
+        "flex w-64 flex-col gap-3",
+        if props.disabled {
+            "opacity-50 pointer-events-none"
+        } else {
+            ""
+        },
+        props.class.as_deref().unwrap_or(""),
+    ]
+    .into_iter()
+    .filter(|s| !s.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ");
+
+    rsx! {
+        div {
+            id: id.clone(),
+            class: container_classes,
+            role: "group",
+            aria_label: props.aria_label.clone().unwrap_or_else(|| "Color picker".to_string()),
+            aria_disabled: props.disabled.then_some("true"),
+            ..props.attributes,
+
+            // Saturation and brightness
+            div {
+                id: area_id.clone(),
+                class: "relative h-40 w-full cursor-crosshair rounded border border-border focus:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2",
+                style: area_style,
+                tabindex: if props.disabled { "-1" } else { "0" },
+                role: "slider",
+                aria_roledescription: "2D slider",
+                aria_label: "Saturation and brightness",
+                aria_valuemin: "0",
+                aria_valuemax: "100",
+                aria_valuenow: saturation_percent.to_string(),
+                aria_valuetext: area_value_text,
+                onmounted: move |event: MountedEvent| area_ref.set(Some(event.data())),
+                onpointerdown: handle_area_down,
+                onpointermove: move |event: PointerEvent| {
+                    if area_dragging() {
+                        let point = event.element_coordinates();
+                        move_area(point.x, point.y);
+                    }
+                },
+                onpointerup: move |_| area_dragging.set(false),
+                onpointercancel: move |_| area_dragging.set(false),
+                onkeydown: handle_area_key,
+
+                div {
+                    class: "pointer-events-none absolute h-4 w-4 -translate-x-1/2 -translate-y-1/2 rounded-full border-2 border-white shadow-[0_0_0_1px_rgba(0,0,0,0.3)]",
+                    style: area_thumb_style,
+                }
+            }
+
+            div {
+                class: "flex items-center gap-3",
+
+                div {
+                    class: "h-9 w-9 shrink-0 rounded border border-border",
+                    style: preview_style,
+                    aria_hidden: "true",
+                }
+
+                div {
+                    class: "flex flex-1 flex-col gap-2",
+
+                    ColorSlider {
+                        id: format!("{}-hue", id),
+                        label: "Hue".to_string(),
+                        fraction: hue / 360.0,
+                        value_now: hue.round(),
+                        value_max: 360.0,
+                        value_text: hue_text,
+                        track: HUE_GRADIENT.to_string(),
+                        thumb_color: format!("hsl({}, 100%, 50%)", hue),
+                        step: 1.0 / 360.0,
+                        disabled: props.disabled,
+                        on_change: move |fraction: f64| {
+                            // Keep the end of the track at 359 degrees, as 360 wraps to red
+                            let hue = (fraction * 360.0).min(359.0);
+                            set_color.call(value.peek().with_hue(hue));
+                        },
+                    }
+
+                    if props.show_alpha {
+                        ColorSlider {
+                            id: format!("{}-alpha", id),
+                            label: "Alpha".to_string(),
+                            fraction: a,
+                            value_now: alpha_percent,
+                            value_max: 100.0,
+                            value_text: alpha_text,
+                            track: alpha_track,
+                            thumb_color: color.to_css_rgb(),
+                            step: 0.01,
+                            disabled: props.disabled,
+                            on_change: move |fraction: f64| {
+                                set_color.call(value.peek().with_alpha(fraction));
+                            },
+                        }
+                    }
+                }
+            }
+
+            div {
+                class: "flex gap-1",
+                role: "group",
+                aria_label: "Color format",
+                for option in [ColorFormat::Hex, ColorFormat::Rgb, ColorFormat::Hsl] {
+                    {
+                        let label = option.label();
+                        let selected = format() == option;
+                        rsx! {
+                            button {
+                                key: "{label}",
+                                type: "button",
+                                class: if selected {
+                                    "rounded px-1.5 py-1 text-xs font-medium bg-secondary text-secondary-foreground focus:outline-none focus-visible:ring-2 focus-visible:ring-ring"
+                                } else {
+                                    "rounded px-1.5 py-1 text-xs font-medium text-muted-foreground hover:text-foreground focus:outline-none focus-visible:ring-2 focus-visible:ring-ring"
+                                },
+                                aria_pressed: selected.to_string(),
+                                disabled: props.disabled,
+                                onclick: move |_| {
+                                    format.set(option);
+                                    editing.set(None);
+                                },
+                                "{label}"
+                            }
+                        }
+                    }
+                }
+            }
+
+            div {
+                class: "flex gap-2",
+
+                for (key, label, text, width) in fields {
+                    {
+                        let shown = match editing() {
+                            Some((editing_key, draft)) if editing_key == key => draft,
+                            _ => text,
+                        };
+                        rsx! {
+                            Input {
+                                key: "{key}",
+                                id: Some(format!("{}-{}", id, key)),
+                                value: shown,
+                                size: InputSize::Small,
+                                disabled: props.disabled,
+                                aria_label: Some(label.to_string()),
+                                autocomplete: Some("off".to_string()),
+                                class: Some(width.to_string()),
+                                on_input: move |event: FormEvent| apply_field(key, event.value()),
+                                on_blur: move |_| editing.set(None),
+                            }
+                        }
+                    }
+                }
+            }
+
+            if !props.swatches.is_empty() {
+                div {
+                    class: "flex flex-wrap gap-1.5",
+                    role: "group",
+                    aria_label: "Preset colors",
+
+                    for swatch in props.swatches.iter().copied() {
+                        {
+                            let hex = swatch.to_hex();
+                            let selected = hex == current_hex;
+                            let style = format!(
+                                "background: linear-gradient({}, {}), {};",
+                                swatch.to_css_rgb(),
+                                swatch.to_css_rgb(),
+                                CHECKERBOARD
+                            );
+                            rsx! {
+                                button {
+                                    key: "{hex}",
+                                    type: "button",
+                                    class: if selected {
+                                        "h-6 w-6 rounded border border-border ring-2 ring-ring ring-offset-1 focus:outline-none focus-visible:ring-2 focus-visible:ring-ring"
+                                    } else {
+                                        "h-6 w-6 rounded border border-border focus:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-1"
+                                    },
+                                    style: style,
+                                    aria_label: hex.clone(),
+                                    aria_pressed: selected.to_string(),
+                                    disabled: props.disabled,
+                                    onclick: move |_| {
+                                        editing.set(None);
+                                        set_color.call(swatch);
+                                    },
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if !name.is_empty() {
+                input { type: "hidden", name: name.clone(), value: current_hex.clone() }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct ColorSliderProps {
+    id: String,
+    label: String,
+    // Thumb position between 0 and 1
+    fraction: f64,
+    value_now: f64,
+    value_max: f64,
+    value_text: String,
+    track: String,
+    thumb_color: String,
+    // Fraction moved by an arrow key
+    step: f64,
+    disabled: bool,
+    on_change: Callback<f64>,
+}
+
+/// A horizontal slider over a gradient track, used for hue and alpha
+#[component]
+fn ColorSlider(props: ColorSliderProps) -> Element {
+    let mut track_ref: Signal<Option<Rc<MountedData>>> = use_signal(|| None);
+    let mut track_width = use_signal(|| 1.0);
+    let mut dragging = use_signal(|| false);
+
+    let on_change = props.on_change;
+    let move_to = move |x: f64| {
+        on_change.call((x / track_width.peek().max(1.0)).clamp(0.0, 1.0));
+    };
+
+    let disabled = props.disabled;
+    let capture_id = props.id.clone();
+    let handle_down = move |event: PointerEvent| {
+        let capture_id = capture_id.clone();
+        async move {
+            if disabled {
+                return;
+            }
+            capture_pointer(&capture_id, event.pointer_id());
+            dragging.set(true);
+            if let Some(track) = track_ref()
+                && let Ok(rect) = track.get_client_rect().await
+            {
+                track_width.set(rect.width());
+            }
+            move_to(event.element_coordinates().x);
+        }
+    };
+
+    let fraction = props.fraction;
+    let step = props.step;
+    let handle_key = move |event: KeyboardEvent| {
+        if disabled {
+            return;
+        }
+        let next = match event.key() {
+            Key::Home => Some(0.0),
+            Key::End => Some(1.0),
+            _ => key_delta(&event, step).map(|delta| fraction + delta),
+        };
+        if let Some(next) = next {
+            event.prevent_default();
+            on_change.call(next.clamp(0.0, 1.0));
+        }
+    };
+
+    let value_max = props.value_max.to_string();
+    let value_now = props.value_now.to_string();
+    let track_style = format!("background: {}; touch-action: none;", props.track);
+    let thumb_style = format!(
+        "left: {}%; top: 50%; background-color: {};",
+        props.fraction * 100.0,
+        props.thumb_color
+    );
+
+    rsx! {
+        div {
+            id: props.id.clone(),
+            class: "relative h-3 w-full cursor-pointer rounded-full border border-border focus:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2",
+            style: track_style,
+            tabindex: if props.disabled { "-1" } else { "0" },
+            role: "slider",
+            aria_label: props.label.clone(),
+            aria_valuemin: "0",
+            aria_valuemax: value_max,
+            aria_valuenow: value_now,
+            aria_valuetext: props.value_text.clone(),
+            aria_orientation: "horizontal",
+            onmounted: move |event: MountedEvent| track_ref.set(Some(event.data())),
+            onpointerdown: handle_down,
+            onpointermove: move |event: PointerEvent| {
+                if dragging() {
+                    move_to(event.element_coordinates().x);
+                }
+            },
+            onpointerup: move |_| dragging.set(false),
+            onpointercancel: move |_| dragging.set(false),
+            onkeydown: handle_key,
+
+            div {
+                class: "pointer-events-none absolute h-4 w-4 -translate-x-1/2 -translate-y-1/2 rounded-full border-2 border-white shadow-[0_0_0_1px_rgba(0,0,0,0.3)]",
+                style: thumb_style,
+            }
+        }
+    }
+}
@@ -0,0 +1,1374 @@
+use crate::use_unique_id;
+use dioxus_lib::prelude::*;
+use dioxus_time::sleep;
+use lucide_dioxus::GripVertical;
+use std::rc::Rc;
+use std::time::Duration;
+
+/// Where an item sits: its column on a KanbanBoard, always 0 in a Sortable, and its index in it
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct DndPosition {
+    pub column: usize,
+    pub index: usize,
+}
+
+/// Direction in which the items of a Sortable are laid out
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SortableOrientation {
+    Vertical,
+    Horizontal,
+}
+
+impl Default for SortableOrientation {
+    fn default() -> Self {
+        Self::Vertical
+    }
+}
+
+/// Moves the item at `from` so it ends up at `to`, as reported by `on_reorder`
+///
+/// ```
+/// use lumen_blocks::components::dnd::reorder;
+///
+/// let mut items = vec!["a", "b", "c", "d"];
+/// reorder(&mut items, 0, 2);
+/// assert_eq!(items, ["b", "c", "a", "d"]);
+/// reorder(&mut items, 3, 0);
+/// assert_eq!(items, ["d", "b", "c", "a"]);
+/// ```
+pub fn reorder<T>(items: &mut Vec<T>, from: usize, to: usize) {
+    if from >= items.len() || from == to {
+        return;
+    }
+    let item = items.remove(from);
+    let to = to.min(items.len());
+    items.insert(to, item);
+}
+
+/// Moves an item between the columns of a board, as reported by a KanbanBoard's `on_reorder`
+///
+/// ```
+/// use lumen_blocks::components::dnd::{DndPosition, move_item};
+///
+/// let mut columns = vec![vec!["a", "b"], vec!["c"]];
+/// move_item(
+///     &mut columns,
+///     DndPosition { column: 0, index: 0 },
+///     DndPosition { column: 1, index: 1 },
+/// );
+/// assert_eq!(columns, [vec!["b"], vec!["c", "a"]]);
+/// ```
+pub fn move_item<T>(columns: &mut [Vec<T>], from: DndPosition, to: DndPosition) {
+    if from.column == to.column {
+        if let Some(column) = columns.get_mut(from.column) {
+            reorder(column, from.index, to.index);
+        }
+        return;
+    }
+    if to.column >= columns.len()
+        || from.column >= columns.len()
+        || from.index >= columns[from.column].len()
+    {
+        return;
+    }
+    let item = columns[from.column].remove(from.index);
+    let target = &mut columns[to.column];
+    let index = to.index.min(target.len());
+    target.insert(index, item);
+}
+
+// How far the pointer moves before a press turns into a drag, so clicks still work
+const DRAG_THRESHOLD: f64 = 4.0;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum DragMode {
+    Pointer,
+    Keyboard,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct DragState {
+    from: DndPosition,
+    // Where the item ends up when dropped
+    to: DndPosition,
+    mode: DragMode,
+}
+
+// A pointer pressed on an item, not yet moved far enough to drag it
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Press {
+    position: DndPosition,
+    pointer_id: i32,
+    x: f64,
+    y: f64,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Bounds {
+    left: f64,
+    top: f64,
+    right: f64,
+    bottom: f64,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum DropEdge {
+    Before,
+    After,
+}
+
+#[derive(Clone)]
+struct RegisteredItem {
+    key: String,
+    column: usize,
+    index: ReadOnlySignal<usize>,
+    label: ReadOnlySignal<Option<String>>,
+    element: Rc<MountedData>,
+}
+
+impl RegisteredItem {
+    fn position(&self) -> DndPosition {
+        DndPosition {
+            column: self.column,
+            index: *self.index.peek(),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct RegisteredColumn {
+    column: usize,
+    label: Option<String>,
+    element: Rc<MountedData>,
+}
+
+// The final position of a dragged item under the pointer: the nearest column, and the number of
+// other items in it before the pointer
+fn drop_target(
+    (x, y): (f64, f64),
+    orientation: SortableOrientation,
+    from: DndPosition,
+    columns: &[(usize, Bounds)],
+    items: &[(DndPosition, Bounds)],
+) -> DndPosition {
+    let distance = |bounds: &Bounds| {
+        if x < bounds.left {
+            bounds.left - x
+        } else if x > bounds.right {
+            x - bounds.right
+        } else {
+            0.0
+        }
+    };
+    let column = columns
+        .iter()
+        .min_by(|a, b| distance(&a.1).total_cmp(&distance(&b.1)))
+        .map(|(column, _)| *column)
+        .unwrap_or(from.column);
+
+    let index = items
+        .iter()
+        .filter(|(position, _)| position.column == column && *position != from)
+        .filter(|(_, bounds)| match orientation {
+            SortableOrientation::Vertical => (bounds.top + bounds.bottom) / 2.0 < y,
+            SortableOrientation::Horizontal => (bounds.left + bounds.right) / 2.0 < x,
+        })
+        .count();
+
+    DndPosition { column, index }
+}
+
+#[derive(Clone, Copy)]
+struct DndContext {
+    root_id: Signal<String>,
+    orientation: ReadOnlySignal<SortableOrientation>,
+    disabled: ReadOnlySignal<bool>,
+    drag: Signal<Option<DragState>>,
+    press: Signal<Option<Press>>,
+    // Last pointer position in client coordinates while dragging
+    pointer: Signal<(f64, f64)>,
+    items: Signal<Vec<RegisteredItem>>,
+    columns: Signal<Vec<RegisteredColumn>>,
+    item_bounds: Signal<Vec<(DndPosition, Bounds)>>,
+    column_bounds: Signal<Vec<(usize, Bounds)>>,
+    announcement: Signal<String>,
+    focus_after_drop: Signal<Option<DndPosition>>,
+    on_reorder: Callback<(DndPosition, DndPosition)>,
+}
+
+impl DndContext {
+    fn item_id(&self, position: DndPosition) -> String {
+        format!(
+            "{}-item-{}-{}",
+            self.root_id.peek(),
+            position.column,
+            position.index
+        )
+    }
+
+    fn instructions_id(&self) -> String {
+        format!("{}-instructions", self.root_id.peek())
+    }
+
+    fn count(&self, column: usize) -> usize {
+        self.items
+            .peek()
+            .iter()
+            .filter(|item| item.column == column)
+            .count()
+    }
+
+    fn column_order(&self) -> Vec<usize> {
+        let mut columns = self
+            .columns
+            .peek()
+            .iter()
+            .map(|column| column.column)
+            .collect::<Vec<_>>();
+        columns.sort_unstable();
+        columns.dedup();
+        columns
+    }
+
+    // The number of places in a column for an item coming from `from`
+    fn places(&self, from: DndPosition, column: usize) -> usize {
+        if column == from.column {
+            self.count(column)
+        } else {
+            self.count(column) + 1
+        }
+    }
+
+    fn describe(&self, position: DndPosition) -> String {
+        self.items
+            .peek()
+            .iter()
+            .find(|item| item.position() == position)
+            .and_then(|item| item.label.peek().clone())
+            .unwrap_or_else(|| format!("item {}", position.index + 1))
+    }
+
+    fn place(&self, position: DndPosition, places: usize) -> String {
+        let mut place = format!("position {} of {}", position.index + 1, places);
+        let columns = self.columns.peek();
+        if columns.len() > 1
+            && let Some(label) = columns
+                .iter()
+                .find(|column| column.column == position.column)
+                .and_then(|column| column.label.clone())
+        {
+            place.push_str(&format!(" in {}", label));
+        }
+        place
+    }
+
+    fn is_dragging(&self) -> bool {
+        self.drag.peek().is_some()
+    }
+
+    fn pick_up(mut self, position: DndPosition, mode: DragMode) {
+        self.drag.set(Some(DragState {
+            from: position,
+            to: position,
+            mode,
+        }));
+        let description = self.describe(position);
+        let place = self.place(position, self.count(position.column));
+        let message = match mode {
+            DragMode::Keyboard => format!(
+                "Picked up {}, {}. Use the arrow keys to move it, Space to drop it and Escape to cancel.",
+                description, place
+            ),
+            DragMode::Pointer => format!("Picked up {}, {}.", description, place),
+        };
+        self.announcement.set(message);
+    }
+
+    fn move_to(mut self, to: DndPosition) {
+        let Some(mut state) = *self.drag.peek() else {
+            return;
+        };
+        if state.to == to {
+            return;
+        }
+        state.to = to;
+        self.drag.set(Some(state));
+        let message = format!(
+            "{} moved to {}.",
+            self.describe(state.from),
+            self.place(to, self.places(state.from, to.column))
+        );
+        self.announcement.set(capitalize(&message));
+    }
+
+    fn drop_item(mut self) {
+        let Some(state) = *self.drag.peek() else {
+            return;
+        };
+        // Describe the item before the reorder changes what sits where
+        let description = self.describe(state.from);
+        let place = self.place(state.to, self.places(state.from, state.to.column));
+        self.drag.set(None);
+        self.press.set(None);
+
+        if state.to == state.from {
+            self.announcement
+                .set(format!("Dropped {}, back at {}.", description, place));
+        } else {
+            self.on_reorder.call((state.from, state.to));
+            self.announcement
+                .set(format!("Dropped {} at {}.", description, place));
+        }
+        if state.mode == DragMode::Keyboard {
+            self.focus_after_drop.set(Some(state.to));
+        }
+    }
+
+    fn cancel(mut self) {
+        let Some(state) = *self.drag.peek() else {
+            return;
+        };
+        let description = self.describe(state.from);
+        let place = self.place(state.from, self.count(state.from.column));
+        self.drag.set(None);
+        self.press.set(None);
+        self.announcement.set(format!(
+            "Cancelled moving {}, back at {}.",
+            description, place
+        ));
+    }
+
+    // Move the drop target under the pointer, using the last measured bounds
+    fn update_target(self) {
+        let Some(state) = *self.drag.peek() else {
+            return;
+        };
+        if state.mode != DragMode::Pointer {
+            return;
+        }
+        let to = drop_target(
+            *self.pointer.peek(),
+            *self.orientation.peek(),
+            state.from,
+            &self.column_bounds.peek(),
+            &self.item_bounds.peek(),
+        );
+        self.move_to(to);
+    }
+
+    // Scroll the area under the pointer, or the page, while the pointer is near its edge
+    fn auto_scroll(self) {
+        let Some(state) = *self.drag.peek() else {
+            return;
+        };
+        let (x, y) = *self.pointer.peek();
+        let script = format!(
+            r#"
+            const dragged = document.getElementById("{}");
+            const x = {}, y = {}, edge = 40, speed = 12;
+            const step = (pos, start, end) => pos < start + edge ? -speed : pos > end - edge ? speed : 0;
+            let node = document.elementsFromPoint(x, y).find((el) => !dragged || !dragged.contains(el));
+            let scrolled = false;
+            while (node && node !== document.body && node !== document.documentElement) {{
+                const style = getComputedStyle(node);
+                const rect = node.getBoundingClientRect();
+                const dx = /(auto|scroll)/.test(style.overflowX) && node.scrollWidth > node.clientWidth ? step(x, rect.left, rect.right) : 0;
+                const dy = /(auto|scroll)/.test(style.overflowY) && node.scrollHeight > node.clientHeight ? step(y, rect.top, rect.bottom) : 0;
+                if (dx || dy) {{
+                    node.scrollBy(dx, dy);
+                    scrolled = true;
+                    break;
+                }}
+                node = node.parentElement;
+            }}
+            if (!scrolled) {{
+                window.scrollBy(step(x, 0, window.innerWidth), step(y, 0, window.innerHeight));
+            }}
+            "#,
+            self.item_id(state.from),
+            x,
+            y
+        );
+        let _ = document::eval(&script);
+    }
+
+    // The edge of an item where the drop indicator is drawn, if any
+    fn indicator(&self, position: DndPosition) -> Option<DropEdge> {
+        let state = (self.drag)()?;
+        if state.to == state.from || position.column != state.to.column {
+            return None;
+        }
+        // The gap the item is dropped into, counting the dragged item itself
+        let gap = if state.to.column == state.from.column && state.to.index > state.from.index {
+            state.to.index + 1
+        } else {
+            state.to.index
+        };
+        let count = self.count(position.column);
+        if position.index == gap {
+            Some(DropEdge::Before)
+        } else if gap == count && position.index + 1 == count {
+            Some(DropEdge::After)
+        } else {
+            None
+        }
+    }
+
+    // How the item at a position is being dragged, if it is
+    fn drag_mode(&self, position: DndPosition) -> Option<DragMode> {
+        (self.drag)()
+            .filter(|state| state.from == position)
+            .map(|state| state.mode)
+    }
+
+    // Moves the item dragged with the pointer along with it
+    fn drag_style(&self, position: DndPosition) -> Option<String> {
+        if self.drag_mode(position) != Some(DragMode::Pointer) {
+            return None;
+        }
+        let press = (*self.press.peek())?;
+        let (x, y) = (self.pointer)();
+        Some(format!(
+            "transform: translate({}px, {}px); position: relative; z-index: 50;",
+            x - press.x,
+            y - press.y
+        ))
+    }
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+// Measure every registered item and column for hit testing
+async fn measure(mut dnd: DndContext) {
+    let items = dnd.items.peek().clone();
+    let mut item_bounds = Vec::with_capacity(items.len());
+    for item in items {
+        if let Ok(rect) = item.element.get_client_rect().await {
+            item_bounds.push((
+                item.position(),
+                Bounds {
+                    left: rect.min_x(),
+                    top: rect.min_y(),
+                    right: rect.max_x(),
+                    bottom: rect.max_y(),
+                },
+            ));
+        }
+    }
+
+    let columns = dnd.columns.peek().clone();
+    let mut column_bounds = Vec::with_capacity(columns.len());
+    for column in columns {
+        if let Ok(rect) = column.element.get_client_rect().await {
+            column_bounds.push((
+                column.column,
+                Bounds {
+                    left: rect.min_x(),
+                    top: rect.min_y(),
+                    right: rect.max_x(),
+                    bottom: rect.max_y(),
+                },
+            ));
+        }
+    }
+
+    dnd.item_bounds.set(item_bounds);
+    dnd.column_bounds.set(column_bounds);
+}
+
+fn use_dnd_provider(
+    id: Option<String>,
+    orientation: ReadOnlySignal<SortableOrientation>,
+    disabled: ReadOnlySignal<bool>,
+    on_reorder: Callback<(DndPosition, DndPosition)>,
+) -> DndContext {
+    // Generate unique ID if not provided
+    let generated_id = use_unique_id();
+    let root_id = use_signal(|| id.unwrap_or_else(|| generated_id()));
+
+    let dnd = use_context_provider(|| DndContext {
+        root_id,
+        orientation,
+        disabled,
+        drag: Signal::new(None),
+        press: Signal::new(None),
+        pointer: Signal::new((0.0, 0.0)),
+        items: Signal::new(Vec::new()),
+        columns: Signal::new(Vec::new()),
+        item_bounds: Signal::new(Vec::new()),
+        column_bounds: Signal::new(Vec::new()),
+        announcement: Signal::new(String::new()),
+        focus_after_drop: Signal::new(None),
+        on_reorder,
+    });
+
+    // While dragging with the pointer, keep measuring and auto-scrolling until the drop
+    let dragging_pointer =
+        use_memo(move || (dnd.drag)().is_some_and(|state| state.mode == DragMode::Pointer));
+    use_effect(move || {
+        if !dragging_pointer() {
+            return;
+        }
+        spawn(async move {
+            while *dragging_pointer.peek() {
+                measure(dnd).await;
+                dnd.update_target();
+                dnd.auto_scroll();
+                sleep(Duration::from_millis(50)).await;
+            }
+        });
+    });
+
+    // Keep focus on an item dropped with the keyboard once the list is rendered in its new order
+    let mut focus_after_drop = dnd.focus_after_drop;
+    use_effect(move || {
+        let Some(position) = focus_after_drop() else {
+            return;
+        };
+        let script = format!(
+            r#"
+            requestAnimationFrame(() => {{
+                const item = document.getElementById("{}");
+                if (item) {{
+                    (item.querySelector("[data-dnd-handle]") || item).focus();
+                }}
+            }});
+            "#,
+            dnd.item_id(position)
+        );
+        let _ = document::eval(&script);
+        focus_after_drop.set(None);
+    });
+
+    dnd
+}
+
+// The column of the items inside it: a KanbanColumn, or 0 in a Sortable
+#[derive(Clone, Copy)]
+struct ColumnContext(usize);
+
+fn use_column_registration(column: usize, label: Option<String>) -> impl FnMut(MountedEvent) {
+    let mut dnd = use_context::<DndContext>();
+    use_drop(move || {
+        if let Ok(mut columns) = dnd.columns.try_write() {
+            columns.retain(|registered| registered.column != column);
+        }
+    });
+
+    move |event: MountedEvent| {
+        let mut columns = dnd.columns.write();
+        columns.retain(|registered| registered.column != column);
+        columns.push(RegisteredColumn {
+            column,
+            label: label.clone(),
+            element: event.data(),
+        });
+    }
+}
+
+#[derive(Clone, Copy)]
+struct ItemContext {
+    dnd: DndContext,
+    key: Signal<String>,
+    column: usize,
+    position: Memo<DndPosition>,
+    index: ReadOnlySignal<usize>,
+    label: ReadOnlySignal<Option<String>>,
+    disabled: ReadOnlySignal<bool>,
+}
+
+impl ItemContext {
+    fn register(mut self, element: Rc<MountedData>) {
+        let key = self.key.peek().clone();
+        let mut items = self.dnd.items.write();
+        items.retain(|item| item.key != key);
+        items.push(RegisteredItem {
+            key,
+            column: self.column,
+            index: self.index,
+            label: self.label,
+            element,
+        });
+    }
+
+    fn is_disabled(&self) -> bool {
+        *self.disabled.peek() || *self.dnd.disabled.peek()
+    }
+
+    fn pointer_down(mut self, event: PointerEvent) {
+        if self.is_disabled() || self.dnd.is_dragging() {
+            return;
+        }
+        let point = event.client_coordinates();
+        self.dnd.press.set(Some(Press {
+            position: *self.position.peek(),
+            pointer_id: event.pointer_id(),
+            x: point.x,
+            y: point.y,
+        }));
+    }
+
+    // The source is the element the pointer pressed, the item or its handle
+    fn pointer_move(mut self, event: PointerEvent, source_id: &str) {
+        let position = *self.position.peek();
+        let Some(press) = *self.dnd.press.peek() else {
+            return;
+        };
+        if press.position != position {
+            return;
+        }
+        let point = event.client_coordinates();
+        self.dnd.pointer.set((point.x, point.y));
+
+        match *self.dnd.drag.peek() {
+            None => {
+                if (point.x - press.x).hypot(point.y - press.y) < DRAG_THRESHOLD {
+                    return;
+                }
+                // Capture the pointer only once dragging, so presses on buttons inside still click
+                let _ = document::eval(&format!(
+                    r#"const source = document.getElementById("{}"); if (source) {{ try {{ source.setPointerCapture({}); }} catch (e) {{}} }}"#,
+                    source_id, press.pointer_id
+                ));
+                self.dnd.pick_up(position, DragMode::Pointer);
+            }
+            Some(state) if state.mode == DragMode::Pointer => self.dnd.update_target(),
+            Some(_) => {}
+        }
+    }
+
+    fn pointer_up(mut self) {
+        let position = *self.position.peek();
+        if !self
+            .dnd
+            .press
+            .peek()
+            .is_some_and(|press| press.position == position)
+        {
+            return;
+        }
+        if self
+            .dnd
+            .drag
+            .peek()
+            .is_some_and(|state| state.mode == DragMode::Pointer)
+        {
+            self.dnd.drop_item();
+        } else {
+            self.dnd.press.set(None);
+        }
+    }
+
+    fn pointer_cancel(mut self) {
+        if self
+            .dnd
+            .drag
+            .peek()
+            .is_some_and(|state| state.mode == DragMode::Pointer)
+        {
+            self.dnd.cancel();
+        } else {
+            self.dnd.press.set(None);
+        }
+    }
+
+    fn key_down(self, event: KeyboardEvent) {
+        let dnd = self.dnd;
+        let position = *self.position.peek();
+        let key = event.key();
+        let is_select = key == Key::Enter || key == Key::Character(" ".to_string());
+
+        let Some(state) = *dnd.drag.peek() else {
+            if is_select && !self.is_disabled() {
+                event.prevent_default();
+                dnd.pick_up(position, DragMode::Keyboard);
+            }
+            return;
+        };
+        if state.mode != DragMode::Keyboard || state.from != position {
+            return;
+        }
+
+        // Items move along the list, and across columns on a board
+        let (back, forward, previous_column, next_column) = match *dnd.orientation.peek() {
+            SortableOrientation::Vertical => (
+                Key::ArrowUp,
+                Key::ArrowDown,
+                Key::ArrowLeft,
+                Key::ArrowRight,
+            ),
+            SortableOrientation::Horizontal => (
+                Key::ArrowLeft,
+                Key::ArrowRight,
+                Key::ArrowUp,
+                Key::ArrowDown,
+            ),
+        };
+        let last_index = |column: usize| dnd.places(state.from, column).saturating_sub(1);
+        let to = state.to;
+
+        if is_select {
+            dnd.drop_item();
+        } else if key == Key::Escape {
+            dnd.cancel();
+        } else if key == back {
+            dnd.move_to(DndPosition {
+                index: to.index.saturating_sub(1),
+                ..to
+            });
+        } else if key == forward {
+            dnd.move_to(DndPosition {
+                index: (to.index + 1).min(last_index(to.column)),
+                ..to
+            });
+        } else if key == previous_column || key == next_column {
+            let columns = dnd.column_order();
+            let current = columns.iter().position(|column| *column == to.column);
+            let neighbour = match current {
+                Some(current) if key == previous_column => {
+                    current.checked_sub(1).and_then(|index| columns.get(index))
+                }
+                Some(current) => columns.get(current + 1),
+                None => None,
+            };
+            if let Some(&column) = neighbour {
+                dnd.move_to(DndPosition {
+                    column,
+                    index: to.index.min(last_index(column)),
+                });
+            }
+        } else if key == Key::Tab {
+            // Leaving the item drops nothing
+            dnd.cancel();
+            return;
+        } else {
+            return;
+        }
+        event.prevent_default();
+    }
+}
+
+fn use_sortable_item(
+    index: ReadOnlySignal<usize>,
+    label: ReadOnlySignal<Option<String>>,
+    disabled: ReadOnlySignal<bool>,
+) -> ItemContext {
+    let mut dnd = use_context::<DndContext>();
+    let column = try_use_context::<ColumnContext>()
+        .map(|context| context.0)
+        .unwrap_or(0);
+    let key = use_unique_id();
+    let position = use_memo(move || DndPosition {
+        column,
+        index: index(),
+    });
+
+    let item = use_context_provider(|| ItemContext {
+        dnd,
+        key,
+        column,
+        position,
+        index,
+        label,
+        disabled,
+    });
+
+    let registered_key = key.peek().clone();
+    use_drop(move || {
+        if let Ok(mut items) = dnd.items.try_write() {
+            items.retain(|item| item.key != registered_key);
+        }
+    });
+
+    item
+}
+
+fn indicator_class(orientation: SortableOrientation, edge: Option<DropEdge>) -> &'static str {
+    match (orientation, edge) {
+        (_, None) => "",
+        (SortableOrientation::Vertical, Some(DropEdge::Before)) => {
+            "shadow-[inset_0_2px_0_0_rgb(var(--primary))]"
+        }
+        (SortableOrientation::Vertical, Some(DropEdge::After)) => {
+            "shadow-[inset_0_-2px_0_0_rgb(var(--primary))]"
+        }
+        (SortableOrientation::Horizontal, Some(DropEdge::Before)) => {
+            "shadow-[inset_2px_0_0_0_rgb(var(--primary))]"
+        }
+        (SortableOrientation::Horizontal, Some(DropEdge::After)) => {
+            "shadow-[inset_-2px_0_0_0_rgb(var(--primary))]"
+        }
+    }
+}
+
+// Classes of a dragged item or of a source of pointer drags
+fn drag_classes(mode: Option<DragMode>, is_source: bool) -> &'static str {
+    match (mode, is_source) {
+        (Some(DragMode::Pointer), _) => "cursor-grabbing opacity-90 shadow-lg",
+        (Some(DragMode::Keyboard), _) => "ring-2 ring-primary",
+        (None, true) => "cursor-grab touch-none select-none",
+        (None, false) => "",
+    }
+}
+
+/// Screen reader instructions and announcements, as a row in table bodies
+#[component]
+fn DndAnnouncer(as_row: bool) -> Element {
+    let dnd = use_context::<DndContext>();
+    let announcement = (dnd.announcement)();
+    let instructions_id = dnd.instructions_id();
+    let content = rsx! {
+        span { id: instructions_id,
+            "Press Space or Enter to pick up an item. While dragging, use the arrow keys to move it, Space or Enter to drop it and Escape to cancel."
+        }
+        span { aria_live: "assertive", aria_atomic: "true", "{announcement}" }
+    };
+
+    if as_row {
+        rsx! {
+            tr { class: "sr-only",
+                td { {content} }
+            }
+        }
+    } else {
+        rsx! {
+            div { class: "sr-only", {content} }
+        }
+    }
+}
+
+/// Props for the Sortable component
+#[derive(Props, Clone, PartialEq)]
+pub struct SortableProps {
+    /// Callback with the index an item was dragged from and the index it ends up at.
+    /// Apply it to the list with `reorder`.
+    #[props(default)]
+    pub on_reorder: Option<EventHandler<(usize, usize)>>,
+
+    /// Direction in which the items are laid out
+    #[props(default)]
+    pub orientation: ReadOnlySignal<SortableOrientation>,
+
+    /// Whether reordering is disabled
+    #[props(default)]
+    pub disabled: ReadOnlySignal<bool>,
+
+    /// Optional ID for the list
+    #[props(default)]
+    pub id: Option<String>,
+
+    /// Accessible label for the list
+    #[props(default)]
+    pub aria_label: Option<String>,
+
+    /// Optional additional classes for the list
+    #[props(default)]
+    pub class: Option<String>,
+
+    #[props(extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+
+    /// The SortableItems
+    pub children: Element,
+}
+
+/// A list whose SortableItems can be reordered with the pointer or the keyboard
+#[component]
+pub fn Sortable(props: SortableProps) -> Element {
+    let on_reorder = props.on_reorder;
+    let reorder = use_callback(move |(from, to): (DndPosition, DndPosition)| {
+        if let Some(handler) = &on_reorder {
+            handler.call((from.index, to.index));
+        }
+    });
+    let dnd = use_dnd_provider(props.id.clone(), props.orientation, props.disabled, reorder);
+    use_context_provider(|| ColumnContext(0));
+    let mut register_column = use_column_registration(0, None);
+
+    let list_classes = vec![
+        match (props.orientation)() {
+            SortableOrientation::Vertical => "flex flex-col gap-2",
+            SortableOrientation::Horizontal => "flex flex-row gap-2",
+        },
+        props.class.as_deref().unwrap_or(""),
+    ]
+    .into_iter()
+    .filter(|s| !s.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ");
+
+    rsx! {
+        div {
+            id: dnd.root_id.peek().clone(),
+            class: list_classes,
+            role: "list",
+            aria_label: props.aria_label.clone(),
+            onmounted: move |event| register_column(event),
+            ..props.attributes,
+
+            {props.children}
+        }
+        DndAnnouncer { as_row: false }
+    }
+}
+
+/// Props for the SortableItem and SortableRow components
+#[derive(Props, Clone, PartialEq)]
+pub struct SortableItemProps {
+    /// The index of the item in its list
+    pub index: ReadOnlySignal<usize>,
+
+    /// Name of the item in screen reader announcements, e.g. a task title.
+    /// Defaults to "item" and its position.
+    #[props(default)]
+    pub label: ReadOnlySignal<Option<String>>,
+
+    /// Whether the item is only dragged by a SortableHandle inside it, e.g. when it holds
+    /// buttons or text fields
+    #[props(default)]
+    pub handle: bool,
+
+    /// Whether the item stays in place
+    #[props(default)]
+    pub disabled: ReadOnlySignal<bool>,
+
+    /// Optional additional classes for the item
+    #[props(default)]
+    pub class: Option<String>,
+
+    #[props(extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+
+    pub children: Element,
+}
+
+/// An item of a Sortable or a KanbanColumn. Without a handle, the whole item is dragged and focused.
+#[component]
+pub fn SortableItem(props: SortableItemProps) -> Element {
+    let item = use_sortable_item(props.index, props.label, props.disabled);
+    let dnd = item.dnd;
+    let position = (item.position)();
+    let item_id = dnd.item_id(position);
+    let handle = props.handle;
+    let is_source = !handle && !(props.disabled)() && !(dnd.disabled)();
+
+    let item_classes = vec![
+        "relative rounded-md",
+        "focus:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2",
+        drag_classes(dnd.drag_mode(position), is_source),
+        indicator_class((dnd.orientation)(), dnd.indicator(position)),
+        props.class.as_deref().unwrap_or(""),
+    ]
+    .into_iter()
+    .filter(|s| !s.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ");
+
+    let source_id = item_id.clone();
+
+    rsx! {
+        div {
+            id: item_id.clone(),
+            class: item_classes,
+            style: dnd.drag_style(position),
+            role: "listitem",
+            tabindex: (!handle).then_some("0"),
+            aria_describedby: (!handle).then(|| dnd.instructions_id()),
+            aria_disabled: (props.disabled)().then_some("true"),
+            onmounted: move |event: MountedEvent| item.register(event.data()),
+            onpointerdown: move |event: PointerEvent| {
+                if !handle {
+                    item.pointer_down(event);
+                }
+            },
+            onpointermove: move |event: PointerEvent| {
+                if !handle {
+                    item.pointer_move(event, &source_id);
+                }
+            },
+            onpointerup: move |_| {
+                if !handle {
+                    item.pointer_up();
+                }
+            },
+            onpointercancel: move |_| {
+                if !handle {
+                    item.pointer_cancel();
+                }
+            },
+            onkeydown: move |event: KeyboardEvent| {
+                if !handle {
+                    item.key_down(event);
+                }
+            },
+            ..props.attributes,
+
+            {props.children}
+        }
+    }
+}
+
+/// Props for the SortableHandle component
+#[derive(Props, Clone, PartialEq)]
+pub struct SortableHandleProps {
+    /// Optional additional classes for the handle
+    #[props(default)]
+    pub class: Option<String>,
+}
+
+/// A grip button that drags its SortableItem or SortableRow, for items created with `handle: true`
+#[component]
+pub fn SortableHandle(props: SortableHandleProps) -> Element {
+    let item = use_context::<ItemContext>();
+    let dnd = item.dnd;
+    let position = (item.position)();
+    let handle_id = format!("{}-handle", dnd.item_id(position));
+    let is_disabled = (item.disabled)() || (dnd.disabled)();
+    let label = (item.label)().unwrap_or_else(|| format!("item {}", position.index + 1));
+    let aria_label = format!("Reorder {}", label);
+
+    let handle_classes = vec![
+        "inline-flex shrink-0 items-center justify-center rounded-sm p-1 text-muted-foreground hover:text-foreground",
+        "focus:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2",
+        if is_disabled {
+            "opacity-50 cursor-not-allowed"
+        } else {
+            drag_classes(dnd.drag_mode(position), true)
+        },
+        props.class.as_deref().unwrap_or(""),
+    ]
+    .into_iter()
+    .filter(|s| !s.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ");
+
+    let source_id = handle_id.clone();
+
+    rsx! {
+        button {
+            id: handle_id,
+            type: "button",
+            class: handle_classes,
+            "data-dnd-handle": "true",
+            aria_label: aria_label,
+            aria_describedby: dnd.instructions_id(),
+            aria_disabled: is_disabled.then_some("true"),
+            onpointerdown: move |event: PointerEvent| item.pointer_down(event),
+            onpointermove: move |event: PointerEvent| item.pointer_move(event, &source_id),
+            onpointerup: move |_| item.pointer_up(),
+            onpointercancel: move |_| item.pointer_cancel(),
+            onkeydown: move |event: KeyboardEvent| item.key_down(event),
+
+            GripVertical { size: 16 }
+        }
+    }
+}
+
+/// Props for the SortableTableBody component
+#[derive(Props, Clone, PartialEq)]
+pub struct SortableTableBodyProps {
+    /// Callback with the index a row was dragged from and the index it ends up at.
+    /// Apply it to the rows with `reorder`.
+    #[props(default)]
+    pub on_reorder: Option<EventHandler<(usize, usize)>>,
+
+    /// Whether reordering is disabled
+    #[props(default)]
+    pub disabled: ReadOnlySignal<bool>,
+
+    /// Optional ID for the table body
+    #[props(default)]
+    pub id: Option<String>,
+
+    /// Optional additional classes for the table body
+    #[props(default)]
+    pub class: Option<String>,
+
+    #[props(extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+
+    /// The SortableRows
+    pub children: Element,
+}
+
+/// A `tbody` whose SortableRows can be reordered with the pointer or the keyboard
+#[component]
+pub fn SortableTableBody(props: SortableTableBodyProps) -> Element {
+    let on_reorder = props.on_reorder;
+    let reorder = use_callback(move |(from, to): (DndPosition, DndPosition)| {
+        if let Some(handler) = &on_reorder {
+            handler.call((from.index, to.index));
+        }
+    });
+    let orientation = use_signal(|| SortableOrientation::Vertical);
+    let dnd = use_dnd_provider(
+        props.id.clone(),
+        orientation.into(),
+        props.disabled,
+        reorder,
+    );
+    use_context_provider(|| ColumnContext(0));
+    let mut register_column = use_column_registration(0, None);
+
+    rsx! {
+        tbody {
+            id: dnd.root_id.peek().clone(),
+            class: props.class.clone(),
+            onmounted: move |event| register_column(event),
+            ..props.attributes,
+
+            {props.children}
+            DndAnnouncer { as_row: true }
+        }
+    }
+}
+
+/// A table row of a SortableTableBody, usually dragged by a SortableHandle in its first cell
+#[component]
+pub fn SortableRow(props: SortableItemProps) -> Element {
+    let item = use_sortable_item(props.index, props.label, props.disabled);
+    let dnd = item.dnd;
+    let position = (item.position)();
+    let row_id = dnd.item_id(position);
+    let handle = props.handle;
+    let is_source = !handle && !(props.disabled)() && !(dnd.disabled)();
+
+    let row_classes = vec![
+        "focus:outline-none focus-visible:ring-2 focus-visible:ring-inset focus-visible:ring-ring",
+        drag_classes(dnd.drag_mode(position), is_source),
+        indicator_class(SortableOrientation::Vertical, dnd.indicator(position)),
+        props.class.as_deref().unwrap_or(""),
+    ]
+    .into_iter()
+    .filter(|s| !s.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ");
+
+    let source_id = row_id.clone();
+
+    rsx! {
+        tr {
+            id: row_id.clone(),
+            class: row_classes,
+            style: dnd.drag_style(position),
+            tabindex: (!handle).then_some("0"),
+            aria_describedby: (!handle).then(|| dnd.instructions_id()),
+            aria_disabled: (props.disabled)().then_some("true"),
+            onmounted: move |event: MountedEvent| item.register(event.data()),
+            onpointerdown: move |event: PointerEvent| {
+                if !handle {
+                    item.pointer_down(event);
+                }
+            },
+            onpointermove: move |event: PointerEvent| {
+                if !handle {
+                    item.pointer_move(event, &source_id);
+                }
+            },
+            onpointerup: move |_| {
+                if !handle {
+                    item.pointer_up();
+                }
+            },
+            onpointercancel: move |_| {
+                if !handle {
+                    item.pointer_cancel();
+                }
+            },
+            onkeydown: move |event: KeyboardEvent| {
+                if !handle {
+                    item.key_down(event);
+                }
+            },
+            ..props.attributes,
+
+            {props.children}
+        }
+    }
+}
+
+/// Props for the KanbanBoard component
+#[derive(Props, Clone, PartialEq)]
+pub struct KanbanBoardProps {
+    /// Callback with the position a card was dragged from and the position it ends up at.
+    /// Apply it to the columns with `move_item`.
+    #[props(default)]
+    pub on_reorder: Option<EventHandler<(DndPosition, DndPosition)>>,
+
+    /// Whether moving cards is disabled
+    #[props(default)]
+    pub disabled: ReadOnlySignal<bool>,
+
+    /// Optional ID for the board
+    #[props(default)]
+    pub id: Option<String>,
+
+    /// Accessible label for the board
+    #[props(default)]
+    pub aria_label: Option<String>,
+
+    /// Optional additional classes for the board
+    #[props(default)]
+    pub class: Option<String>,
+
+    #[props(extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+
+    /// The KanbanColumns
+    pub children: Element,
+}
+
+/// A board of KanbanColumns whose cards can be reordered and moved between columns
+#[component]
+pub fn KanbanBoard(props: KanbanBoardProps) -> Element {
+    let on_reorder = props.on_reorder;
+    let reorder = use_callback(move |(from, to): (DndPosition, DndPosition)| {
+        if let Some(handler) = &on_reorder {
+            handler.call((from, to));
+        }
+    });
+    let orientation = use_signal(|| SortableOrientation::Vertical);
+    let dnd = use_dnd_provider(
+        props.id.clone(),
+        orientation.into(),
+        props.disabled,
+        reorder,
+    );
+
+    let board_classes = vec![
+        "flex items-start gap-4 overflow-x-auto pb-2",
+        props.class.as_deref().unwrap_or(""),
+    ]
+    .into_iter()
+    .filter(|s| !s.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ");
+
+    rsx! {
+        div {
+            id: dnd.root_id.peek().clone(),
+            class: board_classes,
+            role: "group",
+            aria_label: props.aria_label.clone(),
+            ..props.attributes,
+
+            {props.children}
+        }
+        DndAnnouncer { as_row: false }
+    }
+}
+
+/// Props for the KanbanColumn component
+#[derive(Props, Clone, PartialEq)]
+pub struct KanbanColumnProps {
+    /// The index of the column on the board
+    pub index: usize,
+
+    /// The column title, also used in announcements
+    pub title: String,
+
+    /// Optional content next to the title, e.g. an add button
+    #[props(default)]
+    pub actions: Option<Element>,
+
+    /// Optional additional classes for the column
+    #[props(default)]
+    pub class: Option<String>,
+
+    #[props(extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+
+    /// The KanbanCards
+    pub children: Element,
+}
+
+/// A column of a KanbanBoard with a title, a card count and its cards
+#[component]
+pub fn KanbanColumn(props: KanbanColumnProps) -> Element {
+    let dnd = use_context::<DndContext>();
+    let column = props.index;
+    use_context_provider(|| ColumnContext(column));
+    let mut register_column = use_column_registration(column, Some(props.title.clone()));
+
+    let count = dnd
+        .items
+        .read()
+        .iter()
+        .filter(|item| item.column == column)
+        .count();
+    let is_target = (dnd.drag)()
+        .is_some_and(|state| state.to.column == column && state.to.column != state.from.column);
+    let title_id = format!("{}-column-{}", dnd.root_id.peek(), column);
+    let title = props.title.clone();
+
+    let column_classes = vec![
+        "flex w-72 shrink-0 flex-col rounded-lg border border-border bg-muted/50 transition-colors",
+        if is_target {
+            "ring-2 ring-primary/50"
+        } else {
+            ""
+        },
+        props.class.as_deref().unwrap_or(""),
+    ]
+    .into_iter()
+    .filter(|s| !s.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ");
+
+    rsx! {
+        section {
+            class: column_classes,
+            aria_labelledby: title_id.clone(),
+            ..props.attributes,
+
+            div { class: "flex items-center justify-between gap-2 px-3 pt-3",
+                div { class: "flex items-center gap-2",
+                    h3 { id: title_id.clone(), class: "text-sm font-semibold text-foreground", "{title}" }
+                    span { class: "rounded-full bg-muted px-2 py-0.5 text-xs text-muted-foreground", "{count}" }
+                }
+                {props.actions}
+            }
+
+            div {
+                class: "flex min-h-24 max-h-[32rem] flex-col gap-2 overflow-y-auto p-3",
+                role: "list",
+                aria_labelledby: title_id,
+                onmounted: move |event| register_column(event),
+
+                {props.children}
+            }
+        }
+    }
+}
+
+/// A card of a KanbanColumn, dragged anywhere on it
+#[component]
+pub fn KanbanCard(props: SortableItemProps) -> Element {
+    let card_classes = vec![
+        "border border-border bg-background p-3 text-sm shadow-sm",
+        props.class.as_deref().unwrap_or(""),
+    ]
+    .into_iter()
+    .filter(|s| !s.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ");
+
+    rsx! {
+        SortableItem {
+            index: props.index,
+            label: props.label,
+            handle: props.handle,
+            disabled: props.disabled,
+            class: card_classes,
+            ..props.attributes,
+
+            {props.children}
+        }
+    }
+}
@@ -0,0 +1,763 @@
+use crate::components::aspect_ratio::AspectRatio;
+use crate::components::form::use_form_control;
+use crate::components::input::InputVariant;
+use crate::components::progress::{Progress, ProgressSize, ProgressVariant};
+use crate::use_unique_id;
+use dioxus_lib::prelude::*;
+use lucide_dioxus::{CircleAlert, File as FileIcon, Upload, X};
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+/// A file accepted by a FileDropzone. The contents are read on demand through the platform's
+/// file engine, so large files are never loaded unless asked for.
+#[derive(Clone)]
+pub struct DroppedFile {
+    id: usize,
+    path: String,
+    size: u64,
+    mime: String,
+    engine: Arc<dyn FileEngine>,
+}
+
+impl DroppedFile {
+    /// An id that stays the same while the file is in the list. Progress is keyed by it.
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// The file name without any directories
+    pub fn name(&self) -> &str {
+        file_name(&self.path)
+    }
+
+    /// The name reported by the file engine. On desktop this is the full path.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The size in bytes
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// The MIME type guessed from the file extension, e.g. `image/png`
+    pub fn mime(&self) -> &str {
+        &self.mime
+    }
+
+    /// Whether the file is an image that can be previewed
+    pub fn is_image(&self) -> bool {
+        self.mime.starts_with("image/")
+    }
+
+    /// Read the whole file
+    pub async fn read_bytes(&self) -> Option<Vec<u8>> {
+        self.engine.read_file(&self.path).await
+    }
+
+    /// Read the whole file as text
+    pub async fn read_string(&self) -> Option<String> {
+        self.engine.read_file_to_string(&self.path).await
+    }
+
+    /// The platform's handle for the file: a `web_sys::File` on web and a `PathBuf` on desktop
+    pub async fn native_file(&self) -> Option<Box<dyn Any>> {
+        self.engine.get_native_file(&self.path).await
+    }
+}
+
+impl PartialEq for DroppedFile {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && self.path == other.path && self.size == other.size
+    }
+}
+
+impl fmt::Debug for DroppedFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DroppedFile")
+            .field("id", &self.id)
+            .field("path", &self.path)
+            .field("size", &self.size)
+            .field("mime", &self.mime)
+            .finish()
+    }
+}
+
+/// Why a FileDropzone turned a file away
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RejectionReason {
+    /// The file does not match `accept`
+    FileType,
+    /// The file is larger than `max_size`
+    TooLarge,
+    /// Adding the file would go over `max_files`
+    TooMany,
+}
+
+/// A file that was dropped or picked but not added
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct FileRejection {
+    /// The file name without any directories
+    pub name: String,
+    /// The size in bytes
+    pub size: u64,
+    /// Why the file was rejected
+    pub reason: RejectionReason,
+}
+
+impl FileRejection {
+    /// A short English description of the rejection
+    pub fn message(&self, max_size: Option<u64>, max_files: Option<usize>) -> String {
+        match self.reason {
+            RejectionReason::FileType => format!("{} is not an accepted file type", self.name),
+            RejectionReason::TooLarge => match max_size {
+                Some(max) => format!("{} is larger than {}", self.name, format_file_size(max)),
+                None => format!("{} is too large", self.name),
+            },
+            RejectionReason::TooMany => match max_files {
+                Some(max) => format!("{} was not added, the limit is {} files", self.name, max),
+                None => format!("{} was not added, too many files", self.name),
+            },
+        }
+    }
+}
+
+/// Format a byte count for display, e.g. `1.5 MB`
+pub fn format_file_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit + 1 < UNITS.len() {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if size < 10.0 {
+        format!("{:.1} {}", size, UNITS[unit])
+    } else {
+        format!("{:.0} {}", size, UNITS[unit])
+    }
+}
+
+fn file_name(path: &str) -> &str {
+    path.rsplit(['/', '\\']).next().unwrap_or(path)
+}
+
+fn extension(name: &str) -> String {
+    match file_name(name).rsplit_once('.') {
+        Some((_, extension)) => extension.to_lowercase(),
+        None => String::new(),
+    }
+}
+
+fn guess_mime(name: &str) -> &'static str {
+    match extension(name).as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "svg" => "image/svg+xml",
+        "avif" => "image/avif",
+        "bmp" => "image/bmp",
+        "ico" => "image/x-icon",
+        "pdf" => "application/pdf",
+        "json" => "application/json",
+        "zip" => "application/zip",
+        "doc" => "application/msword",
+        "docx" => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        "xls" => "application/vnd.ms-excel",
+        "xlsx" => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        "txt" => "text/plain",
+        "csv" => "text/csv",
+        "md" => "text/markdown",
+        "html" | "htm" => "text/html",
+        "mp3" => "audio/mpeg",
+        "wav" => "audio/wav",
+        "mp4" => "video/mp4",
+        "webm" => "video/webm",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Whether a file matches an `accept` list such as `image/*,.pdf`. An empty list accepts anything.
+fn matches_accept(accept: &str, name: &str, mime: &str) -> bool {
+    let patterns = accept
+        .split(',')
+        .map(|pattern| pattern.trim().to_lowercase())
+        .filter(|pattern| !pattern.is_empty())
+        .collect::<Vec<_>>();
+    if patterns.is_empty() {
+        return true;
+    }
+    let extension = extension(name);
+    patterns.iter().any(|pattern| {
+        if let Some(accepted) = pattern.strip_prefix('.') {
+            accepted == extension
+        } else if let Some(family) = pattern.strip_suffix("/*") {
+            mime.split('/').next() == Some(family)
+        } else {
+            pattern == mime
+        }
+    })
+}
+
+// Image previews are skipped above this size to keep memory use down
+const MAX_PREVIEW_BYTES: u64 = 10 * 1024 * 1024;
+
+fn data_url(mime: &str, bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let triple = chunk.iter().enumerate().fold(0u32, |acc, (index, byte)| {
+            acc | ((*byte as u32) << (16 - index * 8))
+        });
+        for index in 0..4 {
+            if index <= chunk.len() {
+                encoded.push(ALPHABET[(triple >> (18 - index * 6)) as usize & 63] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    format!("data:{};base64,{}", mime, encoded)
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct FileDropzoneProps {
+    /// The files in the dropzone. The dropzone updates this signal as files are added and removed.
+    /// Leave unset to let the dropzone manage its own list.
+    #[props(default)]
+    files: Option<Signal<Vec<DroppedFile>>>,
+
+    /// Callback with the whole list when files are added or removed
+    #[props(default)]
+    on_files_change: Option<EventHandler<Vec<DroppedFile>>>,
+
+    /// Callback with the files that were just added, e.g. to start uploading them
+    #[props(default)]
+    on_add: Option<EventHandler<Vec<DroppedFile>>>,
+
+    /// Callback with a file the user removed
+    #[props(default)]
+    on_remove: Option<EventHandler<DroppedFile>>,
+
+    /// Callback with the files that were turned away
+    #[props(default)]
+    on_reject: Option<EventHandler<Vec<FileRejection>>>,
+
+    /// Upload progress between 0 and 100, keyed by `DroppedFile::id`.
+    /// Files with an entry show a progress bar.
+    #[props(default)]
+    progress: ReadOnlySignal<HashMap<usize, f64>>,
+
+    /// Accepted file types, as in the `accept` attribute, e.g. `image/*,.pdf`
+    #[props(default)]
+    accept: String,
+
+    /// Whether more than one file can be added
+    #[props(default = true)]
+    multiple: bool,
+
+    /// Largest accepted file size in bytes
+    #[props(default)]
+    max_size: Option<u64>,
+
+    /// Largest number of files in the list
+    #[props(default)]
+    max_files: Option<usize>,
+
+    /// Whether image files show a thumbnail
+    #[props(default = true)]
+    show_previews: bool,
+
+    /// The prompt shown in the drop area
+    #[props(default = String::from("Drag files here or click to browse"))]
+    label: String,
+
+    /// Optional hint below the prompt, e.g. the accepted types. Defaults to the limits.
+    #[props(default)]
+    description: Option<String>,
+
+    /// The variant of the drop area
+    #[props(default)]
+    variant: InputVariant,
+
+    /// Whether the dropzone is disabled
+    #[props(default)]
+    disabled: bool,
+
+    /// Optional ID for the file input
+    #[props(default)]
+    id: Option<String>,
+
+    /// Optional aria-label for the file input (for accessibility)
+    #[props(default)]
+    aria_label: Option<String>,
+
+    /// Optional additional classes for the drop area
+    #[props(default)]
+    class: Option<String>,
+
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+/// A drop area for files with click-to-browse, type and size limits, previews and upload progress
+#[component]
+pub fn FileDropzone(props: FileDropzoneProps) -> Element {
+    // Take the id and ARIA state from the surrounding FormControl, if any
+    let control = use_form_control();
+
+    // Generate unique ID if not provided, the picker is reset through it
+    let input_id = use_unique_id();
+    let id = props
+        .id
+        .clone()
+        .or_else(|| control.map(|control| control.control_id()))
+        .unwrap_or_else(|| input_id());
+    let errors_id = format!("{}-errors", id);
+
+    // Use the provided signal when controlled, otherwise keep the files internally
+    let internal_files = use_signal(Vec::new);
+    let mut files = props.files.unwrap_or(internal_files);
+
+    let mut next_id = use_signal(|| 0usize);
+    let mut dragging = use_signal(|| false);
+    let mut rejections = use_signal(Vec::<FileRejection>::new);
+
+    // Check every file the engine offers and add the ones that pass
+    let accept = props.accept.clone();
+    let multiple = props.multiple;
+    let max_size = props.max_size;
+    let max_files = props.max_files.or((!multiple).then_some(1));
+    let on_files_change = props.on_files_change;
+    let on_add = props.on_add;
+    let on_reject = props.on_reject;
+    let add_files = use_callback(move |engine: Arc<dyn FileEngine>| {
+        let accept = accept.clone();
+        spawn(async move {
+            let mut current = files.peek().clone();
+            // A single-file dropzone replaces its file
+            if !multiple {
+                current.clear();
+            }
+            let mut added = Vec::new();
+            let mut rejected = Vec::new();
+
+            for path in engine.files() {
+                let size = engine.file_size(&path).await.unwrap_or(0);
+                let mime = guess_mime(&path).to_string();
+                let reason = if !matches_accept(&accept, &path, &mime) {
+                    Some(RejectionReason::FileType)
+                } else if max_size.is_some_and(|max| size > max) {
+                    Some(RejectionReason::TooLarge)
+                } else if max_files.is_some_and(|max| current.len() + added.len() >= max) {
+                    Some(RejectionReason::TooMany)
+                } else {
+                    None
+                };
+
+                match reason {
+                    Some(reason) => rejected.push(FileRejection {
+                        name: file_name(&path).to_string(),
+                        size,
+                        reason,
+                    }),
+                    None => {
+                        let id = *next_id.peek();
+                        next_id.set(id + 1);
+                        added.push(DroppedFile {
+                            id,
+                            path,
+                            size,
+                            mime,
+                            engine: engine.clone(),
+                        });
+                    }
+                }
+            }
+
+            rejections.set(rejected.clone());
+            if !rejected.is_empty()
+                && let Some(handler) = &on_reject
+            {
+                handler.call(rejected);
+            }
+            if added.is_empty() {
+                return;
+            }
+            current.extend(added.iter().cloned());
+            files.set(current.clone());
+            if let Some(handler) = &on_files_change {
+                handler.call(current);
+            }
+            if let Some(handler) = &on_add {
+                handler.call(added);
+            }
+        });
+    });
+
+    let on_remove = props.on_remove;
+    let remove_file = use_callback(move |file: DroppedFile| {
+        let mut current = files.peek().clone();
+        current.retain(|existing| existing.id != file.id);
+        files.set(current.clone());
+        rejections.set(Vec::new());
+        if let Some(handler) = &on_files_change {
+            handler.call(current);
+        }
+        if let Some(handler) = &on_remove {
+            handler.call(file);
+        }
+    });
+
+    let disabled = props.disabled;
+    let picker_id = id.clone();
+    let handle_change = move |event: FormEvent| {
+        if let Some(engine) = event.files() {
+            add_files.call(engine);
+        }
+        // Clear the picker so choosing the same file again fires another change
+        let _ = document::eval(&format!(
+            r#"const input = document.getElementById("{}"); if (input) {{ input.value = ""; }}"#,
+            picker_id
+        ));
+    };
+
+    let handle_drop = move |event: DragEvent| {
+        event.prevent_default();
+        dragging.set(false);
+        if disabled {
+            return;
+        }
+        if let Some(engine) = event.files() {
+            add_files.call(engine);
+        }
+    };
+
+    let is_invalid = props.variant == InputVariant::Error
+        || !rejections.read().is_empty()
+        || control.is_some_and(|control| control.is_invalid());
+
+    // Determine variant classes
+    let variant_classes = if is_invalid {
+        "border-destructive focus-within:border-destructive"
+    } else if dragging() {
+        "border-ring bg-muted"
+    } else {
+        "border-input focus-within:border-ring hover:bg-muted/50"
+    };
+
+    // Determine state classes
+    let state_class = if props.disabled {
+        "opacity-50 cursor-not-allowed bg-muted"
+    } else {
+        "bg-background cursor-pointer"
+    };
+
+    // Generate all the classes
+    let zone_classes = vec![
+        // Base classes
+        "flex flex-col items-center justify-center gap-2 rounded border-2 border-dashed px-6 py-8 text-center",
+        "transition-colors focus-within:outline-none focus-within:ring-2 focus-within:ring-ring focus-within:ring-offset-2",
+        // Variant-specific classes
+        variant_classes,
+        // State class
+        state_class,
+        // Additional classes passed by the user
+        props.class.as_deref().unwrap_or(""),
+    ]
+    .into_iter()
+    .filter(|s| !s.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ");
+
+    // Describe the limits unless a description is given
+    let description = props.description.clone().unwrap_or_else(|| {
+        let mut limits = Vec::new();
+        if !props.accept.is_empty() {
+            limits.push(props.accept.replace(',', ", "));
+        }
+        if let Some(max) = props.max_size {
+            limits.push(format!("up to {}", format_file_size(max)));
+        }
+        if let Some(max) = props.max_files.filter(|_| multiple) {
+            limits.push(format!("{} files max", max));
+        }
+        limits.join(" · ")
+    });
+
+    let aria_describedby = [
+        control.and_then(|control| control.describedby()),
+        (!rejections.read().is_empty()).then(|| errors_id.clone()),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>()
+    .join(" ");
+
+    let label = props.label.clone();
+    let current_files = files();
+    let progress = (props.progress)();
+    let messages = rejections
+        .read()
+        .iter()
+        .map(|rejection| rejection.message(props.max_size, max_files))
+        .collect::<Vec<_>>();
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-3",
+
+            label {
+                for: id.clone(),
+                class: zone_classes,
+                ondragenter: move |event: DragEvent| {
+                    event.prevent_default();
+                    if !disabled {
+                        dragging.set(true);
+                    }
+                },
+                ondragover: move |event: DragEvent| {
+                    event.prevent_default();
+                },
+                ondragleave: move |_| dragging.set(false),
+                ondrop: handle_drop,
+
+                span {
+                    class: "rounded-full bg-muted p-3 text-muted-foreground",
+                    aria_hidden: "true",
+                    Upload { size: 20 }
+                }
+                span { class: "text-sm font-medium text-foreground", "{label}" }
+                if !description.is_empty() {
+                    span { class: "text-xs text-muted-foreground", "{description}" }
+                }
+
+                input {
+                    id: id.clone(),
+                    type: "file",
+                    class: "sr-only",
+                    accept: (!props.accept.is_empty()).then(|| props.accept.clone()),
+                    multiple: props.multiple,
+                    disabled: props.disabled,
+                    onchange: handle_change,
+                    aria_label: props.aria_label.clone(),
+                    aria_labelledby: control
+                        .filter(|_| props.aria_label.is_none())
+                        .map(|control| control.label_id()),
+                    aria_describedby: (!aria_describedby.is_empty()).then_some(aria_describedby),
+                    aria_invalid: is_invalid.then_some("true"),
+                    ..props.attributes,
+                }
+            }
+
+            if !messages.is_empty() {
+                ul {
+                    id: errors_id.clone(),
+                    class: "flex flex-col gap-1",
+                    role: "alert",
+                    for message in messages {
+                        li {
+                            key: "{message}",
+                            class: "text-destructive text-xs flex items-center",
+                            CircleAlert { size: 14, class: "mr-1 text-destructive" }
+                            "{message}"
+                        }
+                    }
+                }
+            }
+
+            if !current_files.is_empty() {
+                ul {
+                    class: "flex flex-col gap-2",
+                    aria_label: "Selected files",
+
+                    for file in current_files {
+                        {
+                            let name = file.name().to_string();
+                            let size = format_file_size(file.size());
+                            let file_progress = progress.get(&file.id()).copied();
+                            let show_preview = props.show_previews && file.is_image() && file.size() <= MAX_PREVIEW_BYTES;
+                            let file_id = file.id();
+                            let removed = file.clone();
+                            rsx! {
+                                li {
+                                    key: "{file_id}",
+                                    class: "flex items-center gap-3 rounded border border-border bg-background p-2",
+
+                                    div {
+                                        class: "w-10 shrink-0",
+                                        if show_preview {
+                                            FilePreview { file: file.clone() }
+                                        } else {
+                                            AspectRatio {
+                                                ratio: 1.0,
+                                                class: Some("rounded bg-muted".to_string()),
+                                                div {
+                                                    class: "flex h-full w-full items-center justify-center text-muted-foreground",
+                                                    aria_hidden: "true",
+                                                    FileIcon { size: 18 }
+                                                }
+                                            }
+                                        }
+                                    }
+
+                                    div {
+                                        class: "flex min-w-0 flex-1 flex-col gap-1",
+                                        div {
+                                            class: "flex items-baseline justify-between gap-2",
+                                            span { class: "truncate text-sm font-medium text-foreground", title: name.clone(), "{name}" }
+                                            span { class: "shrink-0 text-xs text-muted-foreground", "{size}" }
+                                        }
+                                        if let Some(value) = file_progress {
+                                            Progress {
+                                                value: value,
+                                                size: ProgressSize::Small,
+                                                variant: if value >= 100.0 { ProgressVariant::Success } else { ProgressVariant::Default },
+                                                aria_label: Some(format!("Uploading {}", name)),
+                                            }
+                                        }
+                                    }
+
+                                    if !props.disabled {
+                                        button {
+                                            type: "button",
+                                            class: "rounded-sm p-1 text-muted-foreground hover:text-foreground focus:outline-none focus-visible:ring-2 focus-visible:ring-ring",
+                                            aria_label: format!("Remove {}", name),
+                                            onclick: move |_| remove_file.call(removed.clone()),
+                                            X { size: 16 }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct FilePreviewProps {
+    file: DroppedFile,
+}
+
+/// A square thumbnail of an image file, read through the file engine
+#[component]
+fn FilePreview(props: FilePreviewProps) -> Element {
+    let file = props.file.clone();
+    let src = use_resource(move || {
+        let file = file.clone();
+        async move {
+            file.read_bytes()
+                .await
+                .map(|bytes| data_url(file.mime(), &bytes))
+        }
+    });
+
+    rsx! {
+        AspectRatio {
+            ratio: 1.0,
+            class: Some("rounded bg-muted".to_string()),
+            if let Some(Some(src)) = src() {
+                img {
+                    class: "h-full w-full object-cover",
+                    src: src,
+                    alt: "",
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_file_sizes() {
+        assert_eq!(format_file_size(0), "0 B");
+        assert_eq!(format_file_size(1023), "1023 B");
+        assert_eq!(format_file_size(1024), "1.0 KB");
+        assert_eq!(format_file_size(1536), "1.5 KB");
+        assert_eq!(format_file_size(10 * 1024), "10 KB");
+        assert_eq!(format_file_size(1024 * 1024), "1.0 MB");
+        assert_eq!(format_file_size(5 * 1024u64.pow(4)), "5.0 TB");
+        // Terabytes are the largest unit
+        assert_eq!(format_file_size(2 * 1024u64.pow(5)), "2048 TB");
+    }
+
+    #[test]
+    fn takes_names_and_extensions_from_paths() {
+        assert_eq!(file_name("/home/ada/photo.png"), "photo.png");
+        assert_eq!(file_name(r"C:\Users\ada\photo.PNG"), "photo.PNG");
+        assert_eq!(file_name("photo.png"), "photo.png");
+        assert_eq!(extension("archive.tar.gz"), "gz");
+        assert_eq!(extension("Photo.JPEG"), "jpeg");
+        assert_eq!(extension("README"), "");
+        assert_eq!(extension("/home/ada.d/README"), "");
+        assert_eq!(guess_mime(r"C:\Users\ada\photo.PNG"), "image/png");
+        assert_eq!(guess_mime("notes"), "application/octet-stream");
+    }
+
+    #[test]
+    fn matches_accept_lists() {
+        assert!(matches_accept("", "notes.txt", "text/plain"));
+        assert!(matches_accept(" , ", "notes.txt", "text/plain"));
+        assert!(matches_accept("image/*", "photo.png", "image/png"));
+        assert!(!matches_accept("image/*", "report.pdf", "application/pdf"));
+        assert!(matches_accept(
+            "image/*, .pdf",
+            "report.pdf",
+            "application/pdf"
+        ));
+        assert!(matches_accept(".PDF", "report.pdf", "application/pdf"));
+        assert!(matches_accept(
+            "application/pdf",
+            "report.pdf",
+            "application/pdf"
+        ));
+        assert!(!matches_accept(
+            "application/pdf",
+            "notes.txt",
+            "text/plain"
+        ));
+        assert!(!matches_accept(".pdf", "pdf", "application/octet-stream"));
+    }
+
+    #[test]
+    fn encodes_data_urls_as_base64() {
+        let encode = |bytes: &[u8]| data_url("text/plain", bytes);
+        assert_eq!(encode(b""), "data:text/plain;base64,");
+        assert_eq!(encode(b"f"), "data:text/plain;base64,Zg==");
+        assert_eq!(encode(b"fo"), "data:text/plain;base64,Zm8=");
+        assert_eq!(encode(b"foo"), "data:text/plain;base64,Zm9v");
+        assert_eq!(encode(b"foob"), "data:text/plain;base64,Zm9vYg==");
+        assert_eq!(encode(b"fooba"), "data:text/plain;base64,Zm9vYmE=");
+        assert_eq!(encode(b"foobar"), "data:text/plain;base64,Zm9vYmFy");
+        assert_eq!(
+            data_url("image/png", &[0xff, 0xfe, 0xfd, 0x00]),
+            "data:image/png;base64,//79AA=="
+        );
+    }
+
+    #[test]
+    fn describes_rejections() {
+        let rejection = FileRejection {
+            name: "video.mp4".to_string(),
+            size: 20 * 1024 * 1024,
+            reason: RejectionReason::TooLarge,
+        };
+        assert_eq!(
+            rejection.message(Some(5 * 1024 * 1024), None),
+            "video.mp4 is larger than 5.0 MB"
+        );
+        assert_eq!(rejection.message(None, None), "video.mp4 is too large");
+    }
+}
@@ -0,0 +1,1423 @@
+use crate::components::label::Label;
+use crate::components::validation::{Rule, ValidationMessages, validate_rules};
+use crate::use_unique_id;
+use dioxus_lib::prelude::*;
+use lucide_dioxus::CircleAlert;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+/// A typed value stored for a registered form field
+#[derive(Clone, PartialEq, Debug)]
+pub enum FieldValue {
+    Text(String),
+    Bool(bool),
+    Number(f64),
+    List(Vec<String>),
+}
+
+impl Default for FieldValue {
+    fn default() -> Self {
+        Self::Text(String::new())
+    }
+}
+
+impl FieldValue {
+    /// Whether the value should be treated as missing by validators
+    pub fn is_empty(&self) -> bool {
+        match self {
+            FieldValue::Text(text) => text.trim().is_empty(),
+            FieldValue::Bool(checked) => !checked,
+            FieldValue::Number(number) => number.is_nan(),
+            FieldValue::List(items) => items.is_empty(),
+        }
+    }
+
+    /// Parse raw input text into a value of the same kind as `self`
+    pub fn coerce(&self, raw: &str) -> FieldValue {
+        match self {
+            FieldValue::Number(_) => match raw.trim().parse::<f64>() {
+                Ok(number) => FieldValue::Number(number),
+                // Keep unparsable text so validators can report it
+                Err(_) if !raw.trim().is_empty() => FieldValue::Text(raw.to_string()),
+                Err(_) => FieldValue::Number(f64::NAN),
+            },
+            FieldValue::Bool(_) => FieldValue::Bool(matches!(raw, "true" | "on" | "1")),
+            FieldValue::List(_) => FieldValue::List(
+                raw.split(',')
+                    .map(|item| item.trim().to_string())
+                    .filter(|item| !item.is_empty())
+                    .collect(),
+            ),
+            FieldValue::Text(_) => FieldValue::Text(raw.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for FieldValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FieldValue::Text(text) => write!(f, "{text}"),
+            FieldValue::Bool(checked) => write!(f, "{checked}"),
+            FieldValue::Number(number) if number.is_nan() => Ok(()),
+            FieldValue::Number(number) => write!(f, "{number}"),
+            FieldValue::List(items) => write!(f, "{}", items.join(", ")),
+        }
+    }
+}
+
+impl From<String> for FieldValue {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for FieldValue {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl From<bool> for FieldValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<f64> for FieldValue {
+    fn from(value: f64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<i32> for FieldValue {
+    fn from(value: i32) -> Self {
+        Self::Number(value as f64)
+    }
+}
+
+impl From<i64> for FieldValue {
+    fn from(value: i64) -> Self {
+        Self::Number(value as f64)
+    }
+}
+
+impl From<Vec<String>> for FieldValue {
+    fn from(value: Vec<String>) -> Self {
+        Self::List(value)
+    }
+}
+
+// Conversions back out of a field value return the original value on mismatch
+impl TryFrom<FieldValue> for String {
+    type Error = FieldValue;
+
+    fn try_from(value: FieldValue) -> Result<Self, Self::Error> {
+        match value {
+            FieldValue::List(_) => Err(value),
+            other => Ok(other.to_string()),
+        }
+    }
+}
+
+impl TryFrom<FieldValue> for bool {
+    type Error = FieldValue;
+
+    fn try_from(value: FieldValue) -> Result<Self, Self::Error> {
+        match &value {
+            FieldValue::Bool(checked) => Ok(*checked),
+            FieldValue::Text(text) => text.parse().map_err(|_| value.clone()),
+            _ => Err(value),
+        }
+    }
+}
+
+impl TryFrom<FieldValue> for f64 {
+    type Error = FieldValue;
+
+    fn try_from(value: FieldValue) -> Result<Self, Self::Error> {
+        match &value {
+            FieldValue::Number(number) if !number.is_nan() => Ok(*number),
+            FieldValue::Text(text) => text.trim().parse().map_err(|_| value.clone()),
+            _ => Err(value),
+        }
+    }
+}
+
+impl TryFrom<FieldValue> for Vec<String> {
+    type Error = FieldValue;
+
+    fn try_from(value: FieldValue) -> Result<Self, Self::Error> {
+        match value {
+            FieldValue::List(items) => Ok(items),
+            other => Err(other),
+        }
+    }
+}
+
+/// A snapshot of every registered field value, in registration order
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct FormValues(Vec<(String, FieldValue)>);
+
+impl FormValues {
+    /// Get the raw value of a field
+    pub fn get(&self, name: &str) -> Option<&FieldValue> {
+        self.0
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value)
+    }
+
+    /// Get the value of a field converted to `T`
+    pub fn get_as<T: TryFrom<FieldValue>>(&self, name: &str) -> Option<T> {
+        self.get(name)
+            .and_then(|value| T::try_from(value.clone()).ok())
+    }
+
+    /// Get the value of a field as display text
+    pub fn text(&self, name: &str) -> String {
+        self.get(name)
+            .map(|value| value.to_string())
+            .unwrap_or_default()
+    }
+
+    /// Iterate over field names and values
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &FieldValue)> {
+        self.0.iter().map(|(name, value)| (name.as_str(), value))
+    }
+}
+
+impl<N: Into<String>, V: Into<FieldValue>> FromIterator<(N, V)> for FormValues {
+    fn from_iter<I: IntoIterator<Item = (N, V)>>(iter: I) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|(name, value)| (name.into(), value.into()))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(feature = "serde")]
+impl FieldValue {
+    /// Convert the value to JSON. Whole numbers become integers so they deserialize into integer types.
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            FieldValue::Text(text) => serde_json::Value::String(text.clone()),
+            FieldValue::Bool(checked) => serde_json::Value::Bool(*checked),
+            FieldValue::Number(number) if number.is_nan() => serde_json::Value::Null,
+            FieldValue::Number(number)
+                if number.fract() == 0.0 && number.abs() < i64::MAX as f64 =>
+            {
+                serde_json::Value::from(*number as i64)
+            }
+            FieldValue::Number(number) => serde_json::Value::from(*number),
+            FieldValue::List(items) => serde_json::Value::from(items.clone()),
+        }
+    }
+
+    /// Build a value from JSON. `null` becomes empty text.
+    pub fn from_json(value: &serde_json::Value) -> FieldValue {
+        match value {
+            serde_json::Value::Null => FieldValue::default(),
+            serde_json::Value::Bool(checked) => FieldValue::Bool(*checked),
+            serde_json::Value::Number(number) => {
+                FieldValue::Number(number.as_f64().unwrap_or(f64::NAN))
+            }
+            serde_json::Value::String(text) => FieldValue::Text(text.clone()),
+            serde_json::Value::Array(items) => FieldValue::List(
+                items
+                    .iter()
+                    .map(|item| match item {
+                        serde_json::Value::String(text) => text.clone(),
+                        other => other.to_string(),
+                    })
+                    .collect(),
+            ),
+            serde_json::Value::Object(_) => FieldValue::Text(value.to_string()),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl FormValues {
+    /// Collect the values into a JSON object keyed by field name
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::Value::Object(
+            self.0
+                .iter()
+                .map(|(name, value)| (name.clone(), value.to_json()))
+                .collect(),
+        )
+    }
+
+    /// Deserialize the values into `T`, e.g. a struct with one field per form field
+    pub fn deserialize_into<T: serde::de::DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        serde_json::from_value(self.to_json())
+    }
+
+    /// Read values from a JSON object, e.g. to pass a loaded record to `UseForm::reset_with`
+    pub fn from_json(value: &serde_json::Value) -> FormValues {
+        value
+            .as_object()
+            .map(|object| {
+                object
+                    .iter()
+                    .map(|(name, value)| (name.clone(), FieldValue::from_json(value)))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// The result of validating a single field
+pub type ValidationResult = Result<(), String>;
+
+type ValidatorFn = dyn Fn(&FieldValue, &FormValues) -> ValidationResult;
+type AsyncValidatorFn = dyn Fn(FieldValue) -> Pin<Box<dyn Future<Output = ValidationResult>>>;
+
+/// A synchronous validator. It receives the field value and all form values for cross-field checks.
+#[derive(Clone)]
+pub struct Validator(Rc<ValidatorFn>);
+
+impl Validator {
+    pub fn new(validate: impl Fn(&FieldValue, &FormValues) -> ValidationResult + 'static) -> Self {
+        Self(Rc::new(validate))
+    }
+
+    /// Run the validator against a value
+    pub fn validate(&self, value: &FieldValue, values: &FormValues) -> ValidationResult {
+        (self.0)(value, values)
+    }
+}
+
+impl PartialEq for Validator {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for Validator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Validator")
+    }
+}
+
+/// An asynchronous validator, e.g. a server-side uniqueness check
+#[derive(Clone)]
+pub struct AsyncValidator(Rc<AsyncValidatorFn>);
+
+impl AsyncValidator {
+    pub fn new<F, Fut>(validate: F) -> Self
+    where
+        F: Fn(FieldValue) -> Fut + 'static,
+        Fut: Future<Output = ValidationResult> + 'static,
+    {
+        Self(Rc::new(move |value| Box::pin(validate(value))))
+    }
+
+    /// Run the validator against a value
+    pub async fn validate(&self, value: FieldValue) -> ValidationResult {
+        (self.0)(value).await
+    }
+}
+
+impl PartialEq for AsyncValidator {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for AsyncValidator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("AsyncValidator")
+    }
+}
+
+/// When a field is validated
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ValidationMode {
+    /// Validate on every change
+    OnChange,
+    /// Validate when the field loses focus, then on every change once it has an error
+    OnBlur,
+    /// Validate only on submit, then on every change once it has an error
+    OnSubmit,
+}
+
+impl Default for ValidationMode {
+    fn default() -> Self {
+        Self::OnBlur
+    }
+}
+
+/// Registration options for a single form field
+#[derive(Clone, PartialEq, Debug)]
+pub struct FieldConfig {
+    name: String,
+    default: FieldValue,
+    mode: ValidationMode,
+    rules: Vec<Rule>,
+    validators: Vec<Validator>,
+    async_validators: Vec<AsyncValidator>,
+}
+
+impl FieldConfig {
+    /// Register a field by name with its default value. The value type is taken from the default.
+    pub fn new(name: impl Into<String>, default: impl Into<FieldValue>) -> Self {
+        Self {
+            name: name.into(),
+            default: default.into(),
+            mode: ValidationMode::default(),
+            rules: Vec::new(),
+            validators: Vec::new(),
+            async_validators: Vec::new(),
+        }
+    }
+
+    /// Add a validation rule. Rules run before custom validators.
+    pub fn rule(mut self, rule: Rule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Add several validation rules
+    pub fn rules(mut self, rules: impl IntoIterator<Item = Rule>) -> Self {
+        self.rules.extend(rules);
+        self
+    }
+
+    /// Add a synchronous validator
+    pub fn validate(
+        self,
+        validate: impl Fn(&FieldValue, &FormValues) -> ValidationResult + 'static,
+    ) -> Self {
+        self.validator(Validator::new(validate))
+    }
+
+    /// Add a prebuilt synchronous validator
+    pub fn validator(mut self, validator: Validator) -> Self {
+        self.validators.push(validator);
+        self
+    }
+
+    /// Add an asynchronous validator. It only runs once all synchronous validators pass.
+    pub fn validate_async<F, Fut>(mut self, validate: F) -> Self
+    where
+        F: Fn(FieldValue) -> Fut + 'static,
+        Fut: Future<Output = ValidationResult> + 'static,
+    {
+        self.async_validators.push(AsyncValidator::new(validate));
+        self
+    }
+
+    /// Set when this field is validated
+    pub fn mode(mut self, mode: ValidationMode) -> Self {
+        self.mode = mode;
+        self
+    }
+}
+
+// Runtime state for a registered field
+struct FieldEntry {
+    config: FieldConfig,
+    value: FieldValue,
+    touched: bool,
+    error: Option<String>,
+    validating: bool,
+    // Incremented on every validation so stale async results can be ignored
+    run: usize,
+}
+
+impl FieldEntry {
+    fn new(config: FieldConfig) -> Self {
+        Self {
+            value: config.default.clone(),
+            config,
+            touched: false,
+            error: None,
+            validating: false,
+            run: 0,
+        }
+    }
+
+    // Record a synchronous result, which supersedes any async validation still running
+    fn finish_sync(&mut self, error: Option<String>) {
+        self.run += 1;
+        self.validating = false;
+        self.error = error;
+    }
+
+    // Record an async result unless the field was validated or reset after `run` started
+    fn finish_async(&mut self, run: usize, error: Option<String>) {
+        if self.run == run {
+            self.validating = false;
+            self.error = error;
+        }
+    }
+
+    fn reset(&mut self) {
+        // Keep counting runs so pending async validation does not overwrite the reset
+        let run = self.run + 1;
+        *self = FieldEntry {
+            run,
+            ..FieldEntry::new(self.config.clone())
+        };
+    }
+
+    fn reset_with(&mut self, values: &FormValues) {
+        if let Some(value) = values.get(&self.config.name) {
+            self.config.default = value.clone();
+        }
+        self.reset();
+    }
+}
+
+/// Handle to the state of a form created with `use_form`
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseForm {
+    id: Signal<String>,
+    fields: Signal<Vec<FieldEntry>>,
+    submitting: Signal<bool>,
+    submit_count: Signal<usize>,
+    messages: Signal<ValidationMessages>,
+}
+
+/// Create form state with the given fields registered
+pub fn use_form(init: impl FnOnce() -> Vec<FieldConfig>) -> UseForm {
+    let id = use_unique_id();
+    let fields = use_signal(|| init().into_iter().map(FieldEntry::new).collect());
+    let submitting = use_signal(|| false);
+    let submit_count = use_signal(|| 0);
+    // Rule messages come from the nearest provided ValidationMessages
+    let provided_messages = try_use_context::<ValidationMessages>();
+    let messages = use_signal(|| provided_messages.unwrap_or_default());
+
+    UseForm {
+        id,
+        fields,
+        submitting,
+        submit_count,
+        messages,
+    }
+}
+
+impl UseForm {
+    /// Register a field after the form was created. Existing fields keep their state.
+    pub fn register(&self, config: FieldConfig) {
+        let mut fields = self.fields;
+        if !fields.peek().iter().any(|f| f.config.name == config.name) {
+            fields.write().push(FieldEntry::new(config));
+        }
+    }
+
+    /// Replace the rules of a registered field. Does nothing if the rules are unchanged.
+    pub fn set_rules(&self, name: &str, rules: Vec<Rule>) {
+        // Writing would re-render every reader of the form, so skip it when nothing changed
+        if self
+            .fields
+            .peek()
+            .iter()
+            .any(|f| f.config.name == name && f.config.rules == rules)
+        {
+            return;
+        }
+        self.write_field(name, |f| f.config.rules = rules);
+    }
+
+    /// Set the rules of a field, registering it with the given default if needed.
+    /// Used by inputs that declare their own `rules` inside a `Form`.
+    ///
+    /// `custom` rules compare by closure identity, so build them once (e.g. in a hook or a
+    /// static) rather than inline during render, or every render replaces the rules.
+    pub fn attach_rules(
+        &self,
+        name: &str,
+        default: impl Into<FieldValue>,
+        rules: Vec<Rule>,
+        mode: ValidationMode,
+    ) {
+        let registered = self.fields.peek().iter().any(|f| f.config.name == name);
+        if registered {
+            self.set_rules(name, rules);
+        } else {
+            self.register(FieldConfig::new(name, default).rules(rules).mode(mode));
+        }
+    }
+
+    /// Remove a field from the form
+    pub fn unregister(&self, name: &str) {
+        let mut fields = self.fields;
+        fields.write().retain(|f| f.config.name != name);
+    }
+
+    /// Whether a field with this name is registered
+    pub fn is_registered(&self, name: &str) -> bool {
+        self.fields.read().iter().any(|f| f.config.name == name)
+    }
+
+    fn read_field<T>(&self, name: &str, read: impl FnOnce(&FieldEntry) -> T) -> Option<T> {
+        self.fields
+            .read()
+            .iter()
+            .find(|f| f.config.name == name)
+            .map(read)
+    }
+
+    // Uses try_write since async validation may finish after the form is gone
+    fn write_field(&self, name: &str, write: impl FnOnce(&mut FieldEntry)) {
+        let mut fields = self.fields;
+        if let Ok(mut fields) = fields.try_write()
+            && let Some(field) = fields.iter_mut().find(|f| f.config.name == name)
+        {
+            write(field);
+        }
+    }
+
+    /// The id used for the error message of a field, for `aria-describedby`
+    pub fn error_id(&self, name: &str) -> String {
+        format!("{}-{}-error", self.id.read(), name)
+    }
+
+    /// The current value of a field
+    pub fn value(&self, name: &str) -> FieldValue {
+        self.read_field(name, |f| f.value.clone())
+            .unwrap_or_default()
+    }
+
+    /// The current value of a field converted to `T`
+    pub fn get<T: TryFrom<FieldValue>>(&self, name: &str) -> Option<T> {
+        T::try_from(self.value(name)).ok()
+    }
+
+    /// A snapshot of all field values
+    pub fn values(&self) -> FormValues {
+        FormValues(
+            self.fields
+                .read()
+                .iter()
+                .map(|f| (f.config.name.clone(), f.value.clone()))
+                .collect(),
+        )
+    }
+
+    /// The current error message of a field
+    pub fn error(&self, name: &str) -> Option<String> {
+        self.read_field(name, |f| f.error.clone()).flatten()
+    }
+
+    /// All current errors as (field name, message) pairs
+    pub fn errors(&self) -> Vec<(String, String)> {
+        self.fields
+            .read()
+            .iter()
+            .filter_map(|f| Some((f.config.name.clone(), f.error.clone()?)))
+            .collect()
+    }
+
+    /// Whether the field has lost focus at least once
+    pub fn is_touched(&self, name: &str) -> bool {
+        self.read_field(name, |f| f.touched).unwrap_or(false)
+    }
+
+    /// Whether the field value differs from its default
+    pub fn is_field_dirty(&self, name: &str) -> bool {
+        self.read_field(name, |f| f.value != f.config.default)
+            .unwrap_or(false)
+    }
+
+    /// Whether an async validator is running for the field
+    pub fn is_validating(&self, name: &str) -> bool {
+        self.read_field(name, |f| f.validating).unwrap_or(false)
+    }
+
+    /// Whether any field value differs from its default
+    pub fn is_dirty(&self) -> bool {
+        self.fields
+            .read()
+            .iter()
+            .any(|f| f.value != f.config.default)
+    }
+
+    /// Whether no field currently has an error
+    pub fn is_valid(&self) -> bool {
+        self.fields.read().iter().all(|f| f.error.is_none())
+    }
+
+    /// Whether a submit is waiting on validation or the submit handler
+    pub fn is_submitting(&self) -> bool {
+        (self.submitting)()
+    }
+
+    /// How many times the form has been submitted
+    pub fn submit_count(&self) -> usize {
+        (self.submit_count)()
+    }
+
+    /// Set the value of a field and validate it according to its mode
+    pub fn set_value(&self, name: &str, value: impl Into<FieldValue>) {
+        let value = value.into();
+        self.write_field(name, |f| f.value = value);
+
+        let should_validate = self
+            .fields
+            .peek()
+            .iter()
+            .find(|f| f.config.name == name)
+            .is_some_and(|f| f.config.mode == ValidationMode::OnChange || f.error.is_some());
+
+        if should_validate {
+            self.spawn_validation(name);
+        }
+    }
+
+    /// Set the value of a field from raw input text, keeping the field's value type
+    pub fn set_value_from_str(&self, name: &str, raw: &str) {
+        let Some(value) = self
+            .fields
+            .peek()
+            .iter()
+            .find(|f| f.config.name == name)
+            .map(|f| f.config.default.coerce(raw))
+        else {
+            return;
+        };
+        self.set_value(name, value);
+    }
+
+    /// Mark a field as touched, validating it if its mode is `OnBlur`
+    pub fn touch(&self, name: &str) {
+        self.write_field(name, |f| f.touched = true);
+
+        let should_validate = self
+            .fields
+            .peek()
+            .iter()
+            .find(|f| f.config.name == name)
+            .is_some_and(|f| f.config.mode != ValidationMode::OnSubmit);
+
+        if should_validate {
+            self.spawn_validation(name);
+        }
+    }
+
+    /// Set or clear an error manually, e.g. from a server response
+    pub fn set_error(&self, name: &str, error: Option<String>) {
+        self.write_field(name, |f| f.error = error);
+    }
+
+    fn spawn_validation(&self, name: &str) {
+        // Run synchronous validators immediately so feedback does not wait on a task
+        if self.validate_sync(name) && self.has_async_validators(name) {
+            let form = *self;
+            let name = name.to_string();
+            spawn(async move {
+                form.validate_field(&name).await;
+            });
+        }
+    }
+
+    fn has_async_validators(&self, name: &str) -> bool {
+        self.fields
+            .peek()
+            .iter()
+            .any(|f| f.config.name == name && !f.config.async_validators.is_empty())
+    }
+
+    // Returns whether the synchronous validators passed
+    fn validate_sync(&self, name: &str) -> bool {
+        let values = FormValues(
+            self.fields
+                .peek()
+                .iter()
+                .map(|f| (f.config.name.clone(), f.value.clone()))
+                .collect(),
+        );
+        let Some(error) = self
+            .fields
+            .peek()
+            .iter()
+            .find(|f| f.config.name == name)
+            .map(|f| {
+                validate_rules(&f.config.rules, &f.value, &values, &self.messages.peek())
+                    .and_then(|_| {
+                        f.config
+                            .validators
+                            .iter()
+                            .try_for_each(|validator| validator.validate(&f.value, &values))
+                    })
+                    .err()
+            })
+        else {
+            return true;
+        };
+
+        let valid = error.is_none();
+        self.write_field(name, |f| f.finish_sync(error));
+        valid
+    }
+
+    /// Run all validators for a field, returning whether it is valid
+    pub async fn validate_field(&self, name: &str) -> bool {
+        if !self.validate_sync(name) {
+            return false;
+        }
+
+        let Some((run, value, validators)) = self
+            .fields
+            .peek()
+            .iter()
+            .find(|f| f.config.name == name)
+            .map(|f| (f.run, f.value.clone(), f.config.async_validators.clone()))
+        else {
+            return true;
+        };
+
+        if validators.is_empty() {
+            return true;
+        }
+
+        self.write_field(name, |f| f.validating = true);
+
+        let mut error = None;
+        for validator in validators {
+            if let Err(message) = validator.validate(value.clone()).await {
+                error = Some(message);
+                break;
+            }
+        }
+
+        let valid = error.is_none();
+        self.write_field(name, |f| f.finish_async(run, error));
+        valid
+    }
+
+    /// Touch and validate every field, returning whether the whole form is valid
+    pub async fn validate(&self) -> bool {
+        let mut fields = self.fields;
+        let names = fields
+            .write()
+            .iter_mut()
+            .map(|f| {
+                f.touched = true;
+                f.config.name.clone()
+            })
+            .collect::<Vec<_>>();
+
+        let mut valid = true;
+        for name in names {
+            valid &= self.validate_field(&name).await;
+        }
+        valid
+    }
+
+    /// Collect the field values into a JSON object
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> serde_json::Value {
+        self.values().to_json()
+    }
+
+    /// Deserialize the field values into `T`
+    #[cfg(feature = "serde")]
+    pub fn deserialize_into<T: serde::de::DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        self.values().deserialize_into()
+    }
+
+    /// Validate the form and call `on_valid` with the values only if every field passes.
+    /// Returns `false` without validating while another submit is still running.
+    pub async fn submit(&self, on_valid: impl FnOnce(FormValues)) -> bool {
+        let mut submitting = self.submitting;
+        let mut submit_count = self.submit_count;
+        // Ignore a double click while validation or the handler is still running
+        if *submitting.peek() {
+            return false;
+        }
+        submitting.set(true);
+        *submit_count.write() += 1;
+
+        let valid = self.validate().await;
+        if valid {
+            on_valid(self.values());
+        }
+
+        if let Ok(mut submitting) = submitting.try_write() {
+            *submitting = false;
+        }
+        valid
+    }
+
+    /// Reset every field to its default value and clear touched and error state
+    pub fn reset(&self) {
+        let mut fields = self.fields;
+        for field in fields.write().iter_mut() {
+            field.reset();
+        }
+        let mut submit_count = self.submit_count;
+        submit_count.set(0);
+    }
+
+    /// Replace the default values of the given fields and reset the form to them
+    pub fn reset_with(&self, values: FormValues) {
+        let mut fields = self.fields;
+        for field in fields.write().iter_mut() {
+            field.reset_with(&values);
+        }
+        let mut submit_count = self.submit_count;
+        submit_count.set(0);
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct FormProps {
+    /// The form state created with `use_form`
+    form: UseForm,
+
+    /// Called with the field values once every validator passes
+    #[props(default)]
+    on_submit: Option<EventHandler<FormValues>>,
+
+    /// Called with the field errors when a submit fails validation
+    #[props(default)]
+    on_invalid: Option<EventHandler<Vec<(String, String)>>>,
+
+    /// Optional additional classes for the form
+    #[props(default)]
+    class: Option<String>,
+
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+
+    children: Element,
+}
+
+/// A form element that provides its `use_form` state to inputs inside it.
+/// Inputs whose `name` matches a registered field are bound automatically.
+#[component]
+pub fn Form(props: FormProps) -> Element {
+    let form = use_context_provider(|| props.form);
+
+    let form_classes = vec!["space-y-4", props.class.as_deref().unwrap_or("")]
+        .into_iter()
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let handle_submit = move |event: FormEvent| {
+        event.prevent_default();
+        if form.is_submitting() {
+            return;
+        }
+        let on_submit = props.on_submit;
+        let on_invalid = props.on_invalid;
+        spawn(async move {
+            let valid = form
+                .submit(|values| {
+                    if let Some(handler) = on_submit {
+                        handler.call(values);
+                    }
+                })
+                .await;
+            if !valid && let Some(handler) = on_invalid {
+                handler.call(form.errors());
+            }
+        });
+    };
+
+    let handle_reset = move |event: FormEvent| {
+        event.prevent_default();
+        form.reset();
+    };
+
+    rsx! {
+        form {
+            class: form_classes,
+            // Validation is handled by the form state instead of the browser
+            novalidate: true,
+            onsubmit: handle_submit,
+            onreset: handle_reset,
+            ..props.attributes,
+            {props.children}
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct FieldErrorProps {
+    /// Name of the registered field
+    name: String,
+
+    /// Optional additional classes for the message
+    #[props(default)]
+    class: Option<String>,
+}
+
+/// Renders the current error of a field with the id inputs reference in `aria-describedby`
+#[component]
+pub fn FieldError(props: FieldErrorProps) -> Element {
+    let form = use_context::<UseForm>();
+
+    let classes = vec![
+        "text-destructive text-xs flex items-center mt-1",
+        props.class.as_deref().unwrap_or(""),
+    ]
+    .into_iter()
+    .filter(|s| !s.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ");
+
+    rsx! {
+        if let Some(error) = form.error(&props.name) {
+            div {
+                id: form.error_id(&props.name),
+                class: classes,
+                role: "alert",
+                CircleAlert { size: 14, class: "mr-1 text-destructive" }
+                "{error}"
+            }
+        }
+    }
+}
+
+/// Ids and state shared between the parts of a `FormField`
+#[derive(Clone, Copy, PartialEq)]
+pub struct FormFieldContext {
+    control_id: Memo<String>,
+    label_id: Memo<String>,
+    description_id: Memo<String>,
+    message_id: Memo<String>,
+    name: ReadOnlySignal<Option<String>>,
+    required: ReadOnlySignal<bool>,
+    error: Memo<Option<String>>,
+    has_description: Signal<bool>,
+}
+
+impl FormFieldContext {
+    /// The id of the control, referenced by the label's `for` attribute
+    pub fn control_id(&self) -> String {
+        (self.control_id)()
+    }
+
+    /// The id of the label
+    pub fn label_id(&self) -> String {
+        (self.label_id)()
+    }
+
+    /// The name of the field, used to bind controls to a `Form`
+    pub fn name(&self) -> Option<String> {
+        (self.name)()
+    }
+
+    /// Whether the field is required
+    pub fn is_required(&self) -> bool {
+        (self.required)()
+    }
+
+    /// The current error message of the field
+    pub fn error(&self) -> Option<String> {
+        (self.error)()
+    }
+
+    /// Whether the field currently has an error
+    pub fn is_invalid(&self) -> bool {
+        self.error.read().is_some()
+    }
+
+    /// Space separated ids of the rendered description and error message
+    pub fn describedby(&self) -> Option<String> {
+        let ids = [
+            (self.has_description)().then(|| (self.description_id)()),
+            self.is_invalid().then(|| (self.message_id)()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+        (!ids.is_empty()).then(|| ids.join(" "))
+    }
+}
+
+// Provided by FormControl so only the wrapped control picks up the field ids
+#[derive(Clone, Copy, PartialEq)]
+struct FormControlContext(FormFieldContext);
+
+/// The field context of the surrounding `FormControl`, if any.
+/// Controls use it to take their id, name and ARIA attributes from the field.
+pub fn use_form_control() -> Option<FormFieldContext> {
+    try_use_context::<FormControlContext>().map(|control| control.0)
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct FormFieldProps {
+    /// Name of the field. Inside a `Form`, errors of the registered field with this name are shown.
+    #[props(default)]
+    name: ReadOnlySignal<Option<String>>,
+
+    /// Optional ID for the control. Generated when not provided.
+    #[props(default)]
+    id: ReadOnlySignal<Option<String>>,
+
+    /// Whether the field is required. Drives the label asterisk and `aria-required`.
+    #[props(default)]
+    required: ReadOnlySignal<bool>,
+
+    /// Error message to show, overriding the form state
+    #[props(default)]
+    error: ReadOnlySignal<Option<String>>,
+
+    /// Optional additional classes for the field container
+    #[props(default)]
+    class: Option<String>,
+
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+
+    children: Element,
+}
+
+/// Groups a label, control, description and error message, wiring their ids together
+#[component]
+pub fn FormField(props: FormFieldProps) -> Element {
+    // Generate the ids of every part from a single unique id
+    let field_id = use_unique_id();
+    let user_id = props.id;
+    let control_id =
+        use_memo(move || user_id().unwrap_or_else(|| format!("{}-control", field_id())));
+    let label_id = use_memo(move || format!("{}-label", field_id()));
+    let description_id = use_memo(move || format!("{}-description", field_id()));
+    let message_id = use_memo(move || format!("{}-message", field_id()));
+
+    // Explicit errors win over errors of the registered form field
+    let form = try_use_context::<UseForm>();
+    let name = props.name;
+    let explicit_error = props.error;
+    let error = use_memo(move || {
+        explicit_error().or_else(|| {
+            let name = name()?;
+            form?.error(&name)
+        })
+    });
+
+    use_context_provider(|| FormFieldContext {
+        control_id,
+        label_id,
+        description_id,
+        message_id,
+        name: props.name,
+        required: props.required,
+        error,
+        has_description: Signal::new(false),
+    });
+
+    let field_classes = vec!["space-y-1", props.class.as_deref().unwrap_or("")]
+        .into_iter()
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    rsx! {
+        div {
+            class: field_classes,
+            "data-invalid": error.read().is_some().to_string(),
+            ..props.attributes,
+            {props.children}
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct FormLabelProps {
+    /// Optional additional classes for the label
+    #[props(default)]
+    class: Option<String>,
+
+    children: Element,
+}
+
+/// A label linked to the control of the surrounding `FormField`
+#[component]
+pub fn FormLabel(props: FormLabelProps) -> Element {
+    let field = use_context::<FormFieldContext>();
+
+    let label_classes = vec![
+        if field.is_invalid() {
+            "text-destructive"
+        } else {
+            ""
+        },
+        props.class.as_deref().unwrap_or(""),
+    ]
+    .into_iter()
+    .filter(|s| !s.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ");
+
+    rsx! {
+        Label {
+            id: Some(field.label_id()),
+            for_id: Some(field.control_id()),
+            required: field.is_required(),
+            class: label_classes,
+            {props.children}
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct FormControlProps {
+    children: Element,
+}
+
+/// Wraps the control of a `FormField`. `Input`, `Checkbox` and `Switch` inside it take
+/// their id, name, `aria-describedby`, `aria-invalid` and `aria-required` from the field.
+#[component]
+pub fn FormControl(props: FormControlProps) -> Element {
+    let field = use_context::<FormFieldContext>();
+    use_context_provider(|| FormControlContext(field));
+
+    rsx! {
+        {props.children}
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct FormDescriptionProps {
+    /// Optional additional classes for the description
+    #[props(default)]
+    class: Option<String>,
+
+    children: Element,
+}
+
+/// Helper text for the surrounding `FormField`, referenced by the control's `aria-describedby`
+#[component]
+pub fn FormDescription(props: FormDescriptionProps) -> Element {
+    let field = use_context::<FormFieldContext>();
+
+    // Only reference the description while it is mounted
+    let mut has_description = field.has_description;
+    use_effect(move || has_description.set(true));
+    use_drop(move || {
+        if let Ok(mut has_description) = has_description.try_write() {
+            *has_description = false;
+        }
+    });
+
+    let description_classes = vec![
+        "text-xs text-muted-foreground",
+        props.class.as_deref().unwrap_or(""),
+    ]
+    .into_iter()
+    .filter(|s| !s.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ");
+
+    rsx! {
+        p {
+            id: (field.description_id)(),
+            class: description_classes,
+            {props.children}
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct FormMessageProps {
+    /// Optional additional classes for the message
+    #[props(default)]
+    class: Option<String>,
+}
+
+/// Shows the error of the surrounding `FormField`, referenced by the control's `aria-describedby`
+#[component]
+pub fn FormMessage(props: FormMessageProps) -> Element {
+    let field = use_context::<FormFieldContext>();
+
+    let message_classes = vec![
+        "text-destructive text-xs flex items-center mt-1",
+        props.class.as_deref().unwrap_or(""),
+    ]
+    .into_iter()
+    .filter(|s| !s.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ");
+
+    rsx! {
+        if let Some(error) = field.error() {
+            div {
+                id: (field.message_id)(),
+                class: message_classes,
+                role: "alert",
+                CircleAlert { size: 14, class: "mr-1 text-destructive" }
+                "{error}"
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coerce_keeps_the_value_type() {
+        let number = FieldValue::Number(0.0);
+        assert_eq!(number.coerce(" 42 "), FieldValue::Number(42.0));
+        assert_eq!(number.coerce("4x"), FieldValue::Text("4x".to_string()));
+        assert!(matches!(number.coerce("  "), FieldValue::Number(n) if n.is_nan()));
+
+        let flag = FieldValue::Bool(false);
+        assert_eq!(flag.coerce("on"), FieldValue::Bool(true));
+        assert_eq!(flag.coerce("true"), FieldValue::Bool(true));
+        assert_eq!(flag.coerce("off"), FieldValue::Bool(false));
+
+        let list = FieldValue::List(Vec::new());
+        assert_eq!(
+            list.coerce("a, b,,c "),
+            FieldValue::List(vec!["a".to_string(), "b".to_string(), "c".to_string()])
+        );
+
+        let text = FieldValue::default();
+        assert_eq!(text.coerce(" raw "), FieldValue::Text(" raw ".to_string()));
+    }
+
+    #[test]
+    fn empty_values() {
+        assert!(FieldValue::Text("  ".to_string()).is_empty());
+        assert!(FieldValue::Bool(false).is_empty());
+        assert!(FieldValue::Number(f64::NAN).is_empty());
+        assert!(FieldValue::List(Vec::new()).is_empty());
+        assert!(!FieldValue::Number(0.0).is_empty());
+        assert_eq!(FieldValue::Number(f64::NAN).to_string(), "");
+    }
+
+    #[test]
+    fn try_from_converts_or_returns_the_value() {
+        assert_eq!(
+            String::try_from(FieldValue::Number(3.5)),
+            Ok("3.5".to_string())
+        );
+        assert_eq!(
+            String::try_from(FieldValue::List(vec!["a".to_string()])),
+            Err(FieldValue::List(vec!["a".to_string()]))
+        );
+
+        assert_eq!(bool::try_from(FieldValue::Bool(true)), Ok(true));
+        assert_eq!(bool::try_from(FieldValue::from("false")), Ok(false));
+        assert_eq!(
+            bool::try_from(FieldValue::from("yes")),
+            Err(FieldValue::from("yes"))
+        );
+
+        assert_eq!(f64::try_from(FieldValue::from(7)), Ok(7.0));
+        assert_eq!(f64::try_from(FieldValue::from(" 2.5 ")), Ok(2.5));
+        assert!(f64::try_from(FieldValue::Number(f64::NAN)).is_err());
+        assert!(f64::try_from(FieldValue::Bool(true)).is_err());
+
+        assert_eq!(
+            Vec::<String>::try_from(FieldValue::List(vec!["x".to_string()])),
+            Ok(vec!["x".to_string()])
+        );
+        assert!(Vec::<String>::try_from(FieldValue::from("x")).is_err());
+    }
+
+    #[test]
+    fn form_values_lookup() {
+        let values = [
+            ("age", FieldValue::from(30)),
+            ("name", FieldValue::from("Ada")),
+        ]
+        .into_iter()
+        .collect::<FormValues>();
+        assert_eq!(values.get_as::<f64>("age"), Some(30.0));
+        assert_eq!(values.get_as::<bool>("age"), None);
+        assert_eq!(values.text("name"), "Ada");
+        assert_eq!(values.text("missing"), "");
+        assert_eq!(
+            values.iter().map(|(name, _)| name).collect::<Vec<_>>(),
+            ["age", "name"]
+        );
+    }
+
+    #[test]
+    fn stale_async_results_are_ignored() {
+        let mut field = FieldEntry::new(FieldConfig::new("username", ""));
+
+        // An async check starts, then the value changes and is validated again
+        let run = field.run;
+        field.validating = true;
+        field.finish_sync(Some("Too short".to_string()));
+        field.finish_async(run, None);
+        assert_eq!(field.error.as_deref(), Some("Too short"));
+        assert!(!field.validating);
+
+        // The latest run still applies
+        let run = field.run;
+        field.validating = true;
+        field.finish_async(run, Some("Taken".to_string()));
+        assert_eq!(field.error.as_deref(), Some("Taken"));
+        assert!(!field.validating);
+    }
+
+    #[test]
+    fn reset_restores_defaults_and_drops_pending_results() {
+        let mut field = FieldEntry::new(FieldConfig::new("name", "Ada"));
+        field.value = FieldValue::from("Grace");
+        field.touched = true;
+        field.error = Some("Invalid".to_string());
+
+        let run = field.run;
+        field.reset();
+        assert_eq!(field.value, FieldValue::from("Ada"));
+        assert!(!field.touched);
+        assert_eq!(field.error, None);
+
+        field.finish_async(run, Some("Taken".to_string()));
+        assert_eq!(field.error, None);
+    }
+
+    #[test]
+    fn reset_with_replaces_only_given_defaults() {
+        let values = [("name", "Grace")].into_iter().collect::<FormValues>();
+
+        let mut name = FieldEntry::new(FieldConfig::new("name", "Ada"));
+        name.reset_with(&values);
+        assert_eq!(name.value, FieldValue::from("Grace"));
+        assert_eq!(name.config.default, FieldValue::from("Grace"));
+
+        let mut email = FieldEntry::new(FieldConfig::new("email", "ada@example.com"));
+        email.value = FieldValue::from("changed");
+        email.reset_with(&values);
+        assert_eq!(email.value, FieldValue::from("ada@example.com"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json_round_trip() {
+        use serde_json::json;
+
+        assert_eq!(FieldValue::Number(3.0).to_json(), json!(3));
+        assert_eq!(FieldValue::Number(2.5).to_json(), json!(2.5));
+        assert_eq!(FieldValue::Number(f64::NAN).to_json(), json!(null));
+        assert_eq!(
+            FieldValue::List(vec!["a".to_string()]).to_json(),
+            json!(["a"])
+        );
+
+        assert_eq!(FieldValue::from_json(&json!(null)), FieldValue::default());
+        assert_eq!(FieldValue::from_json(&json!(4)), FieldValue::Number(4.0));
+        assert_eq!(
+            FieldValue::from_json(&json!(["a", 1])),
+            FieldValue::List(vec!["a".to_string(), "1".to_string()])
+        );
+
+        let value = json!({ "name": "Ada", "age": 36, "admin": true });
+        let values = FormValues::from_json(&value);
+        assert_eq!(values.text("name"), "Ada");
+        assert_eq!(values.get_as::<f64>("age"), Some(36.0));
+        assert_eq!(values.to_json(), value);
+        assert_eq!(
+            FormValues::from_json(&json!("not an object")),
+            FormValues::default()
+        );
+    }
+}
@@ -1,5 +1,8 @@
+use crate::components::form::{FieldValue, FormValues, UseForm, ValidationMode, use_form_control};
+use crate::components::validation::{Rule, ValidationMessages, validate_rules};
 use crate::{use_id_or, use_unique_id};
 use dioxus_lib::prelude::*;
+use lucide_dioxus::CircleAlert;
 
 /// Input size options
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
@@ -74,10 +77,18 @@ pub struct InputProps {
     #[props(default)]
     icon_right: Option<Element>,
 
+    /// Whether `icon_right` is interactive, e.g. a button, and must stay visible to assistive technology
+    #[props(default)]
+    icon_right_interactive: bool,
+
     /// Callback when the input value changes
     #[props(default)]
     on_change: Option<Callback<FormEvent>>,
 
+    /// Callback on every keystroke, before the value is committed
+    #[props(default)]
+    on_input: Option<Callback<FormEvent>>,
+
     /// Callback when the input is focused
     #[props(default)]
     on_focus: Option<Callback<FocusEvent>>,
@@ -90,6 +101,14 @@ pub struct InputProps {
     #[props(default)]
     name: String,
 
+    /// Optional autocomplete hint, e.g. `email` or `new-password`
+    #[props(default)]
+    autocomplete: Option<String>,
+
+    /// Optional step for number inputs, e.g. `1` or `any`
+    #[props(default)]
+    step: Option<String>,
+
     /// Optional ID for the input
     #[props(default)]
     id: Option<String>,
@@ -106,6 +125,16 @@ pub struct InputProps {
     #[props(default)]
     aria_describedby: Option<String>,
 
+    /// Validation rules. Inside a `Form` they are added to the field with this input's name.
+    /// Create `custom` rules once, e.g. with `use_hook`, since a new closure on every render
+    /// counts as a change.
+    #[props(default)]
+    rules: Vec<Rule>,
+
+    /// When the rules are checked. Outside a `Form`, `OnSubmit` behaves like `OnBlur`.
+    #[props(default)]
+    validation_mode: ValidationMode,
+
     /// Optional additional classes for the input
     #[props(default)]
     class: Option<String>,
@@ -116,13 +145,98 @@ pub struct InputProps {
 
 #[component]
 pub fn Input(props: InputProps) -> Element {
+    // Take the id, name and ARIA state from the surrounding FormControl, if any
+    let control = use_form_control();
+
     // Generate unique ID if not provided
     let input_id = use_unique_id();
-    let props_id = use_signal(|| props.id);
+    let props_id = use_signal(|| {
+        props
+            .id
+            .clone()
+            .or_else(|| control.map(|control| control.control_id()))
+    });
     let id_value = use_id_or(input_id, props_id.into());
 
+    let name = if props.name.is_empty() {
+        control
+            .and_then(|control| control.name())
+            .unwrap_or_default()
+    } else {
+        props.name.clone()
+    };
+
+    // Inside a Form, rules are attached to the field with this name so they run with the form
+    let form_context = try_use_context::<UseForm>();
+    let rules_name = name.clone();
+    let rules_default = props.value.clone();
+    let validation_mode = props.validation_mode;
+    use_effect(use_reactive((&props.rules,), move |(rules,)| {
+        if let Some(form) = form_context
+            && !rules_name.is_empty()
+            && !rules.is_empty()
+        {
+            form.attach_rules(&rules_name, rules_default.clone(), rules, validation_mode);
+        }
+    }));
+
+    // Bind to the surrounding Form when a field with this name is registered
+    let form = form_context.filter(|form| !name.is_empty() && form.is_registered(&name));
+    let field_error = form.and_then(|form| form.error(&name));
+
+    // Outside a Form, rules are checked locally and the error is rendered below the input
+    let mut rule_error = use_signal(|| None::<String>);
+    let mut latest_value = use_signal(|| None::<String>);
+    let messages = try_use_context::<ValidationMessages>().unwrap_or_default();
+    let local_rules = (form.is_none() && !props.rules.is_empty()).then(|| props.rules.clone());
+    let has_local_rules = local_rules.is_some();
+    let check_rules = use_callback(move |text: String| {
+        if let Some(rules) = &local_rules {
+            let error = validate_rules(
+                rules,
+                &FieldValue::Text(text),
+                &FormValues::default(),
+                &messages,
+            )
+            .err();
+            rule_error.set(error);
+        }
+    });
+    let local_error = if has_local_rules { rule_error() } else { None };
+    let local_error_id = format!("{}-error", id_value());
+
+    let is_invalid = field_error.is_some()
+        || local_error.is_some()
+        || control.is_some_and(|control| control.is_invalid());
+    let is_required = props.required || control.is_some_and(|control| control.is_required());
+
+    let variant = if is_invalid {
+        InputVariant::Error
+    } else {
+        props.variant
+    };
+
+    let value = match form {
+        Some(form) => form.value(&name).to_string(),
+        None => props.value.clone(),
+    };
+
+    // Point assistive technology at the description and error message while they are shown
+    let described_by = props
+        .aria_describedby
+        .iter()
+        .cloned()
+        .chain(control.and_then(|control| control.describedby()))
+        .chain(match (form, control) {
+            (Some(form), None) if field_error.is_some() => Some(form.error_id(&name)),
+            _ => None,
+        })
+        .chain(local_error.as_ref().map(|_| local_error_id.clone()))
+        .collect::<Vec<_>>();
+    let aria_describedby = (!described_by.is_empty()).then(|| described_by.join(" "));
+
     // Determine variant classes
-    let variant_classes = match props.variant {
+    let variant_classes = match variant {
         InputVariant::Default => "border-input focus:border-ring",
         InputVariant::Error => "border-destructive focus:border-destructive",
     };
@@ -189,6 +303,24 @@ pub fn Input(props: InputProps) -> Element {
     .collect::<Vec<_>>()
     .join(" ");
 
+    // Keep the bound field value in sync on every keystroke
+    let input_name = name.clone();
+    let handle_input = move |event: FormEvent| {
+        if let Some(form) = form {
+            form.set_value_from_str(&input_name, &event.value());
+        }
+        if has_local_rules {
+            latest_value.set(Some(event.value()));
+            // Re-check on every change once an error is shown
+            if validation_mode == ValidationMode::OnChange || rule_error.peek().is_some() {
+                check_rules.call(event.value());
+            }
+        }
+        if let Some(callback) = &props.on_input {
+            callback.call(event);
+        }
+    };
+
     // Handle input change event
     let handle_change = move |event: FormEvent| {
         if let Some(callback) = &props.on_change {
@@ -204,7 +336,19 @@ pub fn Input(props: InputProps) -> Element {
     };
 
     // Handle blur event
+    let blur_name = name.clone();
+    let blur_value = props.value.clone();
     let handle_blur = move |event: FocusEvent| {
+        if let Some(form) = form {
+            form.touch(&blur_name);
+        }
+        if has_local_rules {
+            let text = latest_value
+                .peek()
+                .clone()
+                .unwrap_or_else(|| blur_value.clone());
+            check_rules.call(text);
+        }
         if let Some(callback) = &props.on_blur {
             callback.call(event);
         }
@@ -227,15 +371,18 @@ pub fn Input(props: InputProps) -> Element {
                 // Standard HTML attributes
                 id: id_value,
                 type: props.input_type.clone(),
-                name: props.name,
+                name: name,
                 placeholder: props.placeholder,
-                value: props.value,
+                value: value,
                 disabled: props.disabled,
                 readonly: props.readonly,
-                required: props.required,
+                required: is_required,
+                autocomplete: props.autocomplete.clone(),
+                step: props.step.clone(),
                 class: input_classes,
 
                 // Event handlers
+                oninput: handle_input,
                 onchange: handle_change,
                 onfocus: handle_focus,
                 onblur: handle_blur,
@@ -243,9 +390,10 @@ pub fn Input(props: InputProps) -> Element {
                 // ARIA attributes
                 aria_label: props.aria_label.clone(),
                 aria_labelledby: props.aria_labelledby.clone(),
-                aria_describedby: props.aria_describedby.clone(),
+                aria_describedby: aria_describedby,
+                aria_invalid: is_invalid.then_some("true"),
                 aria_disabled: props.disabled.to_string(),
-                aria_required: props.required.to_string(),
+                aria_required: is_required.to_string(),
 
                 // Pass through other attributes
                 ..props.attributes,
@@ -255,10 +403,21 @@ pub fn Input(props: InputProps) -> Element {
             if let Some(icon) = &props.icon_right {
                 div {
                     class: "absolute right-0 inset-y-0 flex items-center pr-2 text-foreground",
-                    aria_hidden: "true",
+                    aria_hidden: (!props.icon_right_interactive).then_some("true"),
                     {icon.clone()}
                 }
             }
         }
+
+        // Error message for rules checked outside a Form
+        if let Some(error) = local_error {
+            div {
+                id: local_error_id,
+                class: "text-destructive text-xs flex items-center mt-1",
+                role: "alert",
+                CircleAlert { size: 14, class: "mr-1 text-destructive" }
+                "{error}"
+            }
+        }
     }
 }
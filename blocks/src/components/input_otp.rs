@@ -0,0 +1,378 @@
+use crate::components::form::{UseForm, use_form_control};
+use crate::components::input::{InputSize, InputVariant};
+use crate::use_unique_id;
+use dioxus_lib::prelude::*;
+use lucide_dioxus::Minus;
+use std::rc::Rc;
+
+/// Characters accepted by an InputOTP
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OtpPattern {
+    /// Digits only
+    Digits,
+    /// Letters and digits
+    Alphanumeric,
+}
+
+impl Default for OtpPattern {
+    fn default() -> Self {
+        Self::Digits
+    }
+}
+
+impl OtpPattern {
+    /// Whether a character may be entered into a slot
+    pub fn accepts(&self, character: char) -> bool {
+        match self {
+            OtpPattern::Digits => character.is_ascii_digit(),
+            OtpPattern::Alphanumeric => character.is_ascii_alphanumeric(),
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct InputOTPProps {
+    /// The current code. The input updates this signal as the code is entered.
+    /// Leave unset to let the input manage its own state.
+    #[props(default)]
+    value: Option<Signal<String>>,
+
+    /// Callback with the code when it changes
+    #[props(default)]
+    on_value_change: Option<EventHandler<String>>,
+
+    /// Callback with the code once every slot is filled
+    #[props(default)]
+    on_complete: Option<EventHandler<String>>,
+
+    /// Number of slots
+    #[props(default = 6)]
+    length: usize,
+
+    /// Sizes of the slot groups, separated visually, e.g. `vec![3, 3]`. Defaults to one group.
+    #[props(default)]
+    groups: Vec<usize>,
+
+    /// Characters accepted in the slots
+    #[props(default)]
+    pattern: OtpPattern,
+
+    /// The variant of the slots
+    #[props(default)]
+    variant: InputVariant,
+
+    /// The size of the slots
+    #[props(default)]
+    size: InputSize,
+
+    /// Whether the input is disabled
+    #[props(default)]
+    disabled: bool,
+
+    /// Name of the input for form submission
+    #[props(default)]
+    name: String,
+
+    /// Optional ID for the first slot
+    #[props(default)]
+    id: Option<String>,
+
+    /// Accessible label for the group of slots
+    #[props(default)]
+    aria_label: Option<String>,
+
+    /// Optional additional classes for the container
+    #[props(default)]
+    class: Option<String>,
+
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+/// A one-time code input split into single-character slots
+#[component]
+pub fn InputOTP(props: InputOTPProps) -> Element {
+    // Take the id, name and ARIA state from the surrounding FormControl, if any
+    let control = use_form_control();
+
+    // Generate unique ID if not provided
+    let otp_id = use_unique_id();
+    let id = props
+        .id
+        .clone()
+        .or_else(|| control.map(|control| control.control_id()))
+        .unwrap_or_else(|| otp_id());
+
+    let name = if props.name.is_empty() {
+        control
+            .and_then(|control| control.name())
+            .unwrap_or_default()
+    } else {
+        props.name.clone()
+    };
+
+    // Bind to the surrounding Form when a field with this name is registered
+    let form =
+        try_use_context::<UseForm>().filter(|form| !name.is_empty() && form.is_registered(&name));
+    let field_error = form.and_then(|form| form.error(&name));
+
+    // Use the provided signal when controlled, otherwise keep the code internally
+    let internal_value = use_signal(String::new);
+    let mut value = props.value.unwrap_or(internal_value);
+
+    let length = props.length;
+    let pattern = props.pattern;
+    let code = match form {
+        Some(form) => form.value(&name).to_string(),
+        None => value(),
+    }
+    .chars()
+    .filter(|c| pattern.accepts(*c))
+    .take(length)
+    .collect::<Vec<_>>();
+
+    let mut slot_refs: Signal<Vec<Option<Rc<MountedData>>>> = use_signal(Vec::new);
+    let focus_slot = move |index: usize| {
+        let slot = slot_refs
+            .peek()
+            .get(index.min(length.saturating_sub(1)))
+            .cloned()
+            .flatten();
+        if let Some(slot) = slot {
+            spawn(async move {
+                let _ = slot.set_focus(true).await;
+            });
+        }
+    };
+
+    // Store a new code and notify listeners
+    let field_name = name.clone();
+    let on_value_change = props.on_value_change;
+    let on_complete = props.on_complete;
+    let set_code = use_callback(move |next: Vec<char>| {
+        let next = next.into_iter().take(length).collect::<String>();
+        if let Some(form) = form {
+            form.set_value(&field_name, next.clone());
+        }
+        if *value.peek() == next {
+            return;
+        }
+        value.set(next.clone());
+        if let Some(handler) = &on_value_change {
+            handler.call(next.clone());
+        }
+        if next.chars().count() == length
+            && let Some(handler) = &on_complete
+        {
+            handler.call(next);
+        }
+    });
+
+    let is_invalid = props.variant == InputVariant::Error
+        || field_error.is_some()
+        || control.is_some_and(|control| control.is_invalid());
+
+    // Determine variant classes
+    let variant_classes = if is_invalid {
+        "border-destructive focus:border-destructive"
+    } else {
+        "border-input focus:border-ring"
+    };
+
+    // Determine size classes
+    let size_classes = match props.size {
+        InputSize::Small => "h-8 w-8 text-sm",
+        InputSize::Medium => "h-10 w-10 text-base",
+        InputSize::Large => "h-12 w-12 text-lg",
+    };
+
+    // Determine state classes
+    let state_class = if props.disabled {
+        "opacity-50 cursor-not-allowed bg-muted"
+    } else {
+        "bg-background"
+    };
+
+    // Generate all the classes
+    let slot_classes = vec![
+        // Base classes
+        "rounded border text-center font-medium text-foreground caret-transparent",
+        "transition-colors focus:outline-none focus:ring-2 focus:ring-ring focus:ring-offset-2",
+        // Variant-specific classes
+        variant_classes,
+        // Size-specific classes
+        size_classes,
+        // State class
+        state_class,
+    ]
+    .join(" ");
+
+    let container_classes = vec![
+        "flex items-center gap-2",
+        props.class.as_deref().unwrap_or(""),
+    ]
+    .into_iter()
+    .filter(|s| !s.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ");
+
+    // Split the slots into groups, putting any slots the groups leave out in a last group
+    let mut groups = Vec::new();
+    let mut start = 0;
+    for size in props.groups.iter().copied().filter(|size| *size > 0) {
+        if start >= length {
+            break;
+        }
+        let end = (start + size).min(length);
+        groups.push(start..end);
+        start = end;
+    }
+    if start < length {
+        groups.push(start..length);
+    }
+
+    let aria_describedby = control
+        .and_then(|control| control.describedby())
+        .or_else(|| {
+            form.filter(|_| field_error.is_some())
+                .map(|form| form.error_id(&name))
+        });
+    let input_mode = match pattern {
+        OtpPattern::Digits => "numeric",
+        OtpPattern::Alphanumeric => "text",
+    };
+    let group_count = groups.len();
+
+    rsx! {
+        div {
+            class: container_classes,
+            role: "group",
+            aria_label: props.aria_label.clone(),
+            aria_labelledby: control
+                .filter(|_| props.aria_label.is_none())
+                .map(|control| control.label_id()),
+            aria_describedby: aria_describedby,
+            ..props.attributes,
+
+            for (group_index, group) in groups.into_iter().enumerate() {
+                div {
+                    key: "{group_index}",
+                    class: "flex items-center gap-2",
+
+                    for index in group {
+                        input {
+                            key: "{index}",
+                            id: if index == 0 { id.clone() } else { format!("{}-{}", id, index) },
+                            type: "text",
+                            class: slot_classes.clone(),
+                            inputmode: input_mode,
+                            // Only the first slot takes the code offered by the browser or OS
+                            autocomplete: if index == 0 { "one-time-code" } else { "off" },
+                            value: code.get(index).map(char::to_string).unwrap_or_default(),
+                            disabled: props.disabled,
+                            aria_label: format!("Character {} of {}", index + 1, length),
+                            aria_invalid: is_invalid.then_some("true"),
+                            onmounted: move |event: MountedEvent| {
+                                let mut refs = slot_refs.write();
+                                if refs.len() <= index {
+                                    refs.resize(index + 1, None);
+                                }
+                                refs[index] = Some(event.data());
+                            },
+                            // Slots fill in order, so focus the first empty one instead of a later slot
+                            onfocus: {
+                                let filled = code.len();
+                                move |_| {
+                                    if index > filled {
+                                        focus_slot(filled);
+                                    }
+                                }
+                            },
+                            oninput: {
+                                let code = code.clone();
+                                move |event: FormEvent| {
+                                    let typed = event.value();
+                                    let mut entered = typed.chars().collect::<Vec<_>>();
+                                    // Typing into a filled slot yields the old and the new character
+                                    if let Some(existing) = code.get(index)
+                                        && entered.len() == 2
+                                        && let Some(position) = entered.iter().position(|c| c == existing)
+                                    {
+                                        entered.remove(position);
+                                    }
+                                    let entered = entered
+                                        .into_iter()
+                                        .filter(|c| pattern.accepts(*c))
+                                        .collect::<Vec<_>>();
+
+                                    let position = index.min(code.len());
+                                    let mut next = code[..position].to_vec();
+                                    if entered.is_empty() {
+                                        // The slot was cleared
+                                        next.extend(code.iter().skip(position + 1));
+                                        set_code.call(next);
+                                        return;
+                                    }
+                                    next.extend(entered.iter());
+                                    next.extend(code.iter().skip(position + entered.len()));
+                                    set_code.call(next);
+                                    focus_slot(position + entered.len());
+                                }
+                            },
+                            onkeydown: {
+                                let code = code.clone();
+                                move |event: KeyboardEvent| match event.key() {
+                                    Key::Backspace => {
+                                        event.prevent_default();
+                                        let mut next = code.clone();
+                                        if index < code.len() {
+                                            next.remove(index);
+                                            set_code.call(next);
+                                        } else if index > 0 && !code.is_empty() {
+                                            next.pop();
+                                            set_code.call(next);
+                                            focus_slot(index - 1);
+                                        }
+                                    }
+                                    Key::ArrowLeft if index > 0 => {
+                                        event.prevent_default();
+                                        focus_slot(index - 1);
+                                    }
+                                    Key::ArrowRight => {
+                                        event.prevent_default();
+                                        focus_slot((index + 1).min(code.len()));
+                                    }
+                                    Key::Home => {
+                                        event.prevent_default();
+                                        focus_slot(0);
+                                    }
+                                    Key::End => {
+                                        event.prevent_default();
+                                        focus_slot(code.len());
+                                    }
+                                    _ => {}
+                                }
+                            },
+                        }
+                    }
+
+                    if group_index + 1 < group_count {
+                        div {
+                            class: "text-muted-foreground",
+                            aria_hidden: "true",
+                            Minus { size: 16 }
+                        }
+                    }
+                }
+            }
+
+            if !name.is_empty() {
+                input {
+                    type: "hidden",
+                    name: name.clone(),
+                    value: code.iter().collect::<String>(),
+                }
+            }
+        }
+    }
+}
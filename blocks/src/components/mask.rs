@@ -0,0 +1,361 @@
+use std::fmt;
+use std::rc::Rc;
+
+type AcceptFn = dyn Fn(char) -> bool;
+
+/// A placeholder character in a mask pattern and the characters it accepts
+#[derive(Clone)]
+pub struct MaskToken {
+    symbol: char,
+    accepts: Rc<AcceptFn>,
+}
+
+impl MaskToken {
+    pub fn new(symbol: char, accepts: impl Fn(char) -> bool + 'static) -> Self {
+        Self {
+            symbol,
+            accepts: Rc::new(accepts),
+        }
+    }
+
+    /// The character that stands for this token in a pattern
+    pub fn symbol(&self) -> char {
+        self.symbol
+    }
+
+    /// Whether a typed character can fill this token
+    pub fn accepts(&self, character: char) -> bool {
+        (self.accepts)(character)
+    }
+}
+
+impl PartialEq for MaskToken {
+    fn eq(&self, other: &Self) -> bool {
+        self.symbol == other.symbol && Rc::ptr_eq(&self.accepts, &other.accepts)
+    }
+}
+
+impl fmt::Debug for MaskToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MaskToken").field(&self.symbol).finish()
+    }
+}
+
+// One position of a parsed pattern
+enum Slot<'a> {
+    Literal(char),
+    Input(&'a MaskToken),
+}
+
+thread_local! {
+    // Shared so that masks built from the same pattern compare equal
+    static DEFAULT_TOKENS: Vec<MaskToken> = vec![
+        MaskToken::new('9', |c| c.is_ascii_digit()),
+        MaskToken::new('a', |c| c.is_alphabetic()),
+        MaskToken::new('*', |c| c.is_alphanumeric()),
+    ];
+}
+
+/// A pattern such as `(999) 999-9999` that formats typed text.
+///
+/// The default tokens are `9` for a digit, `a` for a letter and `*` for a letter or digit.
+/// Every other character is a literal. Prefix a token symbol with `\` to use it as a literal.
+///
+/// ```
+/// use lumen_blocks::components::mask::Mask;
+///
+/// let phone = Mask::new("(999) 999-9999");
+/// assert_eq!(phone.apply("5551234567"), "(555) 123-4567");
+/// assert_eq!(phone.apply("555-12"), "(555) 12");
+/// assert_eq!(phone.unmask("(555) 123-4567"), "5551234567");
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct Mask {
+    pattern: String,
+    tokens: Vec<MaskToken>,
+}
+
+impl Mask {
+    /// Create a mask with the default tokens
+    pub fn new(pattern: impl Into<String>) -> Self {
+        Self {
+            pattern: pattern.into(),
+            tokens: DEFAULT_TOKENS.with(Vec::clone),
+        }
+    }
+
+    /// Add a token, replacing any token with the same symbol. Tokens compare by closure, so build
+    /// masks with custom tokens once, e.g. with `use_hook`, to keep them equal across renders.
+    pub fn token(mut self, symbol: char, accepts: impl Fn(char) -> bool + 'static) -> Self {
+        self.tokens.retain(|token| token.symbol != symbol);
+        self.tokens.push(MaskToken::new(symbol, accepts));
+        self
+    }
+
+    /// The pattern the mask was created from
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    fn slots(&self) -> Vec<Slot<'_>> {
+        let mut slots = Vec::new();
+        let mut chars = self.pattern.chars();
+        while let Some(c) = chars.next() {
+            if c == '\\' {
+                if let Some(escaped) = chars.next() {
+                    slots.push(Slot::Literal(escaped));
+                }
+                continue;
+            }
+            match self.tokens.iter().find(|token| token.symbol == c) {
+                Some(token) => slots.push(Slot::Input(token)),
+                None => slots.push(Slot::Literal(c)),
+            }
+        }
+        slots
+    }
+
+    /// The number of characters the mask takes
+    pub fn capacity(&self) -> usize {
+        self.slots()
+            .iter()
+            .filter(|slot| matches!(slot, Slot::Input(_)))
+            .count()
+    }
+
+    /// Extract the typed characters from text, skipping literals and characters the mask rejects.
+    /// Text typed in the middle of a formatted value shifts the characters after it.
+    /// A character that matches the next literal is taken as that literal, so `1555` typed into
+    /// `+1 (999) 999` gives `555`.
+    pub fn unmask(&self, text: &str) -> String {
+        let slots = self.slots();
+        let mut raw = String::new();
+        let mut index = 0;
+
+        'chars: for c in text.chars() {
+            loop {
+                match slots.get(index) {
+                    None => break 'chars,
+                    Some(Slot::Literal(literal)) => {
+                        index += 1;
+                        // Literals the text leaves out, e.g. after an insertion, are stepped over
+                        if *literal == c {
+                            continue 'chars;
+                        }
+                    }
+                    Some(Slot::Input(token)) => {
+                        if token.accepts(c) {
+                            raw.push(c);
+                            index += 1;
+                        }
+                        continue 'chars;
+                    }
+                }
+            }
+        }
+        raw
+    }
+
+    /// Format typed characters with the pattern. Literals are added up to the last typed
+    /// character, and trailing literals once the mask is complete.
+    pub fn format(&self, raw: &str) -> String {
+        let slots = self.slots();
+        let mut formatted = String::new();
+        let mut pending = String::new();
+        let mut chars = raw.chars().peekable();
+
+        for (index, slot) in slots.iter().enumerate() {
+            match slot {
+                Slot::Literal(literal) => pending.push(*literal),
+                Slot::Input(token) => {
+                    // Skip characters this position rejects
+                    let Some(c) = chars.by_ref().find(|c| token.accepts(*c)) else {
+                        break;
+                    };
+                    formatted.push_str(&pending);
+                    pending.clear();
+                    formatted.push(c);
+                }
+            }
+            let has_more_input = slots[index + 1..]
+                .iter()
+                .any(|slot| matches!(slot, Slot::Input(_)));
+            if chars.peek().is_none() && has_more_input {
+                break;
+            }
+        }
+        let is_complete = !slots
+            .iter()
+            .skip(formatted.chars().count())
+            .any(|slot| matches!(slot, Slot::Input(_)));
+        if is_complete && !formatted.is_empty() {
+            formatted.push_str(&pending);
+        }
+        formatted
+    }
+
+    /// Format any text, formatted or not
+    pub fn apply(&self, text: &str) -> String {
+        self.format(&self.unmask(text))
+    }
+
+    /// Whether typed characters fill every position of the mask
+    pub fn is_complete(&self, raw: &str) -> bool {
+        self.unmask(raw).chars().count() == self.capacity()
+    }
+
+    /// The pattern with every token replaced by `fill`, e.g. `(___) ___-____`
+    pub fn placeholder(&self, fill: char) -> String {
+        self.slots()
+            .iter()
+            .map(|slot| match slot {
+                Slot::Literal(literal) => *literal,
+                Slot::Input(_) => fill,
+            })
+            .collect()
+    }
+
+    /// Apply an edit to a formatted value. `text` is the edited value and `caret` the caret
+    /// position in it, in characters. Returns the typed characters and how many precede the caret.
+    pub(crate) fn edit(&self, previous_raw: &str, text: &str, caret: usize) -> (String, usize) {
+        let before = text.chars().take(caret).collect::<String>();
+        let mut typed = self.unmask(&before).chars().count();
+        let mut raw = self.unmask(text);
+
+        // Deleting a literal leaves the typed characters unchanged, so remove the one before it
+        if raw == previous_raw
+            && text.chars().count() < self.format(previous_raw).chars().count()
+            && typed > 0
+        {
+            raw = raw
+                .chars()
+                .enumerate()
+                .filter(|(index, _)| *index != typed - 1)
+                .map(|(_, c)| c)
+                .collect();
+            typed -= 1;
+        }
+        (raw, typed)
+    }
+
+    /// The caret position, in characters, after the first `typed` characters of a formatted value
+    pub fn caret_position(&self, formatted: &str, typed: usize) -> usize {
+        if typed == 0 {
+            return 0;
+        }
+        let mut seen = 0;
+        for (position, slot) in self.slots().iter().enumerate() {
+            if position >= formatted.chars().count() {
+                return formatted.chars().count();
+            }
+            if matches!(slot, Slot::Input(_)) {
+                seen += 1;
+                if seen == typed {
+                    return position + 1;
+                }
+            }
+        }
+        formatted.chars().count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn phone() -> Mask {
+        Mask::new("(999) 999-9999")
+    }
+
+    #[test]
+    fn masks_from_the_same_pattern_are_equal() {
+        assert_eq!(phone(), phone());
+        assert_ne!(phone(), Mask::new("999-9999"));
+
+        let hex = Mask::new("HH").token('H', |c| c.is_ascii_hexdigit());
+        assert_eq!(hex, hex.clone());
+        assert_ne!(hex, Mask::new("HH").token('H', |c| c.is_ascii_hexdigit()));
+    }
+
+    #[test]
+    fn formats_typed_characters() {
+        assert_eq!(phone().format("5551234567"), "(555) 123-4567");
+        assert_eq!(phone().apply("555.123.4567"), "(555) 123-4567");
+        assert_eq!(phone().unmask("(555) 123-4567"), "5551234567");
+        assert_eq!(Mask::new("999").format("1a2"), "12");
+    }
+
+    #[test]
+    fn formats_partial_input() {
+        assert_eq!(phone().format(""), "");
+        assert_eq!(phone().format("5"), "(5");
+        assert_eq!(phone().format("555"), "(555");
+        assert_eq!(phone().format("5551"), "(555) 1");
+        assert!(!phone().is_complete("555"));
+        assert!(phone().is_complete("(555) 123-4567"));
+    }
+
+    #[test]
+    fn adds_trailing_literals_once_complete() {
+        let mask = Mask::new("99/99 h");
+        assert_eq!(mask.format("123"), "12/3");
+        assert_eq!(mask.format("1230"), "12/30 h");
+    }
+
+    #[test]
+    fn shifts_characters_typed_in_the_middle() {
+        assert_eq!(phone().unmask("(555) 9123-4567"), "5559123456");
+    }
+
+    #[test]
+    fn takes_characters_matching_a_literal_as_the_literal() {
+        let mask = Mask::new("+1 (999) 999");
+        assert_eq!(mask.apply("1555"), "+1 (555");
+        assert_eq!(mask.apply("+1 (555) 123"), "+1 (555) 123");
+    }
+
+    #[test]
+    fn escapes_token_symbols() {
+        let mask = Mask::new(r"\9\a-999");
+        assert_eq!(mask.capacity(), 3);
+        assert_eq!(mask.apply("123"), "9a-123");
+        assert_eq!(mask.placeholder('_'), "9a-___");
+    }
+
+    #[test]
+    fn uses_custom_tokens() {
+        let mask = Mask::new("HH:HH").token('H', |c| c.is_ascii_hexdigit());
+        assert_eq!(mask.apply("a1ff"), "a1:ff");
+        assert_eq!(mask.apply("zz12"), "12");
+
+        // A token with an existing symbol replaces it
+        let mask = Mask::new("999").token('9', |c| c == 'x');
+        assert_eq!(mask.apply("x1x"), "xx");
+    }
+
+    #[test]
+    fn places_the_caret_after_typed_characters() {
+        assert_eq!(phone().caret_position("(555) 123", 0), 0);
+        assert_eq!(phone().caret_position("(555) 123", 3), 4);
+        assert_eq!(phone().caret_position("(555) 123", 4), 7);
+        assert_eq!(phone().caret_position("(555", 5), 4);
+    }
+
+    #[test]
+    fn edits_typed_characters() {
+        // Typing and deleting a typed character change the raw value directly
+        assert_eq!(phone().edit("555", "(5551", 5), ("5551".to_string(), 4));
+        assert_eq!(phone().edit("5551", "(555", 4), ("555".to_string(), 3));
+    }
+
+    #[test]
+    fn deleting_a_literal_removes_the_character_before_it() {
+        // Backspace over the space of "(555) 123"
+        assert_eq!(
+            phone().edit("555123", "(555)123", 5),
+            ("55123".to_string(), 2)
+        );
+        // Nothing precedes the opening parenthesis
+        assert_eq!(phone().edit("555", "555", 0), ("555".to_string(), 0));
+    }
+}
@@ -0,0 +1,242 @@
+use crate::components::form::{UseForm, ValidationMode, use_form_control};
+use crate::components::input::{Input, InputSize, InputVariant};
+use crate::components::mask::Mask;
+use crate::components::validation::Rule;
+use crate::use_unique_id;
+use dioxus_lib::prelude::*;
+
+/// The value of a MaskedInput, with and without the mask's literals
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct MaskedValue {
+    /// The typed characters, e.g. `5551234567`
+    pub raw: String,
+    /// The value as displayed, e.g. `(555) 123-4567`
+    pub formatted: String,
+    /// Whether every position of the mask is filled
+    pub complete: bool,
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct MaskedInputProps {
+    /// The mask applied while typing
+    mask: Mask,
+
+    /// The typed characters without the mask's literals. The input updates this signal as the user types.
+    /// Leave unset to let the input manage its own state.
+    #[props(default)]
+    value: Option<Signal<String>>,
+
+    /// Initial typed characters when `value` is not provided
+    #[props(default)]
+    default_value: String,
+
+    /// Callback with the raw and formatted value when it changes
+    #[props(default)]
+    on_value_change: Option<EventHandler<MaskedValue>>,
+
+    /// Placeholder text. Defaults to the mask with `_` for every typed character.
+    #[props(default)]
+    placeholder: Option<String>,
+
+    /// The variant of the input
+    #[props(default)]
+    variant: InputVariant,
+
+    /// The size of the input
+    #[props(default)]
+    size: InputSize,
+
+    /// Whether the input is disabled
+    #[props(default)]
+    disabled: bool,
+
+    /// Whether the input is read-only
+    #[props(default)]
+    readonly: bool,
+
+    /// Whether the input is required
+    #[props(default)]
+    required: bool,
+
+    /// Whether the input is displayed as a full width block
+    #[props(default)]
+    full_width: bool,
+
+    /// Optional icon to display before the input text
+    #[props(default)]
+    icon_left: Option<Element>,
+
+    /// Optional icon to display after the input text
+    #[props(default)]
+    icon_right: Option<Element>,
+
+    /// Name of the input for form submission. The formatted value is submitted.
+    #[props(default)]
+    name: String,
+
+    /// Optional ID for the input
+    #[props(default)]
+    id: Option<String>,
+
+    /// Optional aria-label for the input (for accessibility)
+    #[props(default)]
+    aria_label: Option<String>,
+
+    /// Validation rules, checked against the formatted value
+    #[props(default)]
+    rules: Vec<Rule>,
+
+    /// When the rules are checked
+    #[props(default)]
+    validation_mode: ValidationMode,
+
+    /// Optional additional classes for the input
+    #[props(default)]
+    class: Option<String>,
+
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+/// An input that formats typed text with a mask such as `(999) 999-9999`
+#[component]
+pub fn MaskedInput(props: MaskedInputProps) -> Element {
+    // Take the id and name from the surrounding FormControl, if any
+    let control = use_form_control();
+
+    // Generate unique ID if not provided, the caret is restored through it
+    let input_id = use_unique_id();
+    let id = props
+        .id
+        .clone()
+        .or_else(|| control.map(|control| control.control_id()))
+        .unwrap_or_else(|| input_id());
+
+    let name = if props.name.is_empty() {
+        control
+            .and_then(|control| control.name())
+            .unwrap_or_default()
+    } else {
+        props.name.clone()
+    };
+
+    // Inside a Form, the field holds the formatted value
+    let form =
+        try_use_context::<UseForm>().filter(|form| !name.is_empty() && form.is_registered(&name));
+
+    // Use the provided signal when controlled, otherwise keep the value internally
+    let internal_value = use_signal(|| props.default_value.clone());
+    let mut value = props.value.unwrap_or(internal_value);
+
+    let mask = props.mask.clone();
+    let raw = match form {
+        Some(form) => mask.unmask(&form.value(&name).to_string()),
+        None => mask.unmask(&value()),
+    };
+    let formatted = mask.format(&raw);
+
+    // Move the caret once the formatted value is rendered
+    let mut pending_caret = use_signal(|| None::<usize>);
+    let caret_id = id.clone();
+    use_effect(move || {
+        if let Some(caret) = pending_caret() {
+            let script = format!(
+                r#"
+                const input = document.getElementById("{}");
+                if (input && document.activeElement === input) {{
+                    input.setSelectionRange({caret}, {caret});
+                }}
+                "#,
+                caret_id
+            );
+            let _ = document::eval(&script);
+            pending_caret.set(None);
+        }
+    });
+
+    // Input events handled so far. Reading the caret is async, so a newer event can finish first.
+    let mut input_count = use_signal(|| 0usize);
+
+    let on_value_change = props.on_value_change;
+    let field_name = name.clone();
+    let input_dom_id = id.clone();
+    let handle_input = move |event: FormEvent| {
+        let mask = mask.clone();
+        let id = input_dom_id.clone();
+        let field_name = field_name.clone();
+        let input_number = *input_count.peek() + 1;
+        input_count.set(input_number);
+        async move {
+            let text = event.value();
+
+            // The caret tells how many typed characters precede it
+            let caret = document::eval(&format!(
+                r#"const input = document.getElementById("{}"); return input ? input.selectionStart : null;"#,
+                id
+            ))
+            .join::<Option<usize>>()
+            .await
+            .ok()
+            .flatten()
+            .unwrap_or_else(|| text.chars().count());
+
+            // The text of a newer event already contains this edit
+            if *input_count.peek() != input_number {
+                return;
+            }
+
+            // Read the value after the await, an earlier event may have changed it meanwhile
+            let previous_raw = match form {
+                Some(form) => mask.unmask(&form.value(&field_name).to_string()),
+                None => mask.unmask(&value.peek()),
+            };
+            let (next_raw, typed) = mask.edit(&previous_raw, &text, caret);
+
+            let next_formatted = mask.format(&next_raw);
+            if let Some(form) = form {
+                form.set_value(&field_name, next_formatted.clone());
+            }
+            value.set(next_raw.clone());
+            pending_caret.set(Some(mask.caret_position(&next_formatted, typed)));
+
+            if next_raw != previous_raw
+                && let Some(handler) = &on_value_change
+            {
+                handler.call(MaskedValue {
+                    complete: mask.is_complete(&next_raw),
+                    raw: next_raw,
+                    formatted: next_formatted,
+                });
+            }
+        }
+    };
+
+    let placeholder = props
+        .placeholder
+        .clone()
+        .unwrap_or_else(|| props.mask.placeholder('_'));
+
+    rsx! {
+        Input {
+            id: Some(id.clone()),
+            input_type: "text".to_string(),
+            name: name.clone(),
+            value: formatted.clone(),
+            placeholder: placeholder,
+            variant: props.variant,
+            size: props.size,
+            disabled: props.disabled,
+            readonly: props.readonly,
+            required: props.required,
+            full_width: props.full_width,
+            icon_left: props.icon_left.clone(),
+            icon_right: props.icon_right.clone(),
+            aria_label: props.aria_label.clone(),
+            rules: props.rules.clone(),
+            validation_mode: props.validation_mode,
+            class: props.class.clone(),
+            on_input: handle_input,
+            ..props.attributes,
+        }
+    }
+}
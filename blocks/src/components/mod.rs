@@ -4,13 +4,32 @@ pub mod avatar;
 pub mod button;
 pub mod checkbox;
 pub mod collapsible;
+pub mod color;
+pub mod color_picker;
 pub mod context_menu;
+pub mod dnd;
 pub mod dropdown;
+pub mod file_dropzone;
+pub mod form;
 pub mod hover_card;
 pub mod input;
+pub mod input_otp;
 pub mod label;
+pub mod mask;
+pub mod masked_input;
 pub mod menubar;
+pub mod notification_center;
+pub mod number_input;
+pub mod password_input;
 pub mod progress;
+pub mod rating;
+#[cfg(feature = "serde")]
+pub mod schema_form;
 pub mod side_sheet;
+pub mod stepper;
 pub mod switch;
+pub mod tag_input;
+pub mod timeline;
 pub mod toast;
+pub mod tree_view;
+pub mod validation;
@@ -0,0 +1,190 @@
+use crate::components::button::{Button, ButtonSize, ButtonVariant};
+use crate::components::toast::use_toast;
+use crate::use_unique_id;
+use dioxus_lib::prelude::*;
+use lucide_dioxus::Bell;
+
+/// Props for the NotificationCenter component
+#[derive(Props, Clone, PartialEq)]
+pub struct NotificationCenterProps {
+    /// Title shown at the top of the panel
+    #[props(default = String::from("Notifications"))]
+    pub title: String,
+
+    /// Text shown when there are no past notifications
+    #[props(default = String::from("No notifications yet"))]
+    pub empty_text: String,
+
+    /// Width of the panel (use class names like "w-80")
+    #[props(default = String::from("w-80"))]
+    pub width: String,
+
+    /// Alignment of the panel relative to the bell button
+    #[props(default = String::from("end"))]
+    pub align: String,
+
+    /// Optional additional classes for the container
+    #[props(default)]
+    pub class: Option<String>,
+}
+
+/// A bell button with an unread badge that opens a panel listing past notifications.
+/// Must be rendered inside a ToastProvider with `history` enabled.
+#[component]
+pub fn NotificationCenter(props: NotificationCenterProps) -> Element {
+    let toasts = use_toast();
+    let mut is_open = use_signal(|| false);
+
+    // Generate unique ID for the panel
+    let panel_id = use_unique_id();
+
+    let history = toasts.history();
+    let unread = toasts.unread_count();
+
+    // Alignment classes
+    let align_class = match props.align.as_str() {
+        "start" => "left-0 origin-top-left",
+        "center" => "left-1/2 -translate-x-1/2 origin-top",
+        _ => "right-0 origin-top-right", // Default to end
+    };
+
+    let container_classes = vec![
+        "relative inline-block",
+        props.class.as_deref().unwrap_or(""),
+    ]
+    .into_iter()
+    .filter(|s| !s.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ");
+
+    let panel_classes = vec![
+        "absolute mt-2 z-50 rounded-md border border-border bg-popover text-popover-foreground shadow-lg",
+        "animate-in fade-in-80",
+        align_class,
+        &props.width,
+    ]
+    .join(" ");
+
+    let button_label = if unread > 0 {
+        format!("{} ({} unread)", props.title, unread)
+    } else {
+        props.title.clone()
+    };
+
+    rsx! {
+        div {
+            class: container_classes,
+            onkeydown: move |event: KeyboardEvent| {
+                if event.key() == Key::Escape {
+                    is_open.set(false);
+                }
+            },
+
+            Button {
+                variant: ButtonVariant::Ghost,
+                is_icon_button: true,
+                aria_label: Some(button_label),
+                aria_expanded: Some(is_open()),
+                aria_controls: Some(panel_id()),
+                on_click: move |_| is_open.set(!is_open()),
+
+                Bell { class: "size-5" }
+
+                if unread > 0 {
+                    span {
+                        class: "absolute -top-1 -right-1 flex h-4 min-w-4 items-center justify-center rounded-full bg-destructive px-1 text-[0.625rem] font-semibold text-primary-foreground",
+                        aria_hidden: "true",
+                        if unread > 99 { "99+" } else { "{unread}" }
+                    }
+                }
+            }
+
+            if is_open() {
+                div {
+                    id: panel_id(),
+                    class: panel_classes,
+                    role: "region",
+                    aria_label: props.title.clone(),
+
+                    // Header with bulk actions
+                    div {
+                        class: "flex items-center justify-between gap-2 border-b border-border px-3 py-2",
+
+                        span { class: "text-sm font-semibold", "{props.title}" }
+
+                        div {
+                            class: "flex items-center gap-1",
+                            Button {
+                                variant: ButtonVariant::Ghost,
+                                size: ButtonSize::Small,
+                                disabled: unread == 0,
+                                on_click: move |_| toasts.mark_all_read(),
+                                "Mark all read"
+                            }
+                            Button {
+                                variant: ButtonVariant::Ghost,
+                                size: ButtonSize::Small,
+                                disabled: history.is_empty(),
+                                on_click: move |_| toasts.clear_history(),
+                                "Clear"
+                            }
+                        }
+                    }
+
+                    if history.is_empty() {
+                        p {
+                            class: "px-3 py-6 text-center text-sm text-muted-foreground",
+                            "{props.empty_text}"
+                        }
+                    } else {
+                        ul {
+                            class: "max-h-96 overflow-y-auto py-1",
+                            role: "list",
+
+                            for entry in history.iter().cloned() {
+                                li {
+                                    key: "{entry.id}",
+                                    class: "flex cursor-pointer items-start gap-3 px-3 py-2 text-sm hover:bg-accent hover:text-accent-foreground",
+                                    onclick: move |_| toasts.mark_read(entry.id),
+
+                                    div {
+                                        class: "mt-0.5 flex-shrink-0 {entry.toast_type.icon_classes()}",
+                                        aria_label: entry.toast_type.aria_label(),
+                                        {entry.toast_type.icon_component()}
+                                    }
+
+                                    div {
+                                        class: "flex-1 space-y-1",
+
+                                        div {
+                                            class: if entry.read { "font-medium" } else { "font-semibold" },
+                                            "{entry.title}"
+                                            if entry.count > 1 {
+                                                span { class: "ml-1 text-xs text-muted-foreground", "×{entry.count}" }
+                                            }
+                                        }
+
+                                        if let Some(description) = &entry.description {
+                                            div { class: "text-xs opacity-90", "{description}" }
+                                        }
+
+                                        if let Some(time_label) = &entry.time_label {
+                                            div { class: "text-xs text-muted-foreground", "{time_label}" }
+                                        }
+                                    }
+
+                                    if !entry.read {
+                                        span {
+                                            class: "mt-1.5 h-2 w-2 flex-shrink-0 rounded-full bg-primary",
+                                            aria_label: "Unread",
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
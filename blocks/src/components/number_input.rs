@@ -0,0 +1,767 @@
+use crate::components::button::{Button, ButtonSize, ButtonVariant};
+use crate::components::form::{FieldValue, UseForm, use_form_control};
+use crate::components::input::{InputSize, InputVariant};
+use crate::use_unique_id;
+use dioxus_lib::prelude::*;
+use lucide_dioxus::{Minus, Plus};
+
+/// How a NumberInput displays its value when it is not being edited
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum NumberFormat {
+    /// Thousands separators and the locale's decimal separator, e.g. `1,234.5`
+    Decimal,
+    /// Like `Decimal` without thousands separators, e.g. for years
+    Plain,
+    /// An amount in an ISO 4217 currency such as `"USD"` or `"EUR"`, e.g. `$1,234.50`
+    Currency(String),
+    /// The value followed by a percent sign, e.g. `25%` for a value of 25
+    Percent,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self::Decimal
+    }
+}
+
+// Separators and placement rules for a locale
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct LocaleSymbols {
+    group: &'static str,
+    decimal: char,
+    currency_after: bool,
+    percent_space: bool,
+}
+
+fn locale_symbols(locale: &str) -> LocaleSymbols {
+    let mut parts = locale.split(['-', '_']);
+    let language = parts.next().unwrap_or("").to_ascii_lowercase();
+    let region = parts.next().unwrap_or("").to_ascii_uppercase();
+
+    match (language.as_str(), region.as_str()) {
+        ("de" | "it" | "fr", "CH") | ("de", "LI") => LocaleSymbols {
+            group: "\u{2019}",
+            decimal: '.',
+            currency_after: false,
+            percent_space: language != "it",
+        },
+        ("fr", _) => LocaleSymbols {
+            group: "\u{202F}",
+            decimal: ',',
+            currency_after: true,
+            percent_space: true,
+        },
+        ("ru" | "uk" | "pl" | "cs" | "sk" | "sv" | "nb" | "no" | "fi", _) => LocaleSymbols {
+            group: "\u{A0}",
+            decimal: ',',
+            currency_after: true,
+            percent_space: true,
+        },
+        ("de" | "es" | "it" | "da" | "el", _) | ("pt", "PT") => LocaleSymbols {
+            group: ".",
+            decimal: ',',
+            currency_after: true,
+            percent_space: language != "it",
+        },
+        ("pt" | "nl" | "id" | "tr", _) => LocaleSymbols {
+            group: ".",
+            decimal: ',',
+            currency_after: false,
+            percent_space: false,
+        },
+        _ => LocaleSymbols {
+            group: ",",
+            decimal: '.',
+            currency_after: false,
+            percent_space: false,
+        },
+    }
+}
+
+fn currency_symbol(code: &str) -> &str {
+    match code {
+        "USD" => "$",
+        "EUR" => "€",
+        "GBP" => "£",
+        "JPY" | "CNY" => "¥",
+        "INR" => "₹",
+        "KRW" => "₩",
+        "BRL" => "R$",
+        "RUB" => "₽",
+        "UAH" => "₴",
+        "TRY" => "₺",
+        other => other,
+    }
+}
+
+// Currencies without minor units
+fn currency_decimals(code: &str) -> u32 {
+    match code {
+        "JPY" | "KRW" | "VND" | "CLP" | "ISK" => 0,
+        _ => 2,
+    }
+}
+
+// The number of decimals needed to represent a step, e.g. 2 for 0.05
+fn step_decimals(step: f64) -> u32 {
+    step.to_string()
+        .split_once('.')
+        .map(|(_, fraction)| fraction.len() as u32)
+        .unwrap_or(0)
+}
+
+fn round_to(value: f64, decimals: u32) -> f64 {
+    let factor = 10f64.powi(decimals as i32);
+    (value * factor).round() / factor
+}
+
+// Format a number with the locale's separators, optionally grouping thousands
+fn format_number(value: f64, decimals: u32, symbols: LocaleSymbols, grouped: bool) -> String {
+    let text = format!("{:.*}", decimals as usize, value.abs());
+    let (integer, fraction) = text.split_once('.').unwrap_or((&text, ""));
+
+    let mut result = String::new();
+    if value < 0.0 && text.chars().any(|c| c.is_ascii_digit() && c != '0') {
+        result.push('-');
+    }
+    for (index, digit) in integer.chars().enumerate() {
+        if grouped && index > 0 && (integer.len() - index) % 3 == 0 {
+            result.push_str(symbols.group);
+        }
+        result.push(digit);
+    }
+    if !fraction.is_empty() {
+        result.push(symbols.decimal);
+        result.push_str(fraction);
+    }
+    result
+}
+
+// Format a number for display with the currency symbol or percent sign of the format
+fn format_value(
+    number: f64,
+    format: &NumberFormat,
+    decimals: u32,
+    symbols: LocaleSymbols,
+) -> String {
+    let digits = format_number(number, decimals, symbols, *format != NumberFormat::Plain);
+    match format {
+        NumberFormat::Currency(code) => {
+            let symbol = currency_symbol(code);
+            if symbols.currency_after {
+                format!("{digits}\u{A0}{symbol}")
+            } else if symbol == code {
+                format!("{symbol}\u{A0}{digits}")
+            } else {
+                format!("{symbol}{digits}")
+            }
+        }
+        NumberFormat::Percent if symbols.percent_space => format!("{digits}\u{A0}%"),
+        NumberFormat::Percent => format!("{digits}%"),
+        _ => digits,
+    }
+}
+
+// Round to the precision, then keep the number within the bounds
+fn clamp_value(number: f64, decimals: u32, min: Option<f64>, max: Option<f64>) -> f64 {
+    let number = round_to(number, decimals);
+    let number = min.map_or(number, |min| number.max(min));
+    max.map_or(number, |max| number.min(max))
+}
+
+// Remove thousands separators. They only count when exactly three digits follow them, so
+// "12.5" in a locale that groups with "." is rejected instead of read as 125.
+fn strip_groups(text: &str, group: &str) -> Option<String> {
+    let mut parts = text.split(group);
+    let mut result = parts.next().unwrap_or("").to_string();
+    for part in parts {
+        let digits = part.chars().take_while(char::is_ascii_digit).count();
+        if digits != 3 || !result.ends_with(|c: char| c.is_ascii_digit()) {
+            return None;
+        }
+        result.push_str(part);
+    }
+    Some(result)
+}
+
+// Parse user input, ignoring separators, currency symbols and percent signs
+fn parse_number(text: &str, symbols: LocaleSymbols) -> Option<f64> {
+    let normalized = strip_groups(text, symbols.group)?
+        .chars()
+        .filter_map(|c| match c {
+            '0'..='9' | '-' => Some(c),
+            c if c == symbols.decimal => Some('.'),
+            // Accept a period as the decimal separator in every locale that doesn't group with it
+            '.' if symbols.group != "." => Some('.'),
+            _ => None,
+        })
+        .collect::<String>();
+
+    if normalized.is_empty() || normalized == "-" {
+        None
+    } else {
+        normalized.parse::<f64>().ok()
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct NumberInputProps {
+    /// The current value, `None` when empty. The input updates this signal when the value changes.
+    /// Leave unset to let the input manage its own state.
+    #[props(default)]
+    value: Option<Signal<Option<f64>>>,
+
+    /// Initial value when `value` is not provided
+    #[props(default)]
+    default_value: Option<f64>,
+
+    /// Callback with the new value when it changes
+    #[props(default)]
+    on_value_change: Option<EventHandler<Option<f64>>>,
+
+    /// Callback with the new value rounded to an integer when it changes
+    #[props(default)]
+    on_integer_change: Option<EventHandler<Option<i64>>>,
+
+    /// Smallest allowed value
+    #[props(default)]
+    min: Option<f64>,
+
+    /// Largest allowed value
+    #[props(default)]
+    max: Option<f64>,
+
+    /// Amount added or removed by the steppers and arrow keys
+    #[props(default = 1.0)]
+    step: f64,
+
+    /// Amount added or removed by PageUp and PageDown. Defaults to ten steps.
+    #[props(default)]
+    page_step: Option<f64>,
+
+    /// Number of decimals to round to. Defaults to the decimals of `step`, or of the currency.
+    #[props(default)]
+    precision: Option<u32>,
+
+    /// How the value is displayed when the input is not focused
+    #[props(default)]
+    format: NumberFormat,
+
+    /// BCP 47 locale used for separators and symbol placement, e.g. `"en-US"` or `"de-DE"`
+    #[props(default = String::from("en-US"))]
+    locale: String,
+
+    /// Whether to show the increment and decrement buttons
+    #[props(default = true)]
+    show_steppers: bool,
+
+    /// The variant of the input
+    #[props(default)]
+    variant: InputVariant,
+
+    /// The size of the input
+    #[props(default)]
+    size: InputSize,
+
+    /// Whether the input is disabled
+    #[props(default)]
+    disabled: bool,
+
+    /// Whether the input is read-only
+    #[props(default)]
+    readonly: bool,
+
+    /// Whether the input is required
+    #[props(default)]
+    required: bool,
+
+    /// Placeholder text for the input
+    #[props(default)]
+    placeholder: String,
+
+    /// Whether the input is displayed as a full width block
+    #[props(default)]
+    full_width: bool,
+
+    /// Callback when the input loses focus, after the value is clamped
+    #[props(default)]
+    on_blur: Option<Callback<FocusEvent>>,
+
+    /// Name of the input for form submission
+    #[props(default)]
+    name: String,
+
+    /// Optional ID for the input
+    #[props(default)]
+    id: Option<String>,
+
+    /// Optional aria-label for the input (for accessibility)
+    #[props(default)]
+    aria_label: Option<String>,
+
+    /// Optional additional classes for the input
+    #[props(default)]
+    class: Option<String>,
+
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+/// A numeric input with increment and decrement buttons, keyboard stepping,
+/// clamping on blur and locale-aware formatting
+#[component]
+pub fn NumberInput(props: NumberInputProps) -> Element {
+    // Take the id, name and ARIA state from the surrounding FormControl, if any
+    let control = use_form_control();
+
+    // Generate unique ID if not provided
+    let input_id = use_unique_id();
+    let id = props
+        .id
+        .clone()
+        .or_else(|| control.map(|control| control.control_id()))
+        .unwrap_or_else(|| input_id());
+
+    let name = if props.name.is_empty() {
+        control
+            .and_then(|control| control.name())
+            .unwrap_or_default()
+    } else {
+        props.name.clone()
+    };
+
+    // Bind to the surrounding Form when a field with this name is registered
+    let form =
+        try_use_context::<UseForm>().filter(|form| !name.is_empty() && form.is_registered(&name));
+    let field_error = form.and_then(|form| form.error(&name));
+
+    // Use the provided signal when controlled, otherwise keep the value internally
+    let internal_value = use_signal(|| props.default_value);
+    let mut value = props.value.unwrap_or(internal_value);
+    let current = match form {
+        Some(form) => f64::try_from(form.value(&name))
+            .ok()
+            .filter(|number| !number.is_nan()),
+        None => value(),
+    };
+
+    // The raw text while the user is editing
+    let mut focused = use_signal(|| false);
+    let mut text = use_signal(String::new);
+
+    let symbols = locale_symbols(&props.locale);
+    let decimals = props.precision.unwrap_or(match &props.format {
+        NumberFormat::Currency(code) => currency_decimals(code).max(step_decimals(props.step)),
+        _ => step_decimals(props.step),
+    });
+    let min = props.min;
+    let max = props.max;
+    let step = props.step;
+    let page_step = props.page_step.unwrap_or(step * 10.0);
+
+    let format = props.format.clone();
+    let formatted = move |number: f64| format_value(number, &format, decimals, symbols);
+    let clamp = move |number: f64| clamp_value(number, decimals, min, max);
+
+    // Store a new value and notify listeners
+    let name_for_commit = name.clone();
+    let on_value_change = props.on_value_change;
+    let on_integer_change = props.on_integer_change;
+    let commit = use_callback(move |next: Option<f64>| {
+        if let Some(form) = form {
+            form.set_value(
+                &name_for_commit,
+                FieldValue::Number(next.unwrap_or(f64::NAN)),
+            );
+        }
+        if value.peek().to_owned() == next {
+            return;
+        }
+        value.set(next);
+        if let Some(handler) = &on_value_change {
+            handler.call(next);
+        }
+        if let Some(handler) = &on_integer_change {
+            handler.call(next.map(|number| number.round() as i64));
+        }
+    });
+
+    // Move to a value from the steppers or the keyboard
+    let disabled = props.disabled;
+    let readonly = props.readonly;
+    let mut move_to = move |next: f64| {
+        if disabled || readonly {
+            return;
+        }
+        let next = clamp(next);
+        commit.call(Some(next));
+        if focused() {
+            text.set(format_number(next, decimals, symbols, false));
+        }
+    };
+    let mut step_by = move |amount: f64| {
+        let base = current.unwrap_or_else(|| min.filter(|min| *min > 0.0).unwrap_or(0.0));
+        move_to(base + amount);
+    };
+
+    let at_min = current.is_some_and(|number| min.is_some_and(|min| number <= min));
+    let at_max = current.is_some_and(|number| max.is_some_and(|max| number >= max));
+
+    let handle_key_down = move |event: KeyboardEvent| {
+        match (event.key(), min, max) {
+            (Key::ArrowUp, _, _) => step_by(step),
+            (Key::ArrowDown, _, _) => step_by(-step),
+            (Key::PageUp, _, _) => step_by(page_step),
+            (Key::PageDown, _, _) => step_by(-page_step),
+            (Key::Home, Some(min), _) => move_to(min),
+            (Key::End, _, Some(max)) => move_to(max),
+            _ => return,
+        }
+        event.prevent_default();
+    };
+
+    let handle_input = move |event: FormEvent| {
+        let raw = event.value();
+        text.set(raw.clone());
+        commit.call(parse_number(&raw, symbols));
+    };
+
+    let handle_focus = move |_| {
+        text.set(
+            current
+                .map(|number| format_number(number, decimals, symbols, false))
+                .unwrap_or_default(),
+        );
+        focused.set(true);
+    };
+
+    // Clamp and round the typed value once editing is done
+    let blur_name = name.clone();
+    let handle_blur = move |event: FocusEvent| {
+        focused.set(false);
+        commit.call(parse_number(&text.peek(), symbols).map(clamp));
+        if let Some(form) = form {
+            form.touch(&blur_name);
+        }
+        if let Some(callback) = &props.on_blur {
+            callback.call(event);
+        }
+    };
+
+    let display = if focused() {
+        text()
+    } else {
+        current.map(&formatted).unwrap_or_default()
+    };
+
+    let is_invalid = field_error.is_some() || control.is_some_and(|control| control.is_invalid());
+    let is_required = props.required || control.is_some_and(|control| control.is_required());
+    let aria_describedby = control
+        .and_then(|control| control.describedby())
+        .or_else(|| {
+            form.filter(|_| field_error.is_some())
+                .map(|form| form.error_id(&name))
+        });
+
+    let variant = if is_invalid {
+        InputVariant::Error
+    } else {
+        props.variant
+    };
+
+    // Determine variant classes
+    let variant_classes = match variant {
+        InputVariant::Default => "border-input focus:border-ring",
+        InputVariant::Error => "border-destructive focus:border-destructive",
+    };
+
+    // Determine size classes
+    let (size_classes, button_size) = match props.size {
+        InputSize::Small => ("text-xs px-2 py-1 h-8", ButtonSize::Small),
+        InputSize::Medium => ("text-sm px-3 py-1.5 h-10", ButtonSize::Medium),
+        InputSize::Large => ("text-base px-4 py-2 h-12", ButtonSize::Large),
+    };
+
+    // Determine state classes
+    let state_class = if props.disabled {
+        "opacity-50 cursor-not-allowed bg-muted"
+    } else {
+        "bg-background"
+    };
+
+    // Generate all the classes
+    let input_classes = vec![
+        // Base classes
+        "min-w-0 flex-1 rounded border text-foreground tabular-nums",
+        "transition-colors focus:outline-none focus:ring-2 focus:ring-ring focus:ring-offset-2",
+        // Variant-specific classes
+        variant_classes,
+        // Size-specific classes
+        size_classes,
+        // State class
+        state_class,
+        // Centered between the steppers
+        if props.show_steppers {
+            "text-center"
+        } else {
+            ""
+        },
+        // Additional classes passed by the user
+        props.class.as_deref().unwrap_or(""),
+    ]
+    .into_iter()
+    .filter(|s| !s.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ");
+
+    let wrapper_classes = if props.full_width {
+        "flex w-full items-center gap-1"
+    } else {
+        "inline-flex items-center gap-1"
+    };
+
+    let input_mode = if decimals == 0 && min.is_some_and(|min| min >= 0.0) {
+        "numeric"
+    } else {
+        "decimal"
+    };
+
+    rsx! {
+        div {
+            class: wrapper_classes,
+
+            if props.show_steppers {
+                Button {
+                    variant: ButtonVariant::Outline,
+                    size: button_size,
+                    is_icon_button: true,
+                    disabled: props.disabled || props.readonly || at_min,
+                    aria_label: Some("Decrease".to_string()),
+                    aria_controls: Some(id.clone()),
+                    on_click: move |_| step_by(-step),
+                    tabindex: "-1",
+                    Minus { size: 16 }
+                }
+            }
+
+            input {
+                // Standard HTML attributes
+                id: id.clone(),
+                type: "text",
+                inputmode: input_mode,
+                autocomplete: "off",
+                placeholder: props.placeholder,
+                value: display,
+                disabled: props.disabled,
+                readonly: props.readonly,
+                required: is_required,
+                class: input_classes,
+
+                // Event handlers
+                oninput: handle_input,
+                onkeydown: handle_key_down,
+                onfocus: handle_focus,
+                onblur: handle_blur,
+
+                // ARIA attributes
+                role: "spinbutton",
+                aria_valuenow: current.map(|number| number.to_string()),
+                aria_valuemin: min.map(|min| min.to_string()),
+                aria_valuemax: max.map(|max| max.to_string()),
+                aria_valuetext: current.map(&formatted),
+                aria_label: props.aria_label.clone(),
+                aria_labelledby: control
+                    .filter(|_| props.aria_label.is_none())
+                    .map(|control| control.label_id()),
+                aria_describedby: aria_describedby,
+                aria_invalid: is_invalid.then_some("true"),
+                aria_required: is_required.to_string(),
+
+                // Pass through other attributes
+                ..props.attributes,
+            }
+
+            if props.show_steppers {
+                Button {
+                    variant: ButtonVariant::Outline,
+                    size: button_size,
+                    is_icon_button: true,
+                    disabled: props.disabled || props.readonly || at_max,
+                    aria_label: Some("Increase".to_string()),
+                    aria_controls: Some(id.clone()),
+                    on_click: move |_| step_by(step),
+                    tabindex: "-1",
+                    Plus { size: 16 }
+                }
+            }
+
+            // The unformatted value is what gets submitted with the form
+            if !name.is_empty() {
+                input {
+                    type: "hidden",
+                    name: name.clone(),
+                    value: current.map(|number| number.to_string()).unwrap_or_default(),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_with_locale_separators() {
+        let value = 1234567.891;
+        assert_eq!(
+            format_number(value, 2, locale_symbols("en-US"), true),
+            "1,234,567.89"
+        );
+        assert_eq!(
+            format_number(value, 2, locale_symbols("de-DE"), true),
+            "1.234.567,89"
+        );
+        assert_eq!(
+            format_number(value, 2, locale_symbols("fr-FR"), true),
+            "1\u{202F}234\u{202F}567,89"
+        );
+        assert_eq!(
+            format_number(value, 2, locale_symbols("de-CH"), true),
+            "1\u{2019}234\u{2019}567.89"
+        );
+        assert_eq!(
+            format_number(value, 2, locale_symbols("sv-SE"), true),
+            "1\u{A0}234\u{A0}567,89"
+        );
+        assert_eq!(
+            format_number(value, 2, locale_symbols("en-US"), false),
+            "1234567.89"
+        );
+    }
+
+    #[test]
+    fn formats_signs_and_rounding() {
+        let symbols = locale_symbols("en-US");
+        assert_eq!(format_number(-1234.5, 2, symbols, true), "-1,234.50");
+        assert_eq!(format_number(999.6, 0, symbols, true), "1,000");
+        assert_eq!(format_number(123.0, 0, symbols, true), "123");
+        // A negative number that rounds to zero has no sign
+        assert_eq!(format_number(-0.001, 2, symbols, true), "0.00");
+    }
+
+    #[test]
+    fn resolves_locale_tags() {
+        assert_eq!(locale_symbols("de_AT"), locale_symbols("de-DE"));
+        assert_eq!(locale_symbols("DE-de"), locale_symbols("de-DE"));
+        assert!(locale_symbols("pt-PT").currency_after);
+        assert!(!locale_symbols("pt-BR").currency_after);
+        assert_eq!(locale_symbols(""), locale_symbols("en-US"));
+    }
+
+    #[test]
+    fn parses_locale_input() {
+        let en = locale_symbols("en-US");
+        assert_eq!(parse_number("1,234.5", en), Some(1234.5));
+        assert_eq!(parse_number("$1,234.50", en), Some(1234.5));
+        assert_eq!(parse_number("-12", en), Some(-12.0));
+        assert_eq!(parse_number("25%", en), Some(25.0));
+
+        let de = locale_symbols("de-DE");
+        assert_eq!(parse_number("1.234,5", de), Some(1234.5));
+        assert_eq!(parse_number("1.234,50\u{A0}€", de), Some(1234.5));
+
+        let fr = locale_symbols("fr-FR");
+        assert_eq!(parse_number("1\u{202F}234,5", fr), Some(1234.5));
+        // A period also works as the decimal separator when it isn't the group separator
+        assert_eq!(parse_number("12.5", fr), Some(12.5));
+    }
+
+    #[test]
+    fn rejects_misplaced_group_separators() {
+        let de = locale_symbols("de-DE");
+        assert_eq!(parse_number("12.5", de), None);
+        assert_eq!(parse_number("1.2345", de), None);
+        assert_eq!(parse_number(".500", de), None);
+        assert_eq!(parse_number("1.234.567", de), Some(1234567.0));
+
+        let en = locale_symbols("en-US");
+        assert_eq!(parse_number("1,2", en), None);
+        assert_eq!(parse_number("1,234,567.25", en), Some(1234567.25));
+    }
+
+    #[test]
+    fn rejects_input_without_a_number() {
+        let en = locale_symbols("en-US");
+        assert_eq!(parse_number("", en), None);
+        assert_eq!(parse_number("-", en), None);
+        assert_eq!(parse_number("abc", en), None);
+        assert_eq!(parse_number("1.2.3", en), None);
+    }
+
+    #[test]
+    fn formats_currencies() {
+        let usd = NumberFormat::Currency("USD".to_string());
+        let eur = NumberFormat::Currency("EUR".to_string());
+        let chf = NumberFormat::Currency("CHF".to_string());
+        let jpy = NumberFormat::Currency("JPY".to_string());
+
+        assert_eq!(
+            format_value(1234.5, &usd, 2, locale_symbols("en-US")),
+            "$1,234.50"
+        );
+        assert_eq!(
+            format_value(1234.5, &eur, 2, locale_symbols("de-DE")),
+            "1.234,50\u{A0}€"
+        );
+        // Codes without a symbol are separated from the amount
+        assert_eq!(
+            format_value(1234.5, &chf, 2, locale_symbols("en-US")),
+            "CHF\u{A0}1,234.50"
+        );
+        assert_eq!(currency_decimals("JPY"), 0);
+        assert_eq!(currency_decimals("EUR"), 2);
+        assert_eq!(
+            format_value(1234.0, &jpy, 0, locale_symbols("ja-JP")),
+            "¥1,234"
+        );
+    }
+
+    #[test]
+    fn formats_percent_and_plain() {
+        let percent = NumberFormat::Percent;
+        assert_eq!(
+            format_value(25.0, &percent, 0, locale_symbols("en-US")),
+            "25%"
+        );
+        assert_eq!(
+            format_value(25.0, &percent, 0, locale_symbols("de-DE")),
+            "25\u{A0}%"
+        );
+        assert_eq!(
+            format_value(25.0, &percent, 0, locale_symbols("it-IT")),
+            "25%"
+        );
+        assert_eq!(
+            format_value(2024.0, &NumberFormat::Plain, 0, locale_symbols("en-US")),
+            "2024"
+        );
+    }
+
+    #[test]
+    fn derives_precision_from_step() {
+        assert_eq!(step_decimals(1.0), 0);
+        assert_eq!(step_decimals(0.1), 1);
+        assert_eq!(step_decimals(0.05), 2);
+        assert_eq!(step_decimals(0.25), 2);
+    }
+
+    #[test]
+    fn clamps_after_rounding() {
+        assert_eq!(clamp_value(1.234, 2, Some(0.0), Some(1.0)), 1.0);
+        assert_eq!(clamp_value(-5.0, 0, Some(0.0), None), 0.0);
+        assert_eq!(clamp_value(0.125, 2, None, None), 0.13);
+        assert_eq!(clamp_value(7.4, 0, None, Some(10.0)), 7.0);
+        assert_eq!(round_to(2.5, 0), 3.0);
+    }
+}
@@ -0,0 +1,500 @@
+use crate::components::form::{UseForm, ValidationMode, use_form_control};
+use crate::components::input::{Input, InputSize, InputVariant};
+use crate::components::progress::{Progress, ProgressSize, ProgressVariant};
+use crate::components::validation::Rule;
+use crate::use_unique_id;
+use dioxus_lib::prelude::*;
+use lucide_dioxus::{Check, Eye, EyeOff, X};
+use std::fmt;
+use std::rc::Rc;
+
+/// How hard a password is to guess, from a score between 0 and 4
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PasswordStrength {
+    VeryWeak,
+    Weak,
+    Fair,
+    Good,
+    Strong,
+}
+
+impl PasswordStrength {
+    /// The strength for a score between 0 and 4. Higher scores count as strong.
+    pub fn from_score(score: u8) -> Self {
+        match score {
+            0 => Self::VeryWeak,
+            1 => Self::Weak,
+            2 => Self::Fair,
+            3 => Self::Good,
+            _ => Self::Strong,
+        }
+    }
+
+    /// The score between 0 and 4
+    pub fn score(&self) -> u8 {
+        match self {
+            Self::VeryWeak => 0,
+            Self::Weak => 1,
+            Self::Fair => 2,
+            Self::Good => 3,
+            Self::Strong => 4,
+        }
+    }
+
+    /// A short English description, e.g. "Fair"
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::VeryWeak => "Very weak",
+            Self::Weak => "Weak",
+            Self::Fair => "Fair",
+            Self::Good => "Good",
+            Self::Strong => "Strong",
+        }
+    }
+
+    /// The progress color used by the strength meter
+    pub fn variant(&self) -> ProgressVariant {
+        match self {
+            Self::VeryWeak | Self::Weak => ProgressVariant::Destructive,
+            Self::Fair => ProgressVariant::Warning,
+            Self::Good | Self::Strong => ProgressVariant::Success,
+        }
+    }
+}
+
+const COMMON_PASSWORDS: &[&str] = &[
+    "password",
+    "123456",
+    "12345678",
+    "123456789",
+    "qwerty",
+    "abc123",
+    "111111",
+    "letmein",
+    "welcome",
+    "admin",
+    "iloveyou",
+    "monkey",
+    "dragon",
+    "football",
+    "passw0rd",
+    "password1",
+];
+
+/// The default password scoring. Length and a mix of lowercase, uppercase, digits and symbols
+/// raise the score, while common passwords and repeated characters score 0.
+pub fn score_password(password: &str) -> u8 {
+    let length = password.chars().count();
+    if length == 0 || COMMON_PASSWORDS.contains(&password.to_lowercase().as_str()) {
+        return 0;
+    }
+    let mut chars = password.chars();
+    let first = chars.next();
+    if chars.all(|c| Some(c) == first) {
+        return 0;
+    }
+
+    let classes = [
+        password.chars().any(|c| c.is_lowercase()),
+        password.chars().any(|c| c.is_uppercase()),
+        password.chars().any(|c| c.is_ascii_digit()),
+        password.chars().any(|c| !c.is_alphanumeric()),
+    ]
+    .into_iter()
+    .filter(|present| *present)
+    .count();
+
+    let mut score = 0;
+    if length >= 8 {
+        score += 1;
+    }
+    if length >= 12 {
+        score += 1;
+    }
+    if classes >= 3 {
+        score += 1;
+    }
+    if classes == 4 || length >= 16 {
+        score += 1;
+    }
+    // Short passwords stay weak whatever they contain
+    if length < 8 {
+        score = score.min(1);
+    }
+    score
+}
+
+type RequirementFn = dyn Fn(&str) -> bool;
+
+/// A requirement listed below a PasswordInput, checked as the user types
+#[derive(Clone)]
+pub struct PasswordRequirement {
+    label: String,
+    check: Rc<RequirementFn>,
+}
+
+impl PasswordRequirement {
+    pub fn new(label: impl Into<String>, check: impl Fn(&str) -> bool + 'static) -> Self {
+        Self {
+            label: label.into(),
+            check: Rc::new(check),
+        }
+    }
+
+    /// At least `min` characters
+    pub fn min_length(min: usize) -> Self {
+        Self::new(format!("At least {min} characters"), move |password| {
+            password.chars().count() >= min
+        })
+    }
+
+    /// At least one uppercase letter
+    pub fn uppercase() -> Self {
+        Self::new("One uppercase letter", |password| {
+            password.chars().any(char::is_uppercase)
+        })
+    }
+
+    /// At least one lowercase letter
+    pub fn lowercase() -> Self {
+        Self::new("One lowercase letter", |password| {
+            password.chars().any(char::is_lowercase)
+        })
+    }
+
+    /// At least one digit
+    pub fn digit() -> Self {
+        Self::new("One number", |password| {
+            password.chars().any(|c| c.is_ascii_digit())
+        })
+    }
+
+    /// At least one character that is not a letter or digit
+    pub fn symbol() -> Self {
+        Self::new("One symbol", |password| {
+            password.chars().any(|c| !c.is_alphanumeric())
+        })
+    }
+
+    /// The text shown for the requirement
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Whether a password meets the requirement
+    pub fn is_met(&self, password: &str) -> bool {
+        (self.check)(password)
+    }
+}
+
+impl PartialEq for PasswordRequirement {
+    fn eq(&self, other: &Self) -> bool {
+        self.label == other.label && Rc::ptr_eq(&self.check, &other.check)
+    }
+}
+
+impl fmt::Debug for PasswordRequirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PasswordRequirement")
+            .field(&self.label)
+            .finish()
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct PasswordInputProps {
+    /// The current password. The input updates this signal as the user types.
+    /// Leave unset to let the input manage its own state.
+    #[props(default)]
+    value: Option<Signal<String>>,
+
+    /// Callback with the password when it changes
+    #[props(default)]
+    on_value_change: Option<EventHandler<String>>,
+
+    /// Whether to show a strength meter below the input
+    #[props(default)]
+    show_strength: bool,
+
+    /// Scores a password between 0 and 4 for the strength meter. Defaults to `score_password`.
+    #[props(default)]
+    score: Option<Callback<String, u8>>,
+
+    /// Requirements listed below the input and checked as the user types
+    #[props(default)]
+    requirements: Vec<PasswordRequirement>,
+
+    /// The autocomplete hint, e.g. `new-password` on sign-up forms
+    #[props(default = String::from("current-password"))]
+    autocomplete: String,
+
+    /// The variant of the input
+    #[props(default)]
+    variant: InputVariant,
+
+    /// The size of the input
+    #[props(default)]
+    size: InputSize,
+
+    /// Whether the input is disabled
+    #[props(default)]
+    disabled: bool,
+
+    /// Whether the input is required
+    #[props(default)]
+    required: bool,
+
+    /// Placeholder text for the input
+    #[props(default)]
+    placeholder: String,
+
+    /// Whether the input is displayed as a full width block
+    #[props(default)]
+    full_width: bool,
+
+    /// Name of the input for form submission
+    #[props(default)]
+    name: String,
+
+    /// Optional ID for the input
+    #[props(default)]
+    id: Option<String>,
+
+    /// Optional aria-label for the input (for accessibility)
+    #[props(default)]
+    aria_label: Option<String>,
+
+    /// Validation rules for the password
+    #[props(default)]
+    rules: Vec<Rule>,
+
+    /// When the rules are checked
+    #[props(default)]
+    validation_mode: ValidationMode,
+
+    /// Optional additional classes for the input
+    #[props(default)]
+    class: Option<String>,
+
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+/// A password input with a visibility toggle, an optional strength meter and requirement hints
+#[component]
+pub fn PasswordInput(props: PasswordInputProps) -> Element {
+    // Take the id and name from the surrounding FormControl, if any
+    let control = use_form_control();
+
+    // Generate unique ID if not provided
+    let input_id = use_unique_id();
+    let id = props
+        .id
+        .clone()
+        .or_else(|| control.map(|control| control.control_id()))
+        .unwrap_or_else(|| input_id());
+    let hints_id = format!("{}-hints", id);
+
+    let name = if props.name.is_empty() {
+        control
+            .and_then(|control| control.name())
+            .unwrap_or_default()
+    } else {
+        props.name.clone()
+    };
+
+    // Inside a Form, the Input binds to the field with this name
+    let form =
+        try_use_context::<UseForm>().filter(|form| !name.is_empty() && form.is_registered(&name));
+
+    let internal_value = use_signal(String::new);
+    let mut value = props.value.unwrap_or(internal_value);
+    let password = match form {
+        Some(form) => form.value(&name).to_string(),
+        None => value(),
+    };
+
+    let mut visible = use_signal(|| false);
+
+    let on_value_change = props.on_value_change;
+    let handle_input = move |event: FormEvent| {
+        value.set(event.value());
+        if let Some(handler) = &on_value_change {
+            handler.call(event.value());
+        }
+    };
+
+    let strength = props.show_strength.then(|| {
+        let score = match &props.score {
+            Some(score) => score.call(password.clone()),
+            None => score_password(&password),
+        };
+        PasswordStrength::from_score(score)
+    });
+
+    // The hints describe the input, alongside any FormField description Input adds itself
+    let has_hints = props.show_strength || !props.requirements.is_empty();
+    let aria_describedby = has_hints.then(|| hints_id.clone());
+
+    rsx! {
+        div {
+            class: if props.full_width { "flex w-full flex-col gap-2" } else { "inline-flex flex-col gap-2" },
+
+            Input {
+                id: Some(id.clone()),
+                input_type: if visible() { "text".to_string() } else { "password".to_string() },
+                name: name.clone(),
+                value: password.clone(),
+                placeholder: props.placeholder.clone(),
+                variant: props.variant,
+                size: props.size,
+                disabled: props.disabled,
+                required: props.required,
+                full_width: props.full_width,
+                aria_label: props.aria_label.clone(),
+                aria_describedby: aria_describedby,
+                rules: props.rules.clone(),
+                validation_mode: props.validation_mode,
+                class: props.class.clone(),
+                on_input: handle_input,
+                autocomplete: Some(props.autocomplete.clone()),
+                icon_right_interactive: true,
+                icon_right: rsx! {
+                    button {
+                        type: "button",
+                        class: "rounded-sm p-1 text-muted-foreground hover:text-foreground focus:outline-none focus-visible:ring-2 focus-visible:ring-ring disabled:cursor-not-allowed",
+                        // A toggle button keeps its label fixed and reports its state through aria-pressed
+                        aria_label: "Show password",
+                        aria_pressed: visible().to_string(),
+                        aria_controls: id.clone(),
+                        disabled: props.disabled,
+                        onclick: move |_| {
+                            let next = !visible();
+                            visible.set(next);
+                        },
+                        if visible() {
+                            EyeOff { size: 16 }
+                        } else {
+                            Eye { size: 16 }
+                        }
+                    }
+                },
+                ..props.attributes,
+            }
+
+            if has_hints {
+                div {
+                    id: hints_id,
+                    class: "flex flex-col gap-2",
+
+                    if let Some(strength) = strength {
+                        div {
+                            class: "flex items-center gap-2",
+                            div {
+                                class: "flex-1",
+                                Progress {
+                                    value: strength.score() as f64,
+                                    max: 4.0,
+                                    size: ProgressSize::Small,
+                                    variant: strength.variant(),
+                                    aria_label: Some("Password strength".to_string()),
+                                }
+                            }
+                            span {
+                                class: "w-16 text-right text-xs text-muted-foreground",
+                                aria_live: "polite",
+                                if password.is_empty() { "" } else { strength.label() }
+                            }
+                        }
+                    }
+
+                    if !props.requirements.is_empty() {
+                        ul {
+                            class: "flex flex-col gap-1 text-xs",
+                            for requirement in props.requirements.iter() {
+                                {
+                                    let met = requirement.is_met(&password);
+                                    let label = requirement.label().to_string();
+                                    rsx! {
+                                        li {
+                                            key: "{label}",
+                                            class: if met { "flex items-center gap-1.5 text-green-600 dark:text-green-500" } else { "flex items-center gap-1.5 text-muted-foreground" },
+                                            span {
+                                                aria_hidden: "true",
+                                                if met {
+                                                    Check { size: 14 }
+                                                } else {
+                                                    X { size: 14 }
+                                                }
+                                            }
+                                            span { "{label}" }
+                                            span { class: "sr-only", if met { "(met)" } else { "(not met)" } }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scores_weak_passwords_zero() {
+        assert_eq!(score_password(""), 0);
+        assert_eq!(score_password("password"), 0);
+        assert_eq!(score_password("QWERTY"), 0);
+        assert_eq!(score_password("aaaaaaaaaaaaaaaa"), 0);
+        assert_eq!(score_password("abc"), 0);
+    }
+
+    #[test]
+    fn caps_short_passwords() {
+        assert_eq!(score_password("aB3!"), 1);
+    }
+
+    #[test]
+    fn scores_length_and_character_classes() {
+        assert_eq!(score_password("abcdefgh"), 1);
+        assert_eq!(score_password("Abcdefgh12"), 2);
+        assert_eq!(score_password("Abcdefgh12!"), 3);
+        assert_eq!(score_password("Abcdefgh123!"), 4);
+        // Long passphrases score well without every class
+        assert_eq!(score_password("correct horse battery"), 3);
+    }
+
+    #[test]
+    fn counts_characters_not_bytes() {
+        assert_eq!(score_password("äöüäöüäö"), 1);
+        assert_eq!(score_password("äöü"), 0);
+    }
+
+    #[test]
+    fn maps_scores_to_strengths() {
+        for score in 0..=4 {
+            assert_eq!(PasswordStrength::from_score(score).score(), score);
+        }
+        assert_eq!(PasswordStrength::from_score(9), PasswordStrength::Strong);
+        assert_eq!(PasswordStrength::from_score(2).label(), "Fair");
+    }
+
+    #[test]
+    fn checks_requirements() {
+        assert!(PasswordRequirement::min_length(8).is_met("abcdefgh"));
+        assert!(!PasswordRequirement::min_length(8).is_met("äöüäöüä"));
+        assert!(PasswordRequirement::uppercase().is_met("abC"));
+        assert!(!PasswordRequirement::lowercase().is_met("ABC"));
+        assert!(PasswordRequirement::digit().is_met("a1"));
+        assert!(PasswordRequirement::symbol().is_met("a b"));
+        assert_eq!(
+            PasswordRequirement::min_length(12).label(),
+            "At least 12 characters"
+        );
+    }
+}
@@ -0,0 +1,310 @@
+use crate::components::form::use_form_control;
+use crate::use_unique_id;
+use dioxus_lib::prelude::*;
+use lucide_dioxus::Star;
+
+/// Rating size options
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RatingSize {
+    Small,
+    Medium,
+    Large,
+}
+
+impl Default for RatingSize {
+    fn default() -> Self {
+        Self::Medium
+    }
+}
+
+impl RatingSize {
+    // The icon size in pixels, matching the w-4/w-5/w-6 slots
+    fn pixels(&self) -> usize {
+        match self {
+            RatingSize::Small => 16,
+            RatingSize::Medium => 20,
+            RatingSize::Large => 24,
+        }
+    }
+}
+
+/// What a custom Rating icon is drawn for
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct RatingIcon {
+    /// The icon size in pixels
+    pub size: usize,
+    /// Whether the icon is the filled layer drawn over the empty one
+    pub filled: bool,
+}
+
+/// Props for the Rating component
+#[derive(Props, Clone, PartialEq)]
+pub struct RatingProps {
+    /// The current rating. The rating updates this signal when the user picks a value.
+    /// Leave unset to let the rating manage its own state.
+    #[props(default)]
+    pub value: Option<Signal<f64>>,
+
+    /// Initial rating when `value` is not provided
+    #[props(default)]
+    pub default_value: f64,
+
+    /// Callback with the rating when it changes
+    #[props(default)]
+    pub on_value_change: Option<EventHandler<f64>>,
+
+    /// Number of icons
+    #[props(default = 5)]
+    pub count: usize,
+
+    /// Whether half steps can be picked
+    #[props(default)]
+    pub allow_half: bool,
+
+    /// Whether picking the current rating again clears it
+    #[props(default = true)]
+    pub allow_clear: bool,
+
+    /// Whether the rating is only displayed. Read-only ratings show fractional values as is.
+    #[props(default)]
+    pub read_only: bool,
+
+    /// Whether the rating is disabled
+    #[props(default)]
+    pub disabled: bool,
+
+    /// Renders a custom icon instead of a star
+    #[props(default)]
+    pub icon: Option<Callback<RatingIcon, Element>>,
+
+    /// Size of the icons
+    #[props(default)]
+    pub size: RatingSize,
+
+    /// Name of the hidden input for form submission
+    #[props(default)]
+    pub name: String,
+
+    /// Optional ID for the rating
+    #[props(default)]
+    pub id: Option<String>,
+
+    /// Accessible label for the rating
+    #[props(default)]
+    pub aria_label: Option<String>,
+
+    /// Optional additional classes for the container
+    #[props(default)]
+    pub class: Option<String>,
+
+    #[props(extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+}
+
+/// A row of stars, or custom icons, for picking or displaying a rating
+#[component]
+pub fn Rating(props: RatingProps) -> Element {
+    // Take the id, name and label from the surrounding FormControl, if any
+    let control = use_form_control();
+
+    // Generate unique ID if not provided
+    let rating_id = use_unique_id();
+    let id = props
+        .id
+        .clone()
+        .or_else(|| control.map(|control| control.control_id()))
+        .unwrap_or_else(|| rating_id());
+
+    let name = if props.name.is_empty() {
+        control
+            .and_then(|control| control.name())
+            .unwrap_or_default()
+    } else {
+        props.name.clone()
+    };
+
+    // Use the provided signal when controlled, otherwise keep the rating internally
+    let internal_value = use_signal(|| props.default_value);
+    let mut value = props.value.unwrap_or(internal_value);
+    let mut hovered = use_signal(|| None::<f64>);
+
+    let count = props.count;
+    let step = if props.allow_half { 0.5 } else { 1.0 };
+    let is_interactive = !props.read_only && !props.disabled;
+    let pixels = props.size.pixels();
+
+    let on_value_change = props.on_value_change;
+    let mut set_rating = move |next: f64| {
+        let next = next.clamp(0.0, count as f64);
+        if *value.peek() == next {
+            return;
+        }
+        value.set(next);
+        if let Some(handler) = &on_value_change {
+            handler.call(next);
+        }
+    };
+
+    let allow_clear = props.allow_clear;
+    let mut pick = move |next: f64| {
+        if allow_clear && *value.peek() == next {
+            set_rating(0.0);
+        } else {
+            set_rating(next);
+        }
+    };
+
+    // The value picked at a horizontal position within an icon, the left half picks a half step
+    let value_at = move |index: usize, x: f64| {
+        if step < 1.0 && x < pixels as f64 / 2.0 {
+            index as f64 + 0.5
+        } else {
+            index as f64 + 1.0
+        }
+    };
+
+    let handle_key_down = move |event: KeyboardEvent| {
+        if !is_interactive {
+            return;
+        }
+        let current = *value.peek();
+        let next = match event.key() {
+            Key::ArrowRight | Key::ArrowUp => Some(current + step),
+            Key::ArrowLeft | Key::ArrowDown => Some(current - step),
+            Key::Home => Some(0.0),
+            Key::End => Some(count as f64),
+            Key::Character(text) => text
+                .parse::<usize>()
+                .ok()
+                .filter(|digit| *digit <= count)
+                .map(|digit| digit as f64),
+            _ => None,
+        };
+        if let Some(next) = next {
+            event.prevent_default();
+            // Snap to the step so a fractional value from outside moves predictably
+            set_rating((next / step).round() * step);
+        }
+    };
+
+    // Determine size classes
+    let (slot_size, gap) = match props.size {
+        RatingSize::Small => ("h-4 w-4", "gap-0.5"),
+        RatingSize::Medium => ("h-5 w-5", "gap-1"),
+        RatingSize::Large => ("h-6 w-6", "gap-1"),
+    };
+
+    // Determine state classes
+    let state_class = if props.disabled {
+        "opacity-50 cursor-not-allowed"
+    } else if props.read_only {
+        ""
+    } else {
+        "cursor-pointer"
+    };
+
+    let container_classes = vec![
+        "inline-flex items-center rounded-sm",
+        "focus:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2",
+        gap,
+        state_class,
+        props.class.as_deref().unwrap_or(""),
+    ]
+    .into_iter()
+    .filter(|s| !s.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ");
+
+    let current = value();
+    // The hover preview shows the value a click would pick
+    let shown = hovered().filter(|_| is_interactive).unwrap_or(current);
+    let unit = if props.icon.is_some() { "" } else { " stars" };
+    let rating_text = format!("{} out of {}{}", current, count, unit);
+    let label = props
+        .aria_label
+        .clone()
+        .unwrap_or_else(|| "Rating".to_string());
+
+    // Read-only ratings are labelled with their value, interactive ones by the FormControl label if any
+    let aria_label = if props.read_only {
+        Some(format!("{}: {}", label, rating_text))
+    } else if props.aria_label.is_none() && control.is_some() {
+        None
+    } else {
+        Some(label)
+    };
+
+    let icon = props.icon;
+    let render_icon = move |filled: bool| match &icon {
+        Some(icon) => icon.call(RatingIcon {
+            size: pixels,
+            filled,
+        }),
+        None if filled => rsx! { Star { size: pixels, fill: "currentColor" } },
+        None => rsx! { Star { size: pixels } },
+    };
+
+    rsx! {
+        div {
+            id: id.clone(),
+            class: container_classes,
+            // Read-only ratings are a picture of the value, interactive ones a slider over it
+            role: if props.read_only { "img" } else { "slider" },
+            tabindex: if is_interactive { "0" } else { "-1" },
+            aria_label: aria_label,
+            aria_labelledby: control
+                .filter(|_| props.aria_label.is_none() && !props.read_only)
+                .map(|control| control.label_id()),
+            aria_valuemin: (!props.read_only).then_some("0"),
+            aria_valuemax: (!props.read_only).then(|| count.to_string()),
+            aria_valuenow: (!props.read_only).then(|| current.to_string()),
+            aria_valuetext: (!props.read_only).then(|| rating_text.clone()),
+            aria_disabled: props.disabled.then_some("true"),
+            onkeydown: handle_key_down,
+            onmouseleave: move |_| hovered.set(None),
+            ..props.attributes,
+
+            for index in 0..count {
+                {
+                    // How much of this icon is filled, from 0 to 1
+                    let fill = (shown - index as f64).clamp(0.0, 1.0);
+                    let fill_style = format!("width: {}%;", fill * 100.0);
+                    rsx! {
+                        span {
+                            key: "{index}",
+                            class: "relative inline-flex shrink-0 {slot_size}",
+                            aria_hidden: "true",
+                            onmousemove: move |event: MouseEvent| {
+                                if !is_interactive {
+                                    return;
+                                }
+                                hovered.set(Some(value_at(index, event.element_coordinates().x)));
+                            },
+                            onclick: move |event: MouseEvent| {
+                                if !is_interactive {
+                                    return;
+                                }
+                                pick(value_at(index, event.element_coordinates().x));
+                                hovered.set(None);
+                            },
+
+                            span {
+                                class: "pointer-events-none text-muted-foreground/40",
+                                {render_icon(false)}
+                            }
+                            span {
+                                class: "pointer-events-none absolute inset-y-0 left-0 overflow-hidden text-amber-400",
+                                style: fill_style,
+                                {render_icon(true)}
+                            }
+                        }
+                    }
+                }
+            }
+
+            if !name.is_empty() {
+                input { type: "hidden", name: name.clone(), value: current.to_string() }
+            }
+        }
+    }
+}
@@ -0,0 +1,568 @@
+use crate::components::button::{Button, ButtonVariant};
+use crate::components::checkbox::Checkbox;
+use crate::components::form::{
+    FieldConfig, FieldValue, Form, FormControl, FormDescription, FormField, FormLabel, FormMessage,
+    FormValues, UseForm, use_form, use_form_control,
+};
+use crate::components::input::Input;
+use crate::components::switch::Switch;
+use crate::components::validation::{self, Rule};
+use dioxus_lib::prelude::*;
+use serde_json::Value;
+
+// The control rendered for a schema property
+#[derive(Clone, PartialEq, Debug)]
+enum SchemaControl {
+    Input(&'static str),
+    Checkbox,
+    Switch,
+    Select(Vec<(String, String)>),
+}
+
+// A form field described by one schema property
+#[derive(Clone, PartialEq, Debug)]
+struct SchemaField {
+    name: String,
+    label: String,
+    description: Option<String>,
+    placeholder: String,
+    // The step of number inputs
+    step: Option<String>,
+    control: SchemaControl,
+    required: bool,
+    default: FieldValue,
+    rules: Vec<Rule>,
+}
+
+impl SchemaField {
+    fn parse(name: &str, property: &Value, required: bool) -> Self {
+        let kind = property["type"].as_str().unwrap_or("string");
+        let format = property["format"].as_str().unwrap_or("");
+        let widget = property["widget"].as_str().unwrap_or("");
+
+        let mut rules = Vec::new();
+        if required {
+            rules.push(validation::required());
+        }
+
+        let control = if let Some(options) = property["enum"].as_array() {
+            let labels = property["enumNames"].as_array();
+            let options = options
+                .iter()
+                .enumerate()
+                .map(|(index, option)| {
+                    let value = FieldValue::from_json(option).to_string();
+                    let label = labels
+                        .and_then(|labels| labels.get(index))
+                        .and_then(Value::as_str)
+                        .map(str::to_string)
+                        .unwrap_or_else(|| value.clone());
+                    (value, label)
+                })
+                .collect();
+            SchemaControl::Select(options)
+        } else {
+            match kind {
+                "boolean" if widget == "switch" => SchemaControl::Switch,
+                "boolean" => SchemaControl::Checkbox,
+                "number" | "integer" => SchemaControl::Input("number"),
+                _ => match format {
+                    "email" => SchemaControl::Input("email"),
+                    "uri" | "url" => SchemaControl::Input("url"),
+                    "password" => SchemaControl::Input("password"),
+                    "date" => SchemaControl::Input("date"),
+                    _ => SchemaControl::Input("text"),
+                },
+            }
+        };
+
+        match format {
+            "email" => rules.push(validation::email()),
+            "uri" | "url" => rules.push(validation::url()),
+            _ => {}
+        }
+        if let Some(min) = property["minLength"].as_u64() {
+            rules.push(validation::min_len(min as usize));
+        }
+        if let Some(max) = property["maxLength"].as_u64() {
+            rules.push(validation::max_len(max as usize));
+        }
+        // Schemas may come from a server, so an invalid pattern is skipped instead of panicking.
+        // JSON Schema allows lookaround and backreferences, which the regex crate rejects.
+        if let Some(pattern) = property["pattern"].as_str() {
+            match validation::try_pattern(pattern) {
+                Ok(rule) => rules.push(rule),
+                Err(error) => {
+                    log::warn!("SchemaForm: ignoring invalid pattern for \"{name}\": {error}")
+                }
+            }
+        }
+        // Text that doesn't parse as a number would otherwise be submitted as a JSON string
+        if matches!(kind, "number" | "integer") {
+            let integer = kind == "integer";
+            rules.push(validation::custom(move |value, _| {
+                match f64::try_from(value.clone()) {
+                    Ok(number) if integer && number.fract() != 0.0 => {
+                        Err("Must be a whole number".to_string())
+                    }
+                    Ok(_) => Ok(()),
+                    Err(_) => Err("Must be a number".to_string()),
+                }
+            }));
+        }
+        let minimum = property["minimum"].as_f64();
+        let maximum = property["maximum"].as_f64();
+        if minimum.is_some() || maximum.is_some() {
+            rules.push(validation::range(
+                minimum.unwrap_or(f64::NEG_INFINITY),
+                maximum.unwrap_or(f64::INFINITY),
+            ));
+        }
+        let step = match kind {
+            "number" | "integer" => Some(
+                property["multipleOf"]
+                    .as_f64()
+                    .map(|step| step.to_string())
+                    .unwrap_or_else(|| if kind == "integer" { "1" } else { "any" }.to_string()),
+            ),
+            _ => None,
+        };
+
+        // Numbers start empty rather than at zero unless a default is given
+        let default = match &property["default"] {
+            Value::Null => match kind {
+                "boolean" => FieldValue::Bool(false),
+                "number" | "integer" => FieldValue::Number(f64::NAN),
+                _ => FieldValue::default(),
+            },
+            value => FieldValue::from_json(value),
+        };
+
+        Self {
+            name: name.to_string(),
+            label: property["title"].as_str().unwrap_or(name).to_string(),
+            description: property["description"].as_str().map(str::to_string),
+            placeholder: property["placeholder"].as_str().unwrap_or("").to_string(),
+            step,
+            control,
+            required,
+            default,
+            rules,
+        }
+    }
+
+    fn config(&self, initial: &Value) -> FieldConfig {
+        let default = match &initial[&self.name] {
+            Value::Null => self.default.clone(),
+            value => FieldValue::from_json(value),
+        };
+        FieldConfig::new(self.name.clone(), default).rules(self.rules.clone())
+    }
+}
+
+// Read the properties of an object schema, honouring an optional "order" list
+fn parse_schema(schema: &Value) -> Vec<SchemaField> {
+    let Some(properties) = schema["properties"].as_object() else {
+        return Vec::new();
+    };
+    let required = schema["required"]
+        .as_array()
+        .map(|names| names.iter().filter_map(Value::as_str).collect::<Vec<_>>())
+        .unwrap_or_default();
+
+    let mut names = schema["order"]
+        .as_array()
+        .map(|order| {
+            order
+                .iter()
+                .filter_map(Value::as_str)
+                .filter(|name| properties.contains_key(*name))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    for name in properties.keys() {
+        if !names.contains(&name.as_str()) {
+            names.push(name.as_str());
+        }
+    }
+
+    names
+        .into_iter()
+        .map(|name| SchemaField::parse(name, &properties[name], required.contains(&name)))
+        .collect()
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct SchemaFormProps {
+    /// A JSON-Schema-like object description with `properties`, `required` and an optional `order`
+    schema: ReadOnlySignal<Value>,
+
+    /// Initial values as a JSON object, overriding the schema defaults
+    #[props(default)]
+    values: Value,
+
+    /// Called with the values as a JSON object once every field is valid
+    #[props(default)]
+    on_submit: Option<EventHandler<Value>>,
+
+    /// Label of the submit button
+    #[props(default = String::from("Submit"))]
+    submit_label: String,
+
+    /// Whether to show a reset button
+    #[props(default)]
+    show_reset: bool,
+
+    /// Optional additional classes for the form
+    #[props(default)]
+    class: Option<String>,
+}
+
+/// Renders a form with inputs, checkboxes, switches and selects generated from a schema
+#[component]
+pub fn SchemaForm(props: SchemaFormProps) -> Element {
+    let schema = props.schema;
+    let fields = use_memo(move || parse_schema(&schema.read()));
+
+    let initial = props.values.clone();
+    let form = use_form(|| {
+        fields
+            .peek()
+            .iter()
+            .map(|field| field.config(&initial))
+            .collect()
+    });
+
+    // Register fields added by later schema changes
+    use_effect(move || {
+        for field in fields.read().iter() {
+            form.register(field.config(&Value::Null));
+        }
+    });
+
+    let on_submit = props.on_submit;
+
+    rsx! {
+        Form {
+            form: form,
+            class: props.class.clone(),
+            on_submit: move |values: FormValues| {
+                if let Some(handler) = on_submit {
+                    handler.call(values.to_json());
+                }
+            },
+
+            for field in fields() {
+                FormField {
+                    key: "{field.name}",
+                    name: Some(field.name.clone()),
+                    required: field.required,
+
+                    {match field.control.clone() {
+                        SchemaControl::Input(input_type) => rsx! {
+                            FormLabel { "{field.label}" }
+                            FormControl {
+                                Input {
+                                    input_type: input_type.to_string(),
+                                    placeholder: field.placeholder.clone(),
+                                    step: field.step.clone(),
+                                    full_width: true,
+                                }
+                            }
+                        },
+                        SchemaControl::Select(options) => rsx! {
+                            FormLabel { "{field.label}" }
+                            FormControl {
+                                SchemaSelect { form: form, name: field.name.clone(), options: options }
+                            }
+                        },
+                        SchemaControl::Checkbox => rsx! {
+                            div { class: "flex items-center gap-2",
+                                FormControl {
+                                    SchemaToggle { form: form, name: field.name.clone(), switch: false }
+                                }
+                                FormLabel { class: "mb-0", "{field.label}" }
+                            }
+                        },
+                        SchemaControl::Switch => rsx! {
+                            div { class: "flex items-center justify-between",
+                                FormLabel { class: "mb-0", "{field.label}" }
+                                FormControl {
+                                    SchemaToggle { form: form, name: field.name.clone(), switch: true }
+                                }
+                            }
+                        },
+                    }}
+
+                    if let Some(description) = field.description.clone() {
+                        FormDescription { "{description}" }
+                    }
+                    FormMessage {}
+                }
+            }
+
+            div { class: "flex gap-2",
+                Button {
+                    variant: ButtonVariant::Primary,
+                    button_type: "submit".to_string(),
+                    loading: form.is_submitting(),
+                    "{props.submit_label}"
+                }
+                if props.show_reset {
+                    Button {
+                        variant: ButtonVariant::Outline,
+                        button_type: "reset".to_string(),
+                        disabled: !form.is_dirty(),
+                        "Reset"
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct SchemaToggleProps {
+    form: UseForm,
+    name: String,
+    switch: bool,
+}
+
+// A checkbox or switch bound to a boolean form field
+#[component]
+fn SchemaToggle(props: SchemaToggleProps) -> Element {
+    let form = props.form;
+    let name = props.name.clone();
+    let mut checked = use_signal(|| form.get::<bool>(&name).unwrap_or(false));
+
+    // Follow the form value, e.g. after a reset
+    let effect_name = props.name.clone();
+    use_effect(move || checked.set(form.get::<bool>(&effect_name).unwrap_or(false)));
+
+    let on_checked_change = move |state: bool| {
+        checked.set(state);
+        form.set_value(&name, state);
+        form.touch(&name);
+    };
+
+    rsx! {
+        if props.switch {
+            Switch { checked: checked, on_checked_change: on_checked_change }
+        } else {
+            Checkbox { checked: checked, on_checked_change: on_checked_change }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct SchemaSelectProps {
+    form: UseForm,
+    name: String,
+    options: Vec<(String, String)>,
+}
+
+// A native select bound to a form field
+#[component]
+fn SchemaSelect(props: SchemaSelectProps) -> Element {
+    let control = use_form_control();
+    let form = props.form;
+    let value = form.value(&props.name).to_string();
+    let is_invalid = form.error(&props.name).is_some();
+
+    let select_classes = vec![
+        "w-full rounded border bg-background text-foreground text-sm px-3 py-1.5 h-10",
+        "transition-colors focus:outline-none focus:ring-2 focus:ring-ring focus:ring-offset-2",
+        if is_invalid {
+            "border-destructive focus:border-destructive"
+        } else {
+            "border-input focus:border-ring"
+        },
+    ]
+    .join(" ");
+
+    let change_name = props.name.clone();
+    let blur_name = props.name.clone();
+
+    rsx! {
+        select {
+            id: control.map(|control| control.control_id()),
+            name: props.name.clone(),
+            class: select_classes,
+            value: value.clone(),
+            onchange: move |event: FormEvent| form.set_value_from_str(&change_name, &event.value()),
+            onblur: move |_| form.touch(&blur_name),
+            aria_describedby: control.and_then(|control| control.describedby()),
+            aria_invalid: is_invalid.then_some("true"),
+            aria_required: control.is_some_and(|control| control.is_required()).then_some("true"),
+
+            option { value: "", disabled: true, selected: value.is_empty(), "Select..." }
+            for (option_value, label) in props.options {
+                option {
+                    key: "{option_value}",
+                    selected: option_value == value,
+                    value: option_value.clone(),
+                    "{label}"
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::validation::{ValidationMessages, validate_rules};
+    use serde_json::json;
+
+    fn field(property: Value) -> SchemaField {
+        SchemaField::parse("field", &property, false)
+    }
+
+    fn check(field: &SchemaField, value: impl Into<FieldValue>) -> Result<(), String> {
+        validate_rules(
+            &field.rules,
+            &value.into(),
+            &FormValues::default(),
+            &ValidationMessages::default(),
+        )
+    }
+
+    #[test]
+    fn enums_become_selects() {
+        let parsed = field(json!({ "enum": ["sm", "lg"], "enumNames": ["Small"] }));
+        assert_eq!(
+            parsed.control,
+            SchemaControl::Select(vec![
+                ("sm".to_string(), "Small".to_string()),
+                ("lg".to_string(), "lg".to_string()),
+            ])
+        );
+
+        let parsed = field(json!({ "type": "integer", "enum": [1, 2] }));
+        assert_eq!(
+            parsed.control,
+            SchemaControl::Select(vec![
+                ("1".to_string(), "1".to_string()),
+                ("2".to_string(), "2".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn booleans_become_checkboxes_or_switches() {
+        let parsed = field(json!({ "type": "boolean" }));
+        assert_eq!(parsed.control, SchemaControl::Checkbox);
+        assert_eq!(parsed.default, FieldValue::Bool(false));
+
+        let parsed = field(json!({ "type": "boolean", "widget": "switch", "default": true }));
+        assert_eq!(parsed.control, SchemaControl::Switch);
+        assert_eq!(parsed.default, FieldValue::Bool(true));
+    }
+
+    #[test]
+    fn strings_pick_an_input_type_from_the_format() {
+        assert_eq!(field(json!({})).control, SchemaControl::Input("text"));
+        let parsed = field(json!({ "type": "string", "format": "email" }));
+        assert_eq!(parsed.control, SchemaControl::Input("email"));
+        assert!(check(&parsed, "ada@example.com").is_ok());
+        assert!(check(&parsed, "ada").is_err());
+        assert_eq!(parsed.step, None);
+    }
+
+    #[test]
+    fn integers_step_by_whole_numbers() {
+        let parsed = field(json!({ "type": "integer" }));
+        assert_eq!(parsed.control, SchemaControl::Input("number"));
+        assert_eq!(parsed.step.as_deref(), Some("1"));
+        assert!(matches!(parsed.default, FieldValue::Number(n) if n.is_nan()));
+        assert!(check(&parsed, 3.0).is_ok());
+        assert_eq!(
+            check(&parsed, 2.5),
+            Err("Must be a whole number".to_string())
+        );
+
+        let parsed = field(json!({ "type": "integer", "multipleOf": 5 }));
+        assert_eq!(parsed.step.as_deref(), Some("5"));
+    }
+
+    #[test]
+    fn numbers_reject_non_numeric_text() {
+        let parsed = field(json!({ "type": "number" }));
+        assert_eq!(parsed.step.as_deref(), Some("any"));
+        assert!(check(&parsed, 2.5).is_ok());
+        assert!(check(&parsed, "2.5").is_ok());
+        assert_eq!(check(&parsed, "abc"), Err("Must be a number".to_string()));
+        assert_eq!(
+            check(&field(json!({ "type": "integer" })), "abc"),
+            Err("Must be a number".to_string())
+        );
+    }
+
+    #[test]
+    fn minimum_and_maximum_become_a_range() {
+        let parsed = field(json!({ "type": "number", "minimum": 0, "maximum": 10 }));
+        assert!(parsed.rules.contains(&validation::range(0.0, 10.0)));
+        assert!(check(&parsed, 10.0).is_ok());
+        assert!(check(&parsed, 11.0).is_err());
+
+        let parsed = field(json!({ "type": "number", "minimum": 1 }));
+        assert!(
+            parsed
+                .rules
+                .contains(&validation::range(1.0, f64::INFINITY))
+        );
+    }
+
+    #[test]
+    fn maps_lengths_and_required() {
+        let parsed = SchemaField::parse(
+            "name",
+            &json!({ "minLength": 2, "maxLength": 4, "title": "Name" }),
+            true,
+        );
+        assert_eq!(parsed.label, "Name");
+        assert!(parsed.required);
+        assert_eq!(
+            check(&parsed, ""),
+            Err("This field is required".to_string())
+        );
+        assert!(check(&parsed, "a").is_err());
+        assert!(check(&parsed, "abc").is_ok());
+        assert!(check(&parsed, "abcde").is_err());
+    }
+
+    #[test]
+    fn skips_invalid_patterns() {
+        let parsed = field(json!({ "pattern": "^[a-z]+$" }));
+        assert!(check(&parsed, "abc").is_ok());
+        assert!(check(&parsed, "ABC").is_err());
+
+        // Lookahead is valid JSON Schema, but the regex crate rejects it
+        let parsed = field(json!({ "pattern": "^(?=.*\\d).+$" }));
+        assert!(parsed.rules.is_empty());
+        assert!(check(&parsed, "abc").is_ok());
+    }
+
+    #[test]
+    fn orders_fields() {
+        let schema = json!({
+            "properties": { "city": {}, "email": {}, "name": {} },
+            "order": ["name", "missing", "email"],
+            "required": ["email"],
+        });
+        let fields = parse_schema(&schema);
+        let names = fields
+            .iter()
+            .map(|field| field.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["name", "email", "city"]);
+        assert!(fields[1].required);
+        assert!(!fields[0].required);
+    }
+
+    #[test]
+    fn ignores_schemas_without_properties() {
+        assert!(parse_schema(&json!({ "type": "object" })).is_empty());
+        assert!(parse_schema(&json!("not a schema")).is_empty());
+    }
+}
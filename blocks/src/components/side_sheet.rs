@@ -1,5 +1,7 @@
+use crate::use_unique_id;
 use dioxus_lib::prelude::*;
 use lucide_dioxus::X;
+use std::rc::Rc;
 
 // Side from which the sheet appears
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -27,10 +29,14 @@ impl SideSheetSide {
 }
 
 // Context for sharing state between side sheet components
-#[derive(Clone)]
+#[derive(Clone, Copy)]
 struct SideSheetContext {
-    is_open: Signal<bool>,
+    is_open: Memo<bool>,
+    set_open: Callback<bool>,
     side: SideSheetSide,
+    trigger_id: Signal<String>,
+    title_id: Signal<String>,
+    description_id: Signal<String>,
 }
 
 // Main SideSheet component that provides context
@@ -39,23 +45,74 @@ pub struct SideSheetProps {
     #[props(default = SideSheetSide::Right)]
     pub side: SideSheetSide,
 
+    /// Whether the sheet is open (controlled). Leave unset to let the sheet manage its own state.
+    #[props(default)]
+    pub open: ReadOnlySignal<Option<bool>>,
+
     #[props(default = false)]
     pub default_open: bool,
 
+    /// Callback for when the sheet requests to open or close
+    #[props(default)]
+    pub on_open_change: Option<EventHandler<bool>>,
+
     pub children: Element,
 }
 
 #[component]
 pub fn SideSheet(props: SideSheetProps) -> Element {
-    let is_open = use_signal(|| props.default_open);
+    let mut internal_open = use_signal(|| props.default_open);
+    let controlled_open = props.open;
+    let is_open = use_memo(move || controlled_open().unwrap_or_else(|| internal_open()));
+
+    let on_open_change = props.on_open_change;
+    let set_open = use_callback(move |open: bool| {
+        // Only track the state ourselves when nobody else is controlling it
+        if controlled_open.peek().is_none() {
+            internal_open.set(open);
+        }
+        if let Some(handler) = &on_open_change {
+            handler.call(open);
+        }
+    });
+
+    // Derive every ARIA id from one generated id so several sheets can live on the same page
+    let sheet_id = use_unique_id();
+    let trigger_id = use_signal(|| format!("{}-trigger", sheet_id.peek()));
+    let title_id = use_signal(|| format!("{}-title", sheet_id.peek()));
+    let description_id = use_signal(|| format!("{}-description", sheet_id.peek()));
 
     let context = SideSheetContext {
         is_open,
+        set_open,
         side: props.side,
+        trigger_id,
+        title_id,
+        description_id,
     };
 
     use_context_provider(|| context);
 
+    // Give focus back to the trigger once the sheet closes
+    let mut was_open = use_signal(|| is_open());
+    use_effect(move || {
+        let open = is_open();
+        if *was_open.peek() && !open {
+            let script = format!(
+                r#"
+                const trigger = document.getElementById("{}");
+                if (trigger) {{
+                    const target = trigger.querySelector("button, a[href], input, select, textarea, [tabindex]:not([tabindex='-1'])") || trigger;
+                    target.focus();
+                }}
+                "#,
+                trigger_id.peek()
+            );
+            let _ = document::eval(&script);
+        }
+        was_open.set(open);
+    });
+
     rsx! {
         {props.children}
     }
@@ -69,14 +126,15 @@ pub struct SideSheetTriggerProps {
 
 #[component]
 pub fn SideSheetTrigger(props: SideSheetTriggerProps) -> Element {
-    let mut context = use_context::<SideSheetContext>();
+    let context = use_context::<SideSheetContext>();
 
     let on_click = move |_| {
-        context.is_open.set(true);
+        context.set_open.call(true);
     };
 
     rsx! {
         div { class: "w-auto inline-block",
+            id: (context.trigger_id)(),
             onclick: on_click,
             {props.children}
         }
@@ -91,10 +149,10 @@ pub struct SideSheetCloseProps {
 
 #[component]
 pub fn SideSheetClose(props: SideSheetCloseProps) -> Element {
-    let mut context = use_context::<SideSheetContext>();
+    let context = use_context::<SideSheetContext>();
 
     let on_click = move |_| {
-        context.is_open.set(false);
+        context.set_open.call(false);
     };
 
     rsx! {
@@ -114,10 +172,10 @@ pub struct SideSheetOverlayProps {
 
 #[component]
 pub fn SideSheetOverlay(props: SideSheetOverlayProps) -> Element {
-    let mut context = use_context::<SideSheetContext>();
+    let context = use_context::<SideSheetContext>();
 
     let on_click = move |_| {
-        context.is_open.set(false);
+        context.set_open.call(false);
     };
 
     let is_open = *context.is_open.read();
@@ -146,8 +204,24 @@ pub fn SideSheetContent(props: SideSheetContentProps) -> Element {
     let context = use_context::<SideSheetContext>();
     let is_open = *context.is_open.read();
 
-    // We'll handle escape key with the aria-modal attribute
-    // which provides built-in keyboard handling
+    // Move focus into the sheet when it opens so keyboard events (like Escape) reach it
+    let mut content_ref: Signal<Option<Rc<MountedData>>> = use_signal(|| None);
+    use_effect(move || {
+        if (context.is_open)() {
+            if let Some(content) = content_ref() {
+                spawn(async move {
+                    let _ = content.set_focus(true).await;
+                });
+            }
+        }
+    });
+
+    let on_key_down = move |event: KeyboardEvent| {
+        if event.key() == Key::Escape {
+            event.stop_propagation();
+            context.set_open.call(false);
+        }
+    };
 
     let side_classes = context.side.content_classes();
     let animation_classes = context.side.animation_classes(is_open);
@@ -162,11 +236,16 @@ pub fn SideSheetContent(props: SideSheetContentProps) -> Element {
 
             // Content
             div {
-                class: "fixed z-50 bg-background border-l border-border shadow-lg transition ease-in-out duration-300 {side_classes} {animation_classes} {props.class}",
+                class: "fixed z-50 bg-background border-l border-border shadow-lg transition ease-in-out duration-300 focus:outline-none {side_classes} {animation_classes} {props.class}",
                 role: "dialog",
                 aria_modal: "true",
-                aria_labelledby: "side-sheet-title",
-                aria_describedby: "side-sheet-description",
+                aria_hidden: (!is_open).to_string(),
+                aria_labelledby: (context.title_id)(),
+                aria_describedby: (context.description_id)(),
+                "data-state": if is_open { "open" } else { "closed" },
+                tabindex: "-1",
+                onmounted: move |event: MountedEvent| content_ref.set(Some(event.data())),
+                onkeydown: on_key_down,
 
                 // Focus trap would be implemented here in a production version
 
@@ -206,9 +285,11 @@ pub struct SideSheetTitleProps {
 
 #[component]
 pub fn SideSheetTitle(props: SideSheetTitleProps) -> Element {
+    let context = use_context::<SideSheetContext>();
+
     rsx! {
         h2 {
-            id: "side-sheet-title",
+            id: (context.title_id)(),
             class: "text-lg font-semibold leading-none tracking-tight {props.class}",
             {props.children}
         }
@@ -226,9 +307,11 @@ pub struct SideSheetDescriptionProps {
 
 #[component]
 pub fn SideSheetDescription(props: SideSheetDescriptionProps) -> Element {
+    let context = use_context::<SideSheetContext>();
+
     rsx! {
         p {
-            id: "side-sheet-description",
+            id: (context.description_id)(),
             class: "text-sm text-muted-foreground {props.class}",
             {props.children}
         }
@@ -282,12 +365,12 @@ pub struct SideSheetCloseButtonProps {
 
 #[component]
 pub fn SideSheetCloseButton(props: SideSheetCloseButtonProps) -> Element {
-    let mut context = use_context::<SideSheetContext>();
+    let context = use_context::<SideSheetContext>();
 
     rsx! {
         button {
             class: "absolute right-4 top-4 rounded-sm opacity-70 ring-offset-background transition-opacity hover:opacity-100 focus:outline-none focus:ring-2 focus:ring-ring focus:ring-offset-2 disabled:pointer-events-none data-[state=open]:bg-secondary {props.class}",
-            onclick: move |_| context.is_open.set(false),
+            onclick: move |_| context.set_open.call(false),
             type: "button",
             aria_label: "Close",
 
@@ -0,0 +1,528 @@
+use crate::components::button::{Button, ButtonVariant};
+use crate::use_unique_id;
+use dioxus_lib::prelude::*;
+use lucide_dioxus::{Check, CircleAlert};
+use std::collections::BTreeSet;
+use std::fmt;
+use std::rc::Rc;
+
+/// Layout of a Stepper
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StepperOrientation {
+    /// Indicators in a row with the current step's content below
+    Horizontal,
+    /// Indicators in a column with the current step's content under its indicator
+    Vertical,
+}
+
+impl Default for StepperOrientation {
+    fn default() -> Self {
+        Self::Horizontal
+    }
+}
+
+/// The state shown by a step's indicator
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StepState {
+    Complete,
+    Current,
+    Error,
+    Skipped,
+    Upcoming,
+}
+
+type CanAdvanceFn = dyn Fn() -> bool;
+
+/// A step of a Stepper with its title and content
+#[derive(Clone)]
+pub struct Step {
+    title: String,
+    description: Option<String>,
+    optional: bool,
+    error: bool,
+    content: Element,
+    can_advance: Option<Rc<CanAdvanceFn>>,
+}
+
+impl Step {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            description: None,
+            optional: false,
+            error: false,
+            content: VNode::empty(),
+            can_advance: None,
+        }
+    }
+
+    /// A short line shown below the title
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Mark the step as optional. Optional steps can be skipped.
+    pub fn optional(mut self) -> Self {
+        self.optional = true;
+        self
+    }
+
+    /// Show the step in the error state, e.g. after a failed server-side check
+    pub fn error(mut self, error: bool) -> Self {
+        self.error = error;
+        self
+    }
+
+    /// The content shown while the step is current
+    pub fn content(mut self, content: Element) -> Self {
+        self.content = content;
+        self
+    }
+
+    /// Checked before moving past the step. While it returns false the step shows its error
+    /// state and the stepper stays on it.
+    pub fn can_advance(mut self, can_advance: impl Fn() -> bool + 'static) -> Self {
+        self.can_advance = Some(Rc::new(can_advance));
+        self
+    }
+
+    /// The step title
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    fn allows_advance(&self) -> bool {
+        self.can_advance
+            .as_ref()
+            .is_none_or(|can_advance| can_advance())
+    }
+}
+
+impl PartialEq for Step {
+    fn eq(&self, other: &Self) -> bool {
+        let same_check = match (&self.can_advance, &other.can_advance) {
+            (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        };
+        self.title == other.title
+            && self.description == other.description
+            && self.optional == other.optional
+            && self.error == other.error
+            && self.content == other.content
+            && same_check
+    }
+}
+
+impl fmt::Debug for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Step")
+            .field("title", &self.title)
+            .field("optional", &self.optional)
+            .field("error", &self.error)
+            .finish()
+    }
+}
+
+/// Props for the Stepper component
+#[derive(Props, Clone, PartialEq)]
+pub struct StepperProps {
+    /// The steps in order
+    pub steps: Vec<Step>,
+
+    /// Index of the current step. The stepper updates this signal as the user moves between steps.
+    /// Leave unset to let the stepper manage its own state.
+    #[props(default)]
+    pub active: Option<Signal<usize>>,
+
+    /// Initial step when `active` is not provided
+    #[props(default)]
+    pub default_active: usize,
+
+    /// Callback with the index of the new current step
+    #[props(default)]
+    pub on_active_change: Option<EventHandler<usize>>,
+
+    /// Callback when Next is pressed on the last step and it can advance
+    #[props(default)]
+    pub on_complete: Option<EventHandler<()>>,
+
+    /// Layout of the stepper
+    #[props(default)]
+    pub orientation: StepperOrientation,
+
+    /// Whether steps must be completed in order. Non-linear steppers let the user jump to any step.
+    #[props(default = true)]
+    pub linear: bool,
+
+    /// Whether to show the Back and Next buttons
+    #[props(default = true)]
+    pub show_controls: bool,
+
+    /// Text of the Back button
+    #[props(default = String::from("Back"))]
+    pub back_label: String,
+
+    /// Text of the Next button
+    #[props(default = String::from("Next"))]
+    pub next_label: String,
+
+    /// Text of the Next button on the last step
+    #[props(default = String::from("Finish"))]
+    pub finish_label: String,
+
+    /// Message shown when the current step's check fails
+    #[props(default = String::from("Complete this step before continuing"))]
+    pub error_label: String,
+
+    /// Optional ID for the stepper
+    #[props(default)]
+    pub id: Option<String>,
+
+    /// Accessible label for the list of steps
+    #[props(default)]
+    pub aria_label: Option<String>,
+
+    /// Optional additional classes for the stepper
+    #[props(default)]
+    pub class: Option<String>,
+
+    #[props(extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+}
+
+/// A multi-step flow with step indicators, Back and Next controls and per-step validation
+#[component]
+pub fn Stepper(props: StepperProps) -> Element {
+    // Generate unique ID if not provided
+    let stepper_id = use_unique_id();
+    let id = props.id.clone().unwrap_or_else(|| stepper_id());
+
+    // Use the provided signal when controlled, otherwise keep the step internally
+    let internal_active = use_signal(|| props.default_active);
+    let mut active = props.active.unwrap_or(internal_active);
+
+    // Steps the user moved past with Next or Skip, and the step whose check last failed
+    let mut completed = use_signal(BTreeSet::<usize>::new);
+    let mut skipped = use_signal(BTreeSet::<usize>::new);
+    let mut blocked = use_signal(|| None::<usize>);
+
+    let step_count = props.steps.len();
+    let current = active().min(step_count.saturating_sub(1));
+
+    let on_active_change = props.on_active_change;
+    let mut go_to = move |index: usize| {
+        if index >= step_count || *active.peek() == index {
+            return;
+        }
+        active.set(index);
+        if let Some(handler) = &on_active_change {
+            handler.call(index);
+        }
+    };
+
+    // Check the current step before moving forward, showing its error state when it fails
+    let steps = props.steps.clone();
+    let leave_current = use_callback(move |()| -> bool {
+        let allowed = steps.get(current).is_none_or(Step::allows_advance);
+        blocked.set((!allowed).then_some(current));
+        allowed
+    });
+
+    let on_complete = props.on_complete;
+    let handle_next = move |_: MouseEvent| {
+        if !leave_current.call(()) {
+            return;
+        }
+        completed.write().insert(current);
+        if current + 1 < step_count {
+            go_to(current + 1);
+        } else if let Some(handler) = &on_complete {
+            handler.call(());
+        }
+    };
+
+    // Optional steps move on without being marked complete
+    let handle_skip = move |_: MouseEvent| {
+        blocked.set(None);
+        skipped.write().insert(current);
+        go_to(current + 1);
+    };
+
+    let handle_back = move |_: MouseEvent| {
+        blocked.set(None);
+        if current > 0 {
+            go_to(current - 1);
+        }
+    };
+
+    let linear = props.linear;
+    // In a linear stepper, steps up to the first one that was neither completed nor skipped can be
+    // reached
+    let reachable = if linear {
+        (0..step_count)
+            .find(|index| {
+                *index != current
+                    && !completed.read().contains(index)
+                    && !skipped.read().contains(index)
+            })
+            .map(|first_incomplete| first_incomplete.max(current))
+            .unwrap_or(step_count.saturating_sub(1))
+    } else {
+        step_count.saturating_sub(1)
+    };
+    let mut select_step = move |index: usize| {
+        if index == current {
+            return;
+        }
+        if index < current || !linear {
+            blocked.set(None);
+            go_to(index);
+        } else if leave_current.call(()) {
+            completed.write().insert(current);
+            go_to(index);
+        }
+    };
+
+    let state_of = |index: usize, step: &Step| {
+        if step.error || blocked() == Some(index) {
+            StepState::Error
+        } else if index == current {
+            StepState::Current
+        } else if completed.read().contains(&index) {
+            StepState::Complete
+        } else if skipped.read().contains(&index) {
+            StepState::Skipped
+        } else {
+            StepState::Upcoming
+        }
+    };
+
+    let is_vertical = props.orientation == StepperOrientation::Vertical;
+    let is_last = current + 1 >= step_count;
+    let current_step = props.steps.get(current).cloned();
+    let announcement = current_step
+        .as_ref()
+        .map(|step| format!("Step {} of {}: {}", current + 1, step_count, step.title))
+        .unwrap_or_default();
+    let panel_id = format!("{}-panel", id);
+
+    let container_classes = vec![
+        if is_vertical {
+            "flex flex-col"
+        } else {
+            "flex flex-col gap-6"
+        },
+        props.class.as_deref().unwrap_or(""),
+    ]
+    .into_iter()
+    .filter(|s| !s.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ");
+
+    let back_label = props.back_label.clone();
+    let next_label = if is_last {
+        props.finish_label.clone()
+    } else {
+        props.next_label.clone()
+    };
+    let error_id = format!("{}-error", id);
+    let controls = rsx! {
+        if props.show_controls {
+            div {
+                class: "flex items-center gap-2",
+                Button {
+                    variant: ButtonVariant::Outline,
+                    disabled: current == 0,
+                    on_click: handle_back,
+                    "{back_label}"
+                }
+                if current_step.as_ref().is_some_and(|step| step.optional) && !is_last {
+                    Button {
+                        variant: ButtonVariant::Ghost,
+                        on_click: handle_skip,
+                        "Skip"
+                    }
+                }
+                Button {
+                    aria_describedby: blocked().map(|_| error_id.clone()),
+                    on_click: handle_next,
+                    "{next_label}"
+                }
+            }
+        }
+    };
+
+    rsx! {
+        div {
+            id: id.clone(),
+            class: container_classes,
+            ..props.attributes,
+
+            ol {
+                class: if is_vertical { "flex flex-col" } else { "flex items-start" },
+                aria_label: props.aria_label.clone().unwrap_or_else(|| "Steps".to_string()),
+
+                for (index, step) in props.steps.iter().enumerate() {
+                    {
+                        let state = state_of(index, step);
+                        let is_current = index == current;
+                        let can_select = index <= reachable && !is_current;
+                        let has_connector = index + 1 < step_count;
+                        let title_id = format!("{}-step-{}", id, index);
+                        let number = index + 1;
+                        let title = step.title.clone();
+                        let description = step.description.clone();
+
+                        let indicator_classes = match state {
+                            StepState::Complete => "bg-primary text-primary-foreground border-primary",
+                            StepState::Current => "border-primary text-primary bg-background",
+                            StepState::Error => "border-destructive text-destructive bg-background",
+                            StepState::Skipped => "border-dashed border-input text-muted-foreground bg-background",
+                            StepState::Upcoming => "border-input text-muted-foreground bg-background",
+                        };
+                        let connector_classes = match (is_vertical, state) {
+                            (true, StepState::Complete) => "my-1 w-px flex-1 min-h-6 bg-primary",
+                            (true, _) => "my-1 w-px flex-1 min-h-6 bg-border",
+                            (false, StepState::Complete) => "mx-3 mt-4 h-px flex-1 bg-primary",
+                            (false, _) => "mx-3 mt-4 h-px flex-1 bg-border",
+                        };
+                        let state_text = match state {
+                            StepState::Complete => "completed",
+                            StepState::Current => "current",
+                            StepState::Error => "needs attention",
+                            StepState::Skipped => "skipped",
+                            StepState::Upcoming => "not started",
+                        };
+
+                        let indicator = rsx! {
+                            span {
+                                class: "flex h-8 w-8 shrink-0 items-center justify-center rounded-full border-2 text-sm font-medium transition-colors {indicator_classes}",
+                                aria_hidden: "true",
+                                match state {
+                                    StepState::Complete => rsx! { Check { size: 16 } },
+                                    StepState::Error => rsx! { CircleAlert { size: 16 } },
+                                    _ => rsx! { "{number}" },
+                                }
+                            }
+                        };
+                        let label = rsx! {
+                            span {
+                                class: "flex flex-col text-left",
+                                span {
+                                    id: title_id.clone(),
+                                    class: if is_current { "text-sm font-medium text-foreground" } else { "text-sm font-medium text-muted-foreground" },
+                                    "{title}"
+                                }
+                                if step.optional {
+                                    span { class: "text-xs text-muted-foreground", "Optional" }
+                                }
+                                if let Some(description) = description {
+                                    span { class: "text-xs text-muted-foreground", "{description}" }
+                                }
+                                span { class: "sr-only", ", {state_text}" }
+                            }
+                        };
+                        let header_classes = if is_vertical {
+                            "flex items-start gap-3 rounded-sm text-left focus:outline-none focus-visible:ring-2 focus-visible:ring-ring"
+                        } else {
+                            "flex flex-col items-center gap-2 rounded-sm text-center focus:outline-none focus-visible:ring-2 focus-visible:ring-ring"
+                        };
+
+                        rsx! {
+                            li {
+                                key: "{index}",
+                                class: if is_vertical { "flex gap-3" } else if has_connector { "flex flex-1 items-start" } else { "flex items-start" },
+                                aria_current: is_current.then_some("step"),
+
+                                if is_vertical {
+                                    // The indicator column carries the connector down to the next step
+                                    div {
+                                        class: "flex flex-col items-center",
+                                        if can_select {
+                                            button {
+                                                type: "button",
+                                                class: "rounded-full focus:outline-none focus-visible:ring-2 focus-visible:ring-ring",
+                                                aria_labelledby: title_id.clone(),
+                                                onclick: move |_| select_step(index),
+                                                {indicator}
+                                            }
+                                        } else {
+                                            {indicator}
+                                        }
+                                        if has_connector {
+                                            div { class: connector_classes, aria_hidden: "true" }
+                                        }
+                                    }
+                                    div {
+                                        class: "flex flex-1 flex-col gap-4 pb-6 pt-1",
+                                        {label}
+                                        if is_current {
+                                            div {
+                                                id: panel_id.clone(),
+                                                role: "group",
+                                                aria_labelledby: title_id.clone(),
+                                                {step.content.clone()}
+                                            }
+                                            {controls.clone()}
+                                        }
+                                    }
+                                } else {
+                                    if can_select {
+                                        button {
+                                            type: "button",
+                                            class: header_classes,
+                                            onclick: move |_| select_step(index),
+                                            {indicator}
+                                            {label}
+                                        }
+                                    } else {
+                                        div {
+                                            class: header_classes,
+                                            {indicator}
+                                            {label}
+                                        }
+                                    }
+                                    if has_connector {
+                                        div { class: connector_classes, aria_hidden: "true" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if !is_vertical {
+                if let Some(step) = current_step.clone() {
+                    div {
+                        id: panel_id.clone(),
+                        role: "group",
+                        aria_labelledby: format!("{}-step-{}", id, current),
+                        {step.content}
+                    }
+                }
+                {controls.clone()}
+            }
+
+            if blocked().is_some() {
+                p {
+                    id: error_id.clone(),
+                    class: "text-destructive text-xs flex items-center mt-2",
+                    role: "alert",
+                    CircleAlert { size: 14, class: "mr-1 text-destructive" }
+                    {props.error_label.clone()}
+                }
+            }
+
+            // Announce the current step to screen readers as it changes
+            div {
+                class: "sr-only",
+                aria_live: "polite",
+                aria_atomic: "true",
+                "{announcement}"
+            }
+        }
+    }
+}
@@ -1,6 +1,13 @@
+use crate::components::form::use_form_control;
+use crate::components::label::Label;
 use crate::{use_id_or, use_unique_id};
 use dioxus_lib::prelude::*;
 use dioxus_primitives::switch::{Switch as PrimitiveSwitch, SwitchThumb};
+use lucide_dioxus::LoaderCircle;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
 
 /// Switch size options
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
@@ -16,6 +23,39 @@ impl Default for SwitchSize {
     }
 }
 
+type SwitchActionFn = dyn Fn(bool) -> Pin<Box<dyn Future<Output = ()>>>;
+
+/// Async work run when a switch is toggled, e.g. saving a feature flag to the backend
+#[derive(Clone)]
+pub struct SwitchAction(Rc<SwitchActionFn>);
+
+impl SwitchAction {
+    pub fn new<F, Fut>(action: F) -> Self
+    where
+        F: Fn(bool) -> Fut + 'static,
+        Fut: Future<Output = ()> + 'static,
+    {
+        Self(Rc::new(move |checked| Box::pin(action(checked))))
+    }
+
+    /// Run the action for the new checked state
+    pub async fn run(&self, checked: bool) {
+        (self.0)(checked).await
+    }
+}
+
+impl PartialEq for SwitchAction {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for SwitchAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SwitchAction")
+    }
+}
+
 /// Props for the Switch component
 #[derive(Props, Clone, PartialEq)]
 pub struct SwitchProps {
@@ -27,6 +67,35 @@ pub struct SwitchProps {
     #[props(default)]
     pub on_checked_change: Option<EventHandler<bool>>,
 
+    /// Async callback for when the switch is toggled. The switch shows a spinner
+    /// in its thumb and ignores input until the returned future completes.
+    #[props(default)]
+    pub on_checked_change_async: Option<SwitchAction>,
+
+    /// Whether the switch is in a loading state
+    #[props(default)]
+    pub loading: ReadOnlySignal<bool>,
+
+    /// Content shown inside the track while the switch is on, e.g. text or an icon
+    #[props(default)]
+    pub on_label: Option<Element>,
+
+    /// Content shown inside the track while the switch is off
+    #[props(default)]
+    pub off_label: Option<Element>,
+
+    /// Text of a clickable label rendered next to the switch
+    #[props(default)]
+    pub label: Option<String>,
+
+    /// Name attribute for form submission
+    #[props(default)]
+    pub name: Option<String>,
+
+    /// Value submitted with the form while the switch is on
+    #[props(default = String::from("on"))]
+    pub value: String,
+
     /// Whether the switch is disabled
     #[props(default)]
     pub disabled: ReadOnlySignal<bool>,
@@ -47,13 +116,34 @@ pub struct SwitchProps {
     pub attributes: Vec<Attribute>,
 }
 
-/// A styled switch component that can be toggled on or off
+/// A styled switch component that can be toggled on or off.
+/// It can show content inside its track, a pending spinner and a clickable label.
 #[component]
 pub fn Switch(props: SwitchProps) -> Element {
     // Generate unique ID if not provided
     let switch_id = use_unique_id();
     let id_value = use_id_or(switch_id, props.id);
 
+    // Take the id and ARIA state from the surrounding FormControl, if any
+    let control = use_form_control();
+    let switch_dom_id = match control {
+        Some(control) if (props.id)().is_none() => control.control_id(),
+        _ => id_value(),
+    };
+    let is_invalid = control.is_some_and(|control| control.is_invalid());
+    let name = props
+        .name
+        .clone()
+        .or_else(|| control.and_then(|control| control.name()));
+    let label_id = format!("{}-label", switch_dom_id);
+
+    // Pending while an async on_checked_change runs
+    let mut pending = use_signal(|| false);
+    let is_loading = (props.loading)() || pending();
+    let disabled = (props.disabled)() || is_loading;
+    let mut checked = props.checked;
+    let is_checked = checked();
+
     // Determine size-specific classes
     let (switch_classes, thumb_size_classes, thumb_translate_x_off, thumb_translate_x_on) =
         match props.size {
@@ -84,7 +174,7 @@ pub fn Switch(props: SwitchProps) -> Element {
         "transition-colors duration-300 ease-in-out focus:outline-none focus:ring-2",
         "focus:ring-ring focus:ring-offset-2 focus:ring-offset-background",
         // Background colors based on state
-        if (props.checked)() { "bg-primary" } else { "bg-input" },
+        if is_checked { "bg-primary" } else { "bg-input" },
         // Size classes
         switch_classes,
     ]
@@ -104,32 +194,128 @@ pub fn Switch(props: SwitchProps) -> Element {
             // Size classes
             thumb_size_classes,
             // Position classes based on checked state
-            if (props.checked)() { thumb_translate_x_on } else { thumb_translate_x_off },
+            if is_checked { thumb_translate_x_on } else { thumb_translate_x_off },
         ]
         .join(" ")
         };
 
+    // The spinner sits on top of the thumb and follows its position
+    let spinner_classes = vec![
+        "pointer-events-none absolute left-0 top-0 flex items-center justify-center",
+        "transition-transform duration-300 ease-in-out",
+        thumb_size_classes,
+        if is_checked {
+            thumb_translate_x_on
+        } else {
+            thumb_translate_x_off
+        },
+    ]
+    .join(" ");
+
     // Handler for change events
-    let on_change = move |checked: bool| {
+    let on_change = move |new_state: bool| {
+        if pending() {
+            return;
+        }
+        checked.set(new_state);
         if let Some(handler) = &props.on_checked_change {
-            handler.call(checked);
+            handler.call(new_state);
+        }
+        if let Some(action) = props.on_checked_change_async.clone() {
+            pending.set(true);
+            spawn(async move {
+                action.run(new_state).await;
+                pending.set(false);
+            });
         }
     };
 
-    rsx! {
+    // A visible label takes precedence over the label of a surrounding FormField
+    let aria_labelledby = if props.label.is_some() {
+        Some(label_id.clone())
+    } else {
+        control
+            .filter(|_| props.aria_label.is_none())
+            .map(|control| control.label_id())
+    };
+
+    let switch = rsx! {
         PrimitiveSwitch {
-            id: id_value,
+            id: switch_dom_id.clone(),
             class: full_switch_classes,
             checked: props.checked,
             on_checked_change: on_change,
-            disabled: (props.disabled)(),
+            disabled: disabled,
             aria_label: props.aria_label.clone(),
+            aria_labelledby: aria_labelledby,
+            aria_describedby: control.and_then(|control| control.describedby()),
+            aria_invalid: is_invalid.then_some("true"),
+            aria_required: control.is_some_and(|control| control.is_required()).then_some("true"),
+            aria_busy: is_loading.then_some("true"),
+
+            // On and off content sits in the half of the track the thumb leaves free
+            if is_checked {
+                if let Some(on_label) = &props.on_label {
+                    span {
+                        class: "pointer-events-none absolute inset-y-0 left-0 flex w-1/2 items-center justify-center text-[0.625rem] font-medium text-primary-foreground",
+                        aria_hidden: "true",
+                        {on_label.clone()}
+                    }
+                }
+            } else if let Some(off_label) = &props.off_label {
+                span {
+                    class: "pointer-events-none absolute inset-y-0 right-0 flex w-1/2 items-center justify-center text-[0.625rem] font-medium text-muted-foreground",
+                    aria_hidden: "true",
+                    {off_label.clone()}
+                }
+            }
 
             SwitchThumb {
                 class: full_thumb_classes(),
                 // Add ARIA attributes for better accessibility
                 aria_hidden: "true".to_string(),
             }
+
+            if is_loading {
+                span {
+                    class: spinner_classes,
+                    aria_hidden: "true",
+                    LoaderCircle {
+                        class: "h-3 w-3 animate-spin text-muted-foreground",
+                    }
+                }
+            }
         }
+
+        // Hidden input so the switch takes part in native form submission
+        if let Some(name) = name {
+            input {
+                type: "checkbox",
+                name: name,
+                value: props.value.clone(),
+                checked: is_checked,
+                disabled: (props.disabled)(),
+                tabindex: "-1",
+                aria_hidden: "true",
+                class: "sr-only",
+                onclick: move |event: MouseEvent| event.prevent_default(),
+            }
+        }
+    };
+
+    match props.label.clone() {
+        Some(label) => rsx! {
+            div { class: "inline-flex items-center gap-2",
+                {switch}
+                Label {
+                    id: Some(label_id),
+                    for_id: Some(switch_dom_id),
+                    class: "mb-0",
+                    disabled: (props.disabled)(),
+                    "{label}"
+                }
+            }
+        },
+        None => switch,
     }
 }
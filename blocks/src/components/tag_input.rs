@@ -0,0 +1,560 @@
+use crate::components::form::{FieldValue, UseForm, use_form_control};
+use crate::components::input::{InputSize, InputVariant};
+use crate::use_unique_id;
+use dioxus_lib::prelude::*;
+use lucide_dioxus::X;
+use std::rc::Rc;
+
+// Split text on the delimiters, new lines and tabs
+fn split_tags(text: &str, delimiters: &[char]) -> Vec<String> {
+    text.split(|c: char| delimiters.contains(&c) || c == '\n' || c == '\t')
+        .map(str::to_string)
+        .collect()
+}
+
+// Add candidate tags to the current ones, returning the new tags and the candidates that were
+// rejected for being over the limit or failing `is_valid`. Empty candidates and duplicates are
+// dropped silently.
+fn merge_tags(
+    current: &[String],
+    candidates: Vec<String>,
+    max_tags: Option<usize>,
+    allow_duplicates: bool,
+    is_valid: impl Fn(&str) -> bool,
+) -> (Vec<String>, Vec<String>) {
+    let mut next = current.to_vec();
+    let mut rejected = Vec::new();
+    for candidate in candidates {
+        let tag = candidate.trim().to_string();
+        if tag.is_empty() {
+            continue;
+        }
+        let is_duplicate = next
+            .iter()
+            .any(|existing| existing.to_lowercase() == tag.to_lowercase());
+        if is_duplicate && !allow_duplicates {
+            continue;
+        }
+        let is_full = max_tags.is_some_and(|max| next.len() >= max);
+        if is_full || !is_valid(&tag) {
+            rejected.push(tag);
+            continue;
+        }
+        next.push(tag);
+    }
+    (next, rejected)
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct TagInputProps {
+    /// The current tags. The input updates this signal when tags are added or removed.
+    /// Leave unset to let the input manage its own tags.
+    #[props(default)]
+    value: Option<Signal<Vec<String>>>,
+
+    /// Initial tags when `value` is not provided
+    #[props(default)]
+    default_value: Vec<String>,
+
+    /// Callback with every tag when the tags change
+    #[props(default)]
+    on_value_change: Option<EventHandler<Vec<String>>>,
+
+    /// Callback with text that was not added, because it failed `validate` or the limit was reached
+    #[props(default)]
+    on_reject: Option<EventHandler<String>>,
+
+    /// Characters that complete a tag besides Enter. Pasted text is split on them too.
+    #[props(default = vec![','])]
+    delimiters: Vec<char>,
+
+    /// Maximum number of tags
+    #[props(default)]
+    max_tags: Option<usize>,
+
+    /// Whether the same tag may be added more than once. Duplicates are compared case-insensitively.
+    #[props(default)]
+    allow_duplicates: bool,
+
+    /// Predicate every new tag must pass, e.g. an email check for recipients
+    #[props(default)]
+    validate: Option<Callback<String, bool>>,
+
+    /// Suggestions offered while typing, filtered by the current text
+    #[props(default)]
+    suggestions: Vec<String>,
+
+    /// The variant of the input
+    #[props(default)]
+    variant: InputVariant,
+
+    /// The size of the input
+    #[props(default)]
+    size: InputSize,
+
+    /// Whether the input is disabled
+    #[props(default)]
+    disabled: bool,
+
+    /// Placeholder text shown while there are no tags
+    #[props(default)]
+    placeholder: String,
+
+    /// Whether the input is displayed as a full width block
+    #[props(default)]
+    full_width: bool,
+
+    /// Name for form submission. Every tag is submitted under this name.
+    #[props(default)]
+    name: String,
+
+    /// Optional ID for the text input
+    #[props(default)]
+    id: Option<String>,
+
+    /// Optional aria-label for the input (for accessibility)
+    #[props(default)]
+    aria_label: Option<String>,
+
+    /// Optional additional classes for the input
+    #[props(default)]
+    class: Option<String>,
+
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+/// An input that turns typed text into removable tags
+#[component]
+pub fn TagInput(props: TagInputProps) -> Element {
+    // Take the id, name and ARIA state from the surrounding FormControl, if any
+    let control = use_form_control();
+
+    // Generate unique ID if not provided
+    let input_id = use_unique_id();
+    let id = props
+        .id
+        .clone()
+        .or_else(|| control.map(|control| control.control_id()))
+        .unwrap_or_else(|| input_id());
+    let listbox_id = format!("{}-suggestions", id);
+
+    let name = if props.name.is_empty() {
+        control
+            .and_then(|control| control.name())
+            .unwrap_or_default()
+    } else {
+        props.name.clone()
+    };
+
+    // Bind to the surrounding Form when a field with this name is registered
+    let form =
+        try_use_context::<UseForm>().filter(|form| !name.is_empty() && form.is_registered(&name));
+    let field_error = form.and_then(|form| form.error(&name));
+
+    // Use the provided signal when controlled, otherwise keep the tags internally
+    let internal_value = use_signal(|| props.default_value.clone());
+    let mut value = props.value.unwrap_or(internal_value);
+    let tags = match form {
+        Some(form) => form.get::<Vec<String>>(&name).unwrap_or_default(),
+        None => value(),
+    };
+
+    let mut draft = use_signal(String::new);
+    let mut rejected = use_signal(|| false);
+    let mut focused = use_signal(|| false);
+    let mut highlighted = use_signal(|| None::<usize>);
+    let mut input_ref: Signal<Option<Rc<MountedData>>> = use_signal(|| None);
+
+    // Store new tags and notify listeners
+    let field_name = name.clone();
+    let on_value_change = props.on_value_change;
+    let set_tags = use_callback(move |next: Vec<String>| {
+        if let Some(form) = form {
+            form.set_value(&field_name, FieldValue::List(next.clone()));
+        }
+        value.set(next.clone());
+        if let Some(handler) = &on_value_change {
+            handler.call(next);
+        }
+    });
+
+    // Add tags from raw text, returning the text that could not be added
+    let current_tags = tags.clone();
+    let max_tags = props.max_tags;
+    let allow_duplicates = props.allow_duplicates;
+    let validate = props.validate;
+    let on_reject = props.on_reject;
+    let add_tags = use_callback(move |candidates: Vec<String>| -> Vec<String> {
+        let (next, remaining) = merge_tags(
+            &current_tags,
+            candidates,
+            max_tags,
+            allow_duplicates,
+            |tag| validate.is_none_or(|validate| validate.call(tag.to_string())),
+        );
+        if let Some(handler) = &on_reject {
+            for tag in &remaining {
+                handler.call(tag.clone());
+            }
+        }
+        if next != current_tags {
+            set_tags.call(next);
+        }
+        remaining
+    });
+
+    let delimiters = props.delimiters.clone();
+    let split = move |text: &str| split_tags(text, &delimiters);
+
+    // Commit the typed text, keeping whatever was rejected so it can be corrected
+    let mut commit_draft = move |text: String| {
+        let remaining = add_tags.call(vec![text]);
+        rejected.set(!remaining.is_empty());
+        draft.set(remaining.join(", "));
+        highlighted.set(None);
+    };
+
+    // Suggestions matching the current text that are not tags yet
+    let query = draft().trim().to_lowercase();
+    let matches = props
+        .suggestions
+        .iter()
+        .filter(|suggestion| {
+            !tags
+                .iter()
+                .any(|tag| tag.to_lowercase() == suggestion.to_lowercase())
+        })
+        .filter(|suggestion| suggestion.to_lowercase().contains(&query))
+        .take(8)
+        .cloned()
+        .collect::<Vec<_>>();
+    let show_suggestions = focused() && !matches.is_empty();
+    let active_suggestion = highlighted().and_then(|index| matches.get(index).cloned());
+
+    let key_matches = matches.clone();
+    let key_tags = tags.clone();
+    let key_delimiters = props.delimiters.clone();
+    let handle_key_down = move |event: KeyboardEvent| match event.key() {
+        Key::Enter => {
+            let text = highlighted()
+                .and_then(|index| key_matches.get(index).cloned())
+                .unwrap_or_else(|| draft());
+            // Only keep Enter from submitting the form when there is something to add
+            if !text.trim().is_empty() {
+                event.prevent_default();
+                commit_draft(text);
+            }
+        }
+        Key::Character(character)
+            if character
+                .chars()
+                .next()
+                .is_some_and(|c| key_delimiters.contains(&c)) =>
+        {
+            event.prevent_default();
+            commit_draft(draft());
+        }
+        Key::Backspace if draft().is_empty() && !key_tags.is_empty() => {
+            let mut next = key_tags.clone();
+            next.pop();
+            set_tags.call(next);
+        }
+        Key::ArrowDown if !key_matches.is_empty() => {
+            event.prevent_default();
+            let next = highlighted().map_or(0, |index| (index + 1) % key_matches.len());
+            highlighted.set(Some(next));
+        }
+        Key::ArrowUp if !key_matches.is_empty() => {
+            event.prevent_default();
+            let last = key_matches.len() - 1;
+            let next = highlighted().map_or(last, |index| index.checked_sub(1).unwrap_or(last));
+            highlighted.set(Some(next));
+        }
+        Key::Escape => highlighted.set(None),
+        _ => {}
+    };
+
+    // Text containing delimiters, e.g. from a paste, is split into tags
+    let handle_input = move |event: FormEvent| {
+        let text = event.value();
+        rejected.set(false);
+        highlighted.set(None);
+        let mut parts = split(&text);
+        if parts.len() > 1 {
+            let last = parts.pop().unwrap_or_default();
+            let mut remaining = add_tags.call(parts);
+            rejected.set(!remaining.is_empty());
+            remaining.push(last);
+            draft.set(remaining.join(", "));
+        } else {
+            draft.set(text);
+        }
+    };
+
+    let blur_name = name.clone();
+    let handle_blur = move |_| {
+        focused.set(false);
+        highlighted.set(None);
+        if let Some(form) = form {
+            form.touch(&blur_name);
+        }
+    };
+
+    let remove_tags = tags.clone();
+    let remove_tag = use_callback(move |index: usize| {
+        let mut next = remove_tags.clone();
+        if index < next.len() {
+            next.remove(index);
+            set_tags.call(next);
+        }
+    });
+
+    // Clicking the empty area of the field focuses the text input
+    let focus_input = move |_| async move {
+        if let Some(input) = input_ref() {
+            let _ = input.set_focus(true).await;
+        }
+    };
+
+    let is_invalid =
+        rejected() || field_error.is_some() || control.is_some_and(|control| control.is_invalid());
+    let variant = if is_invalid {
+        InputVariant::Error
+    } else {
+        props.variant
+    };
+    let aria_describedby = control
+        .and_then(|control| control.describedby())
+        .or_else(|| {
+            form.filter(|_| field_error.is_some())
+                .map(|form| form.error_id(&name))
+        });
+
+    // Determine variant classes
+    let variant_classes = match variant {
+        InputVariant::Default => "border-input focus-within:border-ring",
+        InputVariant::Error => "border-destructive focus-within:border-destructive",
+    };
+
+    // Determine size classes
+    let size_classes = match props.size {
+        InputSize::Small => "text-xs px-2 py-1 min-h-8 gap-1",
+        InputSize::Medium => "text-sm px-3 py-1.5 min-h-10 gap-1.5",
+        InputSize::Large => "text-base px-4 py-2 min-h-12 gap-2",
+    };
+
+    // Determine width class
+    let width_class = if props.full_width { "w-full" } else { "w-auto" };
+
+    // Determine state classes
+    let state_class = if props.disabled {
+        "opacity-50 cursor-not-allowed bg-muted"
+    } else {
+        "bg-background cursor-text"
+    };
+
+    // Generate all the classes
+    let field_classes = vec![
+        // Base classes
+        "flex flex-wrap items-center rounded border text-foreground",
+        "transition-colors focus-within:outline-none focus-within:ring-2 focus-within:ring-ring focus-within:ring-offset-2",
+        // Variant-specific classes
+        variant_classes,
+        // Size-specific classes
+        size_classes,
+        // Width class
+        width_class,
+        // State class
+        state_class,
+        // Additional classes passed by the user
+        props.class.as_deref().unwrap_or(""),
+    ]
+    .into_iter()
+    .filter(|s| !s.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ");
+
+    let is_full = props.max_tags.is_some_and(|max| tags.len() >= max);
+    let placeholder = if tags.is_empty() {
+        props.placeholder.clone()
+    } else {
+        String::new()
+    };
+    let active_descendant = highlighted()
+        .filter(|_| show_suggestions)
+        .map(|index| format!("{}-option-{}", id, index));
+
+    rsx! {
+        div {
+            class: "relative",
+
+            div {
+                class: field_classes,
+                onclick: focus_input,
+
+                for (index, tag) in tags.iter().cloned().enumerate() {
+                    span {
+                        key: "{index}-{tag}",
+                        class: "inline-flex items-center gap-1 rounded bg-secondary text-secondary-foreground px-2 py-0.5 text-xs font-medium",
+                        "{tag}"
+                        if !props.disabled {
+                            button {
+                                type: "button",
+                                class: "rounded-sm opacity-70 hover:opacity-100 focus:outline-none focus-visible:ring-2 focus-visible:ring-ring",
+                                aria_label: format!("Remove {}", tag),
+                                // Return focus to the text input, since the button goes away
+                                onclick: move |event: MouseEvent| async move {
+                                    event.stop_propagation();
+                                    remove_tag.call(index);
+                                    if let Some(input) = input_ref() {
+                                        let _ = input.set_focus(true).await;
+                                    }
+                                },
+                                X { size: 12 }
+                            }
+                        }
+                    }
+                }
+
+                input {
+                    id: id.clone(),
+                    type: "text",
+                    class: "min-w-[6rem] flex-1 bg-transparent outline-none placeholder:text-muted-foreground",
+                    placeholder: placeholder,
+                    value: draft(),
+                    disabled: props.disabled,
+                    autocomplete: "off",
+                    onmounted: move |event: MountedEvent| input_ref.set(Some(event.data())),
+                    oninput: handle_input,
+                    onkeydown: handle_key_down,
+                    onfocus: move |_| focused.set(true),
+                    onblur: handle_blur,
+
+                    // ARIA attributes
+                    role: if !props.suggestions.is_empty() { "combobox" },
+                    aria_autocomplete: if !props.suggestions.is_empty() { "list" },
+                    aria_expanded: if !props.suggestions.is_empty() { show_suggestions.to_string() },
+                    aria_controls: show_suggestions.then(|| listbox_id.clone()),
+                    aria_activedescendant: active_descendant,
+                    aria_label: props.aria_label.clone(),
+                    aria_labelledby: control
+                        .filter(|_| props.aria_label.is_none())
+                        .map(|control| control.label_id()),
+                    aria_describedby: aria_describedby,
+                    aria_invalid: is_invalid.then_some("true"),
+                    aria_disabled: (props.disabled || is_full).to_string(),
+
+                    // Pass through other attributes
+                    ..props.attributes,
+                }
+            }
+
+            if show_suggestions {
+                ul {
+                    id: listbox_id.clone(),
+                    role: "listbox",
+                    class: "absolute z-50 mt-1 max-h-60 w-full overflow-auto rounded border border-border bg-background py-1 text-sm shadow-md",
+
+                    for (index, suggestion) in matches.iter().cloned().enumerate() {
+                        li {
+                            key: "{suggestion}",
+                            id: format!("{}-option-{}", id, index),
+                            role: "option",
+                            aria_selected: (active_suggestion.as_ref() == Some(&suggestion)).to_string(),
+                            class: if active_suggestion.as_ref() == Some(&suggestion) {
+                                "cursor-pointer px-3 py-1.5 bg-muted"
+                            } else {
+                                "cursor-pointer px-3 py-1.5 hover:bg-muted"
+                            },
+                            // Keep focus in the input while picking a suggestion
+                            onmousedown: move |event: MouseEvent| event.prevent_default(),
+                            onclick: {
+                                let suggestion = suggestion.clone();
+                                move |_| commit_draft(suggestion.clone())
+                            },
+                            "{suggestion}"
+                        }
+                    }
+                }
+            }
+
+            // Every tag is submitted under the field name
+            if !name.is_empty() {
+                for tag in tags.iter().cloned() {
+                    input { type: "hidden", name: name.clone(), value: tag }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tags(items: &[&str]) -> Vec<String> {
+        items.iter().map(|item| item.to_string()).collect()
+    }
+
+    fn merge(current: &[&str], candidates: &[&str]) -> (Vec<String>, Vec<String>) {
+        merge_tags(&tags(current), tags(candidates), None, false, |_| true)
+    }
+
+    #[test]
+    fn splits_on_delimiters_new_lines_and_tabs() {
+        assert_eq!(split_tags("a,b", &[',']), tags(&["a", "b"]));
+        assert_eq!(split_tags("a;b c", &[';', ' ']), tags(&["a", "b", "c"]));
+        assert_eq!(split_tags("a\nb\tc", &[]), tags(&["a", "b", "c"]));
+        // A trailing delimiter leaves an empty draft
+        assert_eq!(split_tags("a,", &[',']), tags(&["a", ""]));
+        assert_eq!(split_tags("a b", &[',']), tags(&["a b"]));
+    }
+
+    #[test]
+    fn trims_and_skips_empty_tags() {
+        assert_eq!(merge(&[], &[" rust ", "", "  "]), (tags(&["rust"]), vec![]));
+    }
+
+    #[test]
+    fn drops_duplicates_case_insensitively() {
+        assert_eq!(
+            merge(&["Rust"], &["rust", "web", "WEB"]),
+            (tags(&["Rust", "web"]), vec![])
+        );
+
+        let (next, rejected) = merge_tags(&tags(&["Rust"]), tags(&["rust"]), None, true, |_| true);
+        assert_eq!(next, tags(&["Rust", "rust"]));
+        assert!(rejected.is_empty());
+    }
+
+    #[test]
+    fn rejects_tags_over_the_limit() {
+        let (next, rejected) = merge_tags(
+            &tags(&["a"]),
+            tags(&["b", "c", "d"]),
+            Some(2),
+            false,
+            |_| true,
+        );
+        assert_eq!(next, tags(&["a", "b"]));
+        assert_eq!(rejected, tags(&["c", "d"]));
+
+        // Duplicates are dropped rather than rejected, even when full
+        let (_, rejected) = merge_tags(&tags(&["a"]), tags(&["a"]), Some(1), false, |_| true);
+        assert!(rejected.is_empty());
+    }
+
+    #[test]
+    fn rejects_invalid_tags() {
+        let (next, rejected) = merge_tags(
+            &[],
+            tags(&["ada@example.com", "not an email"]),
+            None,
+            false,
+            |tag| tag.contains('@'),
+        );
+        assert_eq!(next, tags(&["ada@example.com"]));
+        assert_eq!(rejected, tags(&["not an email"]));
+    }
+}
@@ -0,0 +1,690 @@
+use crate::components::avatar::{Avatar, AvatarFallback, AvatarImage};
+use crate::use_unique_id;
+use dioxus_lib::prelude::*;
+
+/// Layout of a Timeline
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TimelineOrientation {
+    /// Items in a column with connectors running down
+    Vertical,
+    /// Items in a scrollable row with connectors running across
+    Horizontal,
+}
+
+impl Default for TimelineOrientation {
+    fn default() -> Self {
+        Self::Vertical
+    }
+}
+
+/// Which side of the markers the item content is placed on
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TimelineAlign {
+    /// After the markers: right of them, or below them in a horizontal timeline
+    Start,
+    /// Before the markers: left of them, or above them in a horizontal timeline
+    End,
+    /// Alternating sides, with each timestamp shown across from its content
+    Alternate,
+}
+
+impl Default for TimelineAlign {
+    fn default() -> Self {
+        Self::Start
+    }
+}
+
+/// Timeline marker color variants
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TimelineVariant {
+    Default,
+    Destructive,
+    Success,
+    Warning,
+}
+
+impl Default for TimelineVariant {
+    fn default() -> Self {
+        Self::Default
+    }
+}
+
+/// What marks an item on the line
+#[derive(Clone, PartialEq)]
+pub enum TimelineMarker {
+    /// A small dot
+    Dot,
+    /// An icon in a circle
+    Icon(Element),
+    /// An avatar with the image, or the initials of the name while it loads or if it fails
+    Avatar { src: Option<String>, name: String },
+}
+
+impl Default for TimelineMarker {
+    fn default() -> Self {
+        Self::Dot
+    }
+}
+
+/// An event on a Timeline
+#[derive(Clone, PartialEq)]
+pub struct TimelineItem {
+    title: String,
+    description: Option<String>,
+    timestamp: Option<String>,
+    datetime: Option<String>,
+    marker: TimelineMarker,
+    variant: TimelineVariant,
+    content: Element,
+}
+
+impl TimelineItem {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            description: None,
+            timestamp: None,
+            datetime: None,
+            marker: TimelineMarker::Dot,
+            variant: TimelineVariant::Default,
+            content: VNode::empty(),
+        }
+    }
+
+    /// Supporting text shown under the title
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// When the event happened, as shown to the user, e.g. "2 hours ago"
+    pub fn timestamp(mut self, timestamp: impl Into<String>) -> Self {
+        self.timestamp = Some(timestamp.into());
+        self
+    }
+
+    /// Machine-readable date of the event for the `time` element, e.g. "2025-03-02T14:30"
+    pub fn datetime(mut self, datetime: impl Into<String>) -> Self {
+        self.datetime = Some(datetime.into());
+        self
+    }
+
+    /// Mark the item with an icon
+    pub fn icon(mut self, icon: Element) -> Self {
+        self.marker = TimelineMarker::Icon(icon);
+        self
+    }
+
+    /// Mark the item with an avatar. Pass no image to show the initials of the name.
+    pub fn avatar(mut self, src: Option<String>, name: impl Into<String>) -> Self {
+        self.marker = TimelineMarker::Avatar {
+            src,
+            name: name.into(),
+        };
+        self
+    }
+
+    /// Color of the marker
+    pub fn variant(mut self, variant: TimelineVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Extra content shown under the title and description
+    pub fn content(mut self, content: Element) -> Self {
+        self.content = content;
+        self
+    }
+}
+
+impl std::fmt::Debug for TimelineItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TimelineItem")
+            .field("title", &self.title)
+            .field("description", &self.description)
+            .field("timestamp", &self.timestamp)
+            .field("variant", &self.variant)
+            .finish()
+    }
+}
+
+// Up to two initials of a name, e.g. "Ada Lovelace" gives "AL"
+fn initials(name: &str) -> String {
+    name.split_whitespace()
+        .filter_map(|word| word.chars().next())
+        .take(2)
+        .flat_map(char::to_uppercase)
+        .collect()
+}
+
+/// Props for the Timeline component
+#[derive(Props, Clone, PartialEq)]
+pub struct TimelineProps {
+    /// The events, in the order they are shown
+    pub items: Vec<TimelineItem>,
+
+    /// Whether the timeline runs down or across
+    #[props(default)]
+    pub orientation: TimelineOrientation,
+
+    /// Which side of the markers the content is placed on
+    #[props(default)]
+    pub align: TimelineAlign,
+
+    /// Optional ID for the timeline
+    #[props(default)]
+    pub id: Option<String>,
+
+    /// Accessible label for the timeline
+    #[props(default)]
+    pub aria_label: Option<String>,
+
+    /// Optional additional classes for the list
+    #[props(default)]
+    pub class: Option<String>,
+
+    #[props(extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+}
+
+/// A list of events joined by a line, with dot, icon or avatar markers
+#[component]
+pub fn Timeline(props: TimelineProps) -> Element {
+    // Generate unique ID if not provided
+    let generated_id = use_unique_id();
+    let timeline_id = props.id.clone().unwrap_or_else(|| generated_id());
+
+    let is_vertical = props.orientation == TimelineOrientation::Vertical;
+    let count = props.items.len();
+
+    let list_classes = vec![
+        if is_vertical {
+            "flex flex-col"
+        } else {
+            "flex overflow-x-auto pb-2"
+        },
+        props.class.as_deref().unwrap_or(""),
+    ]
+    .into_iter()
+    .filter(|s| !s.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ");
+
+    rsx! {
+        ol {
+            id: timeline_id.clone(),
+            class: list_classes,
+            aria_label: props.aria_label.clone(),
+            ..props.attributes,
+
+            for (index, item) in props.items.iter().cloned().enumerate() {
+                {
+                    let is_last = index + 1 == count;
+                    // Alternating timelines put odd items before the markers
+                    let content_first = match props.align {
+                        TimelineAlign::Start => false,
+                        TimelineAlign::End => true,
+                        TimelineAlign::Alternate => index % 2 == 1,
+                    };
+                    let is_alternate = props.align == TimelineAlign::Alternate;
+
+                    let marker_classes = match item.variant {
+                        TimelineVariant::Default => "border-primary text-primary",
+                        TimelineVariant::Destructive => "border-destructive text-destructive",
+                        TimelineVariant::Success => "border-green-500 text-green-600 dark:text-green-400",
+                        TimelineVariant::Warning => "border-yellow-500 text-yellow-600 dark:text-yellow-400",
+                    };
+                    let dot_classes = match item.variant {
+                        TimelineVariant::Default => "bg-primary",
+                        TimelineVariant::Destructive => "bg-destructive",
+                        TimelineVariant::Success => "bg-green-500",
+                        TimelineVariant::Warning => "bg-yellow-500",
+                    };
+
+                    let marker = match item.marker.clone() {
+                        TimelineMarker::Dot => rsx! {
+                            span { class: "flex h-8 w-8 items-center justify-center",
+                                span { class: "h-2.5 w-2.5 rounded-full ring-4 ring-background {dot_classes}" }
+                            }
+                        },
+                        TimelineMarker::Icon(icon) => rsx! {
+                            span {
+                                class: "flex h-8 w-8 items-center justify-center rounded-full border-2 bg-background [&_svg]:h-4 [&_svg]:w-4 {marker_classes}",
+                                {icon}
+                            }
+                        },
+                        TimelineMarker::Avatar { src, name } => {
+                            let fallback = initials(&name);
+                            rsx! {
+                                Avatar { class: "h-8 w-8",
+                                    if let Some(src) = src {
+                                        AvatarImage { src: src, alt: name.clone() }
+                                    }
+                                    AvatarFallback { class: "text-xs", "{fallback}" }
+                                }
+                            }
+                        }
+                    };
+
+                    let connector_classes = if is_vertical {
+                        "my-1 w-px flex-1 min-h-4 bg-border"
+                    } else {
+                        "mx-2 h-px flex-1 min-w-8 bg-border"
+                    };
+                    let marker_track = rsx! {
+                        div {
+                            class: if is_vertical { "flex flex-col items-center" } else { "flex w-full items-center" },
+                            aria_hidden: "true",
+                            {marker}
+                            if !is_last {
+                                span { class: connector_classes }
+                            }
+                        }
+                    };
+
+                    let time = item.timestamp.clone().map(|timestamp| {
+                        let datetime = item.datetime.clone();
+                        rsx! {
+                            time { class: "text-xs text-muted-foreground", datetime: datetime, "{timestamp}" }
+                        }
+                    });
+                    let title = item.title.clone();
+                    let description = item.description.clone();
+                    // Alternating timelines show the timestamp across from the content
+                    let body = rsx! {
+                        if !title.is_empty() || (!is_alternate && time.is_some()) {
+                            div {
+                                class: if content_first && is_vertical { "flex flex-row-reverse flex-wrap items-baseline gap-x-2" } else { "flex flex-wrap items-baseline gap-x-2" },
+                                if !title.is_empty() {
+                                    p { class: "text-sm font-medium text-foreground", "{title}" }
+                                }
+                                if !is_alternate {
+                                    {time.clone()}
+                                }
+                            }
+                        }
+                        if let Some(description) = description {
+                            p { class: "mt-0.5 text-sm text-muted-foreground", "{description}" }
+                        }
+                        {item.content.clone()}
+                    };
+                    let opposite = if is_alternate { time } else { None };
+
+                    if is_vertical {
+                        let content_classes = vec![
+                            "min-w-0 pt-1.5",
+                            if is_last { "" } else { "pb-6" },
+                            if content_first { "text-right" } else { "" },
+                        ]
+                        .into_iter()
+                        .filter(|s| !s.is_empty())
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                        let opposite_classes = if content_first { "pt-2" } else { "pt-2 text-right" };
+
+                        if is_alternate {
+                            let content = rsx! { div { class: content_classes, {body} } };
+                            let opposite = rsx! { div { class: opposite_classes, {opposite} } };
+                            let (left, right) = if content_first {
+                                (content, opposite)
+                            } else {
+                                (opposite, content)
+                            };
+                            rsx! {
+                                li { key: "{index}", class: "grid grid-cols-[1fr_auto_1fr] gap-x-4",
+                                    {left}
+                                    {marker_track}
+                                    {right}
+                                }
+                            }
+                        } else {
+                            rsx! {
+                                li {
+                                    key: "{index}",
+                                    class: if content_first { "flex flex-row-reverse gap-3" } else { "flex gap-3" },
+                                    {marker_track}
+                                    div { class: "flex-1 {content_classes}", {body} }
+                                }
+                            }
+                        }
+                    } else {
+                        // Three rows keep the markers of every item on one line
+                        let content_classes = if content_first {
+                            "row-start-1 self-end pb-3 pr-4"
+                        } else {
+                            "row-start-3 pt-3 pr-4"
+                        };
+                        let opposite_classes = if content_first {
+                            "row-start-3 pt-3 pr-4"
+                        } else {
+                            "row-start-1 self-end pb-3 pr-4"
+                        };
+                        let rows = match props.align {
+                            TimelineAlign::Start => "grid-rows-[0_auto_1fr]",
+                            TimelineAlign::End => "grid-rows-[1fr_auto_0]",
+                            TimelineAlign::Alternate => "grid-rows-[1fr_auto_1fr]",
+                        };
+
+                        rsx! {
+                            li { key: "{index}", class: "grid min-w-44 flex-1 {rows}",
+                                div { class: content_classes, {body} }
+                                div { class: "row-start-2", {marker_track} }
+                                if opposite.is_some() {
+                                    div { class: opposite_classes, {opposite} }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// An entry of an ActivityFeed: who did what, and when
+#[derive(Clone, PartialEq)]
+pub struct Activity {
+    actor: String,
+    avatar: Option<String>,
+    action: String,
+    target: Option<String>,
+    date: String,
+    time: Option<String>,
+    content: Element,
+}
+
+impl Activity {
+    /// An activity on a day, given as "YYYY-MM-DD". Consecutive activities on the same day are
+    /// grouped under one heading.
+    pub fn new(
+        actor: impl Into<String>,
+        action: impl Into<String>,
+        date: impl Into<String>,
+    ) -> Self {
+        Self {
+            actor: actor.into(),
+            avatar: None,
+            action: action.into(),
+            target: None,
+            date: date.into(),
+            time: None,
+            content: VNode::empty(),
+        }
+    }
+
+    /// Image of the actor. Their initials are shown without one.
+    pub fn avatar(mut self, src: impl Into<String>) -> Self {
+        self.avatar = Some(src.into());
+        self
+    }
+
+    /// What the action was done to, shown in bold after it
+    pub fn target(mut self, target: impl Into<String>) -> Self {
+        self.target = Some(target.into());
+        self
+    }
+
+    /// Time of day, as shown to the user, e.g. "14:30"
+    pub fn time(mut self, time: impl Into<String>) -> Self {
+        self.time = Some(time.into());
+        self
+    }
+
+    /// Extra content such as a comment or a diff summary
+    pub fn content(mut self, content: Element) -> Self {
+        self.content = content;
+        self
+    }
+}
+
+impl std::fmt::Debug for Activity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Activity")
+            .field("actor", &self.actor)
+            .field("action", &self.action)
+            .field("target", &self.target)
+            .field("date", &self.date)
+            .field("time", &self.time)
+            .finish()
+    }
+}
+
+// The year, month and day of a "YYYY-MM-DD" date, if it names a real day
+fn parse_day(date: &str) -> Option<(u32, usize, u32)> {
+    let mut parts = date.splitn(3, '-');
+    let (year, month, day) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(year), Some(month), Some(day)) if year.len() == 4 => (
+            year.parse::<u32>().ok()?,
+            month.parse::<usize>().ok()?,
+            day.get(..2).unwrap_or(day).parse::<u32>().ok()?,
+        ),
+        _ => return None,
+    };
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days = match month {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        1..=12 => 31,
+        _ => return None,
+    };
+    (1..=days).contains(&day).then_some((year, month, day))
+}
+
+// A machine-readable datetime for an activity. Times that are not "HH:MM" or "HH:MM:SS", such
+// as "2:30 PM", are left out rather than giving the `time` element an invalid value.
+fn activity_datetime(date: &str, time: &str) -> Option<String> {
+    parse_day(date)?;
+    let mut parts = time.split(':');
+    let hour = parts.next()?;
+    let minute = parts.next()?;
+    let second = parts.next();
+    let valid = |part: &str, max: u32| {
+        part.len() == 2
+            && part.bytes().all(|byte| byte.is_ascii_digit())
+            && part.parse::<u32>().is_ok_and(|value| value <= max)
+    };
+    let valid = valid(hour, 23)
+        && valid(minute, 59)
+        && second.is_none_or(|second| valid(second, 59))
+        && parts.next().is_none();
+    valid.then(|| format!("{}T{}", date, time))
+}
+
+/// Formats a "YYYY-MM-DD" date as e.g. "March 2, 2025", leaving other text and impossible dates
+/// such as "2025-02-31" as is
+///
+/// ```
+/// use lumen_blocks::components::timeline::format_day;
+///
+/// assert_eq!(format_day("2025-03-02"), "March 2, 2025");
+/// assert_eq!(format_day("Today"), "Today");
+/// assert_eq!(format_day("2025-02-31"), "2025-02-31");
+/// ```
+pub fn format_day(date: &str) -> String {
+    const MONTHS: [&str; 12] = [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ];
+
+    match parse_day(date) {
+        Some((year, month, day)) => format!("{} {}, {}", MONTHS[month - 1], day, year),
+        None => date.to_string(),
+    }
+}
+
+/// Props for the ActivityFeed component
+#[derive(Props, Clone, PartialEq)]
+pub struct ActivityFeedProps {
+    /// The activities, usually newest first
+    pub activities: Vec<Activity>,
+
+    /// Formats the day headings from the activity dates, e.g. to show "Today" and "Yesterday".
+    /// Defaults to `format_day`.
+    #[props(default)]
+    pub day_label: Option<Callback<String, String>>,
+
+    /// Text shown when there are no activities
+    #[props(default = String::from("No activity yet"))]
+    pub empty_text: String,
+
+    /// Optional ID for the feed
+    #[props(default)]
+    pub id: Option<String>,
+
+    /// Accessible label for the feed
+    #[props(default)]
+    pub aria_label: Option<String>,
+
+    /// Optional additional classes for the container
+    #[props(default)]
+    pub class: Option<String>,
+
+    #[props(extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+}
+
+/// A vertical Timeline of activities with avatar markers, grouped under a heading per day
+#[component]
+pub fn ActivityFeed(props: ActivityFeedProps) -> Element {
+    // Generate unique ID if not provided
+    let generated_id = use_unique_id();
+    let feed_id = props.id.clone().unwrap_or_else(|| generated_id());
+
+    // Group consecutive activities of the same day
+    let mut days: Vec<(String, Vec<Activity>)> = Vec::new();
+    for activity in &props.activities {
+        match days.last_mut() {
+            Some((date, group)) if *date == activity.date => group.push(activity.clone()),
+            _ => days.push((activity.date.clone(), vec![activity.clone()])),
+        }
+    }
+
+    let container_classes = vec!["flex flex-col gap-6", props.class.as_deref().unwrap_or("")]
+        .into_iter()
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let day_label = props.day_label;
+    let empty_text = props.empty_text.clone();
+
+    rsx! {
+        section {
+            id: feed_id.clone(),
+            class: container_classes,
+            aria_label: props.aria_label.clone(),
+            ..props.attributes,
+
+            if days.is_empty() {
+                p { class: "text-sm text-muted-foreground", "{empty_text}" }
+            }
+
+            for (index, (date, group)) in days.into_iter().enumerate() {
+                {
+                    let heading = match &day_label {
+                        Some(label) => label.call(date.clone()),
+                        None => format_day(&date),
+                    };
+                    let heading_id = format!("{}-day-{}", feed_id, index);
+                    let items = group
+                        .into_iter()
+                        .map(|activity| {
+                            let actor = activity.actor.clone();
+                            let action = activity.action.clone();
+                            let target = activity.target.clone();
+                            let mut item = TimelineItem::new("")
+                                .avatar(activity.avatar.clone(), activity.actor.clone())
+                                .content(rsx! {
+                                    p { class: "text-sm text-muted-foreground",
+                                        span { class: "font-medium text-foreground", "{actor}" }
+                                        " {action}"
+                                        if let Some(target) = target {
+                                            " "
+                                            span { class: "font-medium text-foreground", "{target}" }
+                                        }
+                                    }
+                                    {activity.content.clone()}
+                                });
+                            if let Some(time) = activity.time.clone() {
+                                if let Some(datetime) = activity_datetime(&activity.date, &time) {
+                                    item = item.datetime(datetime);
+                                }
+                                item = item.timestamp(time);
+                            }
+                            item
+                        })
+                        .collect::<Vec<_>>();
+
+                    rsx! {
+                        div { key: "{date}-{index}", class: "flex flex-col gap-3",
+                            h3 {
+                                id: heading_id.clone(),
+                                class: "sticky top-0 z-10 bg-background py-1 text-xs font-semibold uppercase tracking-wide text-muted-foreground",
+                                "{heading}"
+                            }
+                            Timeline {
+                                items: items,
+                                aria_labelledby: heading_id,
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_real_days() {
+        assert_eq!(format_day("2025-01-31"), "January 31, 2025");
+        assert_eq!(format_day("2025-12-01"), "December 1, 2025");
+        assert_eq!(format_day("2024-02-29"), "February 29, 2024");
+        assert_eq!(format_day("2000-02-29"), "February 29, 2000");
+    }
+
+    #[test]
+    fn leaves_impossible_days_as_is() {
+        assert_eq!(format_day("2025-02-31"), "2025-02-31");
+        assert_eq!(format_day("2025-02-29"), "2025-02-29");
+        assert_eq!(format_day("1900-02-29"), "1900-02-29");
+        assert_eq!(format_day("2025-04-31"), "2025-04-31");
+        assert_eq!(format_day("2025-13-01"), "2025-13-01");
+        assert_eq!(format_day("2025-01-00"), "2025-01-00");
+        assert_eq!(format_day("Yesterday"), "Yesterday");
+    }
+
+    #[test]
+    fn activity_datetimes_include_the_time_of_day() {
+        assert_eq!(
+            activity_datetime("2025-03-02", "14:30").as_deref(),
+            Some("2025-03-02T14:30")
+        );
+        assert_eq!(
+            activity_datetime("2025-03-02", "09:05:10").as_deref(),
+            Some("2025-03-02T09:05:10")
+        );
+    }
+
+    #[test]
+    fn activity_datetimes_skip_times_that_are_not_machine_readable() {
+        assert_eq!(activity_datetime("2025-03-02", "2:30 PM"), None);
+        assert_eq!(activity_datetime("2025-03-02", "24:00"), None);
+        assert_eq!(activity_datetime("2025-03-02", "14:30:00:00"), None);
+        assert_eq!(activity_datetime("Today", "14:30"), None);
+    }
+}
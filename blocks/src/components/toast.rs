@@ -1,7 +1,9 @@
-use crate::components::button::{Button, ButtonVariant};
+use crate::components::button::{Button, ButtonSize, ButtonVariant};
 use dioxus_lib::prelude::*;
-use dioxus_time::use_timeout;
-use lucide_dioxus::{Check, Info, TriangleAlert, X};
+use dioxus_time::sleep;
+use lucide_dioxus::{Check, Info, LoaderCircle, TriangleAlert, X};
+use std::fmt;
+use std::future::Future;
 use std::time::Duration;
 
 // Toast types for different visual styles
@@ -11,15 +13,17 @@ pub enum ToastType {
     Error,
     Warning,
     Info,
+    Loading,
 }
 
 impl ToastType {
-    fn icon_component(&self) -> Element {
+    pub(crate) fn icon_component(&self) -> Element {
         match self {
             ToastType::Success => rsx! { Check { class: "size-5" } },
             ToastType::Error => rsx! { X { class: "size-5" } },
             ToastType::Warning => rsx! { TriangleAlert { class: "size-5" } },
             ToastType::Info => rsx! { Info { class: "size-5" } },
+            ToastType::Loading => rsx! { LoaderCircle { class: "size-5 animate-spin" } },
         }
     }
 
@@ -27,31 +31,208 @@ impl ToastType {
         "border-border bg-popover text-foreground"
     }
 
-    fn icon_classes(&self) -> &'static str {
+    pub(crate) fn icon_classes(&self) -> &'static str {
         match self {
             ToastType::Success => "text-green-600 dark:text-green-400",
             ToastType::Error => "text-red-600 dark:text-red-400",
             ToastType::Warning => "text-yellow-600 dark:text-yellow-400",
             ToastType::Info => "text-foreground",
+            ToastType::Loading => "text-muted-foreground",
         }
     }
+
+    pub(crate) fn aria_label(&self) -> &'static str {
+        match self {
+            ToastType::Success => "Success:",
+            ToastType::Error => "Error:",
+            ToastType::Warning => "Warning:",
+            ToastType::Info => "Information:",
+            ToastType::Loading => "Loading:",
+        }
+    }
+}
+
+// Screen corner or edge where toasts are displayed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastPosition {
+    TopLeft,
+    TopCenter,
+    TopRight,
+    BottomLeft,
+    BottomCenter,
+    BottomRight,
+}
+
+impl Default for ToastPosition {
+    fn default() -> Self {
+        Self::TopRight
+    }
+}
+
+impl ToastPosition {
+    fn container_classes(&self) -> &'static str {
+        match self {
+            ToastPosition::TopLeft => "top-4 left-4 items-start",
+            ToastPosition::TopCenter => "top-4 left-1/2 -translate-x-1/2 items-center",
+            ToastPosition::TopRight => "top-4 right-4 items-end",
+            ToastPosition::BottomLeft => "bottom-4 left-4 items-start",
+            ToastPosition::BottomCenter => "bottom-4 left-1/2 -translate-x-1/2 items-center",
+            ToastPosition::BottomRight => "bottom-4 right-4 items-end",
+        }
+    }
+
+    fn is_top(&self) -> bool {
+        matches!(
+            self,
+            ToastPosition::TopLeft | ToastPosition::TopCenter | ToastPosition::TopRight
+        )
+    }
+
+    fn enter_animation(&self) -> &'static str {
+        match self {
+            ToastPosition::TopLeft | ToastPosition::BottomLeft => "animate-slide-in-from-left",
+            ToastPosition::TopRight | ToastPosition::BottomRight => "animate-slide-in-from-right",
+            ToastPosition::TopCenter => "animate-slide-in-from-top",
+            ToastPosition::BottomCenter => "animate-slide-in-from-bottom",
+        }
+    }
+
+    fn exit_animation(&self) -> &'static str {
+        match self {
+            ToastPosition::TopLeft | ToastPosition::BottomLeft => "animate-slide-out-to-left",
+            ToastPosition::TopRight | ToastPosition::BottomRight => "animate-slide-out-to-right",
+            ToastPosition::TopCenter => "animate-slide-out-to-top",
+            ToastPosition::BottomCenter => "animate-slide-out-to-bottom",
+        }
+    }
+
+    // Unit vector pointing towards the nearest screen edge, used for swipe-to-dismiss
+    fn swipe_direction(&self) -> (f64, f64) {
+        match self {
+            ToastPosition::TopLeft | ToastPosition::BottomLeft => (-1.0, 0.0),
+            ToastPosition::TopRight | ToastPosition::BottomRight => (1.0, 0.0),
+            ToastPosition::TopCenter => (0.0, -1.0),
+            ToastPosition::BottomCenter => (0.0, 1.0),
+        }
+    }
+
+    // Transform of a toast inside a stack, where offset 0 is the newest toast
+    fn stack_style(&self, offset: usize, count: usize, expanded: bool) -> String {
+        let direction = if self.is_top() { 1.0 } else { -1.0 };
+        let z_index = count - offset;
+
+        if expanded {
+            format!(
+                "transform: translateY(calc({direction} * {offset} * (100% + 0.5rem))); z-index: {z_index};"
+            )
+        } else {
+            let translate = direction * offset as f64 * STACK_PEEK_PX;
+            let scale = 1.0 - offset as f64 * 0.05;
+            let opacity = if offset < MAX_STACKED_VISIBLE { 1 } else { 0 };
+            format!(
+                "transform: translateY({translate}px) scale({scale}); z-index: {z_index}; opacity: {opacity};"
+            )
+        }
+    }
+}
+
+// How far each collapsed toast peeks out from under the one in front of it
+const STACK_PEEK_PX: f64 = 12.0;
+// Number of toasts visible when the stack is collapsed
+const MAX_STACKED_VISIBLE: usize = 3;
+// Distance a toast must be swiped towards the edge before it is dismissed
+const SWIPE_THRESHOLD_PX: f64 = 80.0;
+// Granularity of the auto-dismiss countdown, which stops while timers are paused
+const TIMER_TICK: Duration = Duration::from_millis(100);
+
+// Importance of a toast, deciding which toasts are evicted first when the queue is full
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ToastPriority {
+    Low,
+    Normal,
+    High,
+}
+
+impl Default for ToastPriority {
+    fn default() -> Self {
+        Self::Normal
+    }
+}
+
+// Maximum number of toasts of a single type that can be shown within a time window
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ToastRateLimit {
+    pub max: usize,
+    pub per: Duration,
+}
+
+// Identifier of a toast, returned when it is shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ToastId(usize);
+
+impl fmt::Display for ToastId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// An action button displayed inside a toast, e.g. "Undo" or "Retry"
+#[derive(Clone, PartialEq)]
+pub struct ToastAction {
+    pub label: String,
+    pub on_click: Callback<()>,
+}
+
+impl ToastAction {
+    pub fn new(label: String, on_click: impl FnMut(()) + 'static) -> Self {
+        Self {
+            label,
+            on_click: Callback::new(on_click),
+        }
+    }
+}
+
+impl fmt::Debug for ToastAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ToastAction")
+            .field("label", &self.label)
+            .finish_non_exhaustive()
+    }
 }
 
 // A single toast item
 #[derive(Debug, Clone, PartialEq)]
 pub struct ToastItem {
-    pub id: usize,
+    pub id: ToastId,
     pub title: String,
     pub description: Option<String>,
     pub toast_type: ToastType,
     pub duration: Option<Duration>,
     pub permanent: bool,
     pub visible: bool,
+    pub actions: Vec<ToastAction>,
+    pub dedupe_key: Option<String>,
+    pub priority: ToastPriority,
+    // Number of times this toast was shown, displayed as a badge when above one
+    pub count: usize,
+    // Bumped every time the toast is updated in place, restarting its timer
+    pub revision: usize,
 }
 
-// Global signal for toast management
-static TOASTS: GlobalSignal<Vec<ToastItem>> = Signal::global(|| Vec::new());
-static NEXT_ID: GlobalSignal<usize> = Signal::global(|| 0);
+// A dismissed toast kept in the notification history
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotificationEntry {
+    pub id: ToastId,
+    pub title: String,
+    pub description: Option<String>,
+    pub toast_type: ToastType,
+    pub count: usize,
+    pub read: bool,
+    // Milliseconds since the Unix epoch at which the toast was dismissed, filled in by the browser
+    pub timestamp: Option<f64>,
+    // Dismissal time formatted in the user's locale, e.g. "3:41 PM"
+    pub time_label: Option<String>,
+}
 
 // Toast provider props
 #[derive(Props, Clone, PartialEq)]
@@ -62,27 +243,128 @@ pub struct ToastProviderProps {
     #[props(default = 10)]
     pub max_toasts: usize,
 
+    /// Where on the screen toasts are displayed
+    #[props(default)]
+    pub position: ToastPosition,
+
+    /// Collapse toasts into a stack that expands on hover
+    #[props(default)]
+    pub stacked: bool,
+
+    /// Optional limit on how many toasts of each type can be shown within a time window.
+    /// Toasts over the limit are folded into the newest toast of the same type.
+    #[props(default)]
+    pub rate_limit: Option<ToastRateLimit>,
+
+    /// Keep dismissed toasts in a history that can be shown with a NotificationCenter
+    #[props(default)]
+    pub history: bool,
+
+    /// Maximum number of entries kept in the history
+    #[props(default = 50)]
+    pub max_history: usize,
+
     pub children: Element,
 }
 
 // Toast provider component
 #[component]
 pub fn ToastProvider(props: ToastProviderProps) -> Element {
+    // Each provider owns its own queue, so toasts never leak between windows or SSR requests
+    let toasts = use_context_provider(|| Toasts {
+        toasts: Signal::new(Vec::new()),
+        next_id: Signal::new(0),
+        max_toasts: Signal::new(props.max_toasts),
+        rate_limit: Signal::new(props.rate_limit),
+        recent: Signal::new(Vec::new()),
+        history: Signal::new(Vec::new()),
+        max_history: Signal::new(if props.history { props.max_history } else { 0 }),
+    });
+
+    let mut max_toasts = toasts.max_toasts;
+    let mut rate_limit = toasts.rate_limit;
+    let mut max_history = toasts.max_history;
+    use_effect(use_reactive(
+        (
+            &props.max_toasts,
+            &props.rate_limit,
+            &props.history,
+            &props.max_history,
+        ),
+        move |(max, limit, history, max_entries)| {
+            max_toasts.set(max);
+            rate_limit.set(limit);
+            max_history.set(if history { max_entries } else { 0 });
+        },
+    ));
+
+    let mut hovered = use_signal(|| false);
+    let mut window_focused = use_signal(|| true);
+
+    // Timers pause while the toasts are hovered or the window is in the background
+    let paused = use_memo(move || hovered() || !window_focused());
+
+    use_future(move || async move {
+        let mut eval = document::eval(
+            r#"
+            window.addEventListener("blur", () => dioxus.send(false));
+            window.addEventListener("focus", () => dioxus.send(true));
+            "#,
+        );
+        while let Ok(focused) = eval.recv::<bool>().await {
+            window_focused.set(focused);
+        }
+    });
+
+    let position = props.position;
+    let stacked = props.stacked;
+    let expanded = !stacked || hovered();
+
+    // Stacked toasts are laid on top of each other and positioned with transforms
+    let layout_classes = if stacked {
+        "w-[24rem] max-w-[calc(100vw-2rem)]"
+    } else {
+        "space-y-2 max-w-sm"
+    };
+    let wrapper_classes = if !stacked {
+        "w-full"
+    } else if position.is_top() {
+        "absolute inset-x-0 top-0 transition-all duration-300 ease-out"
+    } else {
+        "absolute inset-x-0 bottom-0 transition-all duration-300 ease-out"
+    };
+
+    let toasts = toasts.toasts.read();
+    let count = toasts.len();
+
     rsx! {
         // Render children
         {props.children}
 
         // Toast container - fixed position overlay
         div {
-            class: "fixed top-4 right-4 z-50 flex flex-col space-y-2 max-w-sm items-end pointer-events-none",
+            class: "fixed z-50 flex flex-col pointer-events-none {position.container_classes()} {layout_classes}",
             aria_live: "polite",
             aria_atomic: true,
+            onmouseenter: move |_| hovered.set(true),
+            onmouseleave: move |_| hovered.set(false),
 
-            for toast in TOASTS.read().iter() {
-                Toast {
+            for (index, toast) in toasts.iter().enumerate() {
+                div {
                     key: "{toast.id}",
-                    toast: toast.clone(),
-                    default_duration: props.default_duration,
+                    class: wrapper_classes,
+                    style: if stacked {
+                        Some(position.stack_style(count - 1 - index, count, expanded))
+                    } else {
+                        None
+                    },
+
+                    Toast {
+                        toast: toast.clone(),
+                        default_duration: props.default_duration,
+                        position,
+                        paused,
+                    }
                 }
             }
         }
@@ -94,6 +376,13 @@ pub fn ToastProvider(props: ToastProviderProps) -> Element {
 pub struct ToastProps {
     pub toast: ToastItem,
     pub default_duration: Duration,
+
+    #[props(default)]
+    pub position: ToastPosition,
+
+    /// Whether the auto-dismiss timer is paused
+    #[props(default)]
+    pub paused: ReadOnlySignal<bool>,
 }
 
 // Toast component
@@ -101,45 +390,102 @@ pub struct ToastProps {
 pub fn Toast(props: ToastProps) -> Element {
     let toast = props.toast.clone();
     let id = toast.id;
+    let toasts = use_toast();
     let mut visible = use_signal(|| true);
+    let is_visible = *visible.read() && toast.visible;
 
     // Handle removing toast from the list
-    let remove_toast = move || {
-        let mut toasts = TOASTS.write();
-        if let Some(pos) = toasts.iter().position(|t| t.id == id) {
-            toasts.remove(pos);
-        }
-    };
+    let remove_toast = move || toasts.remove(id);
 
     // Handle starting exit animation
     let start_exit = move |_| {
-        visible.with_mut(|val| *val = false);
+        visible.set(false);
     };
 
-    // Set up auto-dismiss timer if not permanent
-    if !toast.permanent {
-        let duration = toast.duration.unwrap_or(props.default_duration);
-        let mut visible_state = visible.clone();
-
-        // Simple timeout effect
-        use_effect(move || {
-            let timer = use_timeout(duration, move |()| {
-                visible_state.with_mut(|val| *val = false);
-            });
-            timer.action(());
-            // Explicitly return unit type
-            ()
-        });
-    }
+    // Set up auto-dismiss timer if not permanent, restarting it whenever the toast is updated
+    let dismiss_after = if toast.permanent {
+        None
+    } else {
+        Some(toast.duration.unwrap_or(props.default_duration))
+    };
+    let revision = toast.revision;
+    let paused = props.paused;
+    let mut swiping = use_signal(|| false);
+    let mut timer: Signal<Option<Task>> = use_signal(|| None);
+    use_effect(use_reactive(
+        (&revision, &dismiss_after),
+        move |(_, dismiss_after)| {
+            // A refreshed toast comes back even if it had started leaving
+            if !*visible.peek() {
+                visible.set(true);
+            }
+            if let Some(task) = timer.take() {
+                task.cancel();
+            }
+            if let Some(duration) = dismiss_after {
+                timer.set(Some(spawn(async move {
+                    // Count down in small steps so time spent paused is not counted
+                    let mut remaining = duration;
+                    while !remaining.is_zero() {
+                        sleep(TIMER_TICK).await;
+                        if !*paused.peek() && !*swiping.peek() {
+                            remaining = remaining.saturating_sub(TIMER_TICK);
+                        }
+                    }
+                    visible.set(false);
+                })));
+            }
+        },
+    ));
+
+    // Swipe-to-dismiss towards the nearest screen edge
+    let (swipe_x, swipe_y) = props.position.swipe_direction();
+    let mut swipe_start: Signal<Option<(f64, f64)>> = use_signal(|| None);
+    let mut swipe_offset = use_signal(|| 0.0);
+
+    let on_pointer_down = move |event: PointerEvent| {
+        let point = event.client_coordinates();
+        swipe_start.set(Some((point.x, point.y)));
+        swiping.set(true);
+    };
+
+    let on_pointer_move = move |event: PointerEvent| {
+        if let Some((start_x, start_y)) = swipe_start() {
+            let point = event.client_coordinates();
+            let distance = (point.x - start_x) * swipe_x + (point.y - start_y) * swipe_y;
+            swipe_offset.set(distance.max(0.0));
+        }
+    };
+
+    let mut end_swipe = move || {
+        if swipe_start.take().is_some() {
+            if swipe_offset() > SWIPE_THRESHOLD_PX {
+                visible.set(false);
+            }
+            swipe_offset.set(0.0);
+        }
+        swiping.set(false);
+    };
+
+    let swipe_style = if swipe_offset() > 0.0 {
+        Some(format!(
+            "transform: translate({}px, {}px); opacity: {};",
+            swipe_offset() * swipe_x,
+            swipe_offset() * swipe_y,
+            (1.0 - swipe_offset() / (SWIPE_THRESHOLD_PX * 2.0)).max(0.0)
+        ))
+    } else {
+        None
+    };
 
     // Base styling
-    let base_classes = "pointer-events-auto relative flex w-full items-center justify-between space-x-4 overflow-hidden rounded-md border p-4 shadow-md hover:shadow-lg transition-all duration-300 group backdrop-blur-sm";
+    let base_classes = "pointer-events-auto relative flex w-full items-center justify-between space-x-4 overflow-hidden rounded-md border p-4 shadow-md hover:shadow-lg transition-all duration-300 group backdrop-blur-sm touch-none select-none";
 
     // Animation classes based on state
-    let animation_classes = if !*visible.read() {
-        "animate-slide-out-to-right"
+    let animation_classes = if !is_visible {
+        props.position.exit_animation()
     } else {
-        "animate-slide-in-from-right"
+        props.position.enter_animation()
     };
 
     // Toast type specific classes
@@ -159,10 +505,16 @@ pub fn Toast(props: ToastProps) -> Element {
             } else {
                 None
             },
-            // Simplified: no pause-on-hover for initial implementation
+            aria_busy: (toast.toast_type == ToastType::Loading).to_string(),
+            style: swipe_style,
+            onpointerdown: on_pointer_down,
+            onpointermove: on_pointer_move,
+            onpointerup: move |_| end_swipe(),
+            onpointerleave: move |_| end_swipe(),
+            onpointercancel: move |_| end_swipe(),
             onanimationend: move |_| {
                 // If toast is not visible, remove it when animation ends
-                if !*visible.read() {
+                if !is_visible {
                     remove_toast();
                 }
             },
@@ -172,12 +524,7 @@ pub fn Toast(props: ToastProps) -> Element {
 
                 div {
                     class: "flex-shrink-0 {toast.toast_type.icon_classes()}",
-                    aria_label: match toast.toast_type {
-                        ToastType::Success => "Success:",
-                        ToastType::Error => "Error:",
-                        ToastType::Warning => "Warning:",
-                        ToastType::Info => "Information:",
-                    },
+                    aria_label: toast.toast_type.aria_label(),
                     {toast.toast_type.icon_component()}
                 }
 
@@ -188,6 +535,14 @@ pub fn Toast(props: ToastProps) -> Element {
                         class: "text-sm font-semibold leading-none tracking-tight",
                         id: format!("toast-title-{}", toast.id),
                         "{toast.title}"
+
+                        if toast.count > 1 {
+                            span {
+                                class: "ml-2 inline-flex items-center rounded-full bg-muted px-1.5 py-0.5 text-xs font-medium text-muted-foreground",
+                                aria_label: format!("shown {} times", toast.count),
+                                "{toast.count}"
+                            }
+                        }
                     }
 
                     if let Some(description) = &toast.description {
@@ -200,6 +555,26 @@ pub fn Toast(props: ToastProps) -> Element {
                 }
             }
 
+            // Action buttons dismiss the toast once their callback has run
+            if !toast.actions.is_empty() {
+                div {
+                    class: "flex flex-shrink-0 items-center gap-2 pr-6",
+
+                    for (index, action) in toast.actions.iter().cloned().enumerate() {
+                        Button {
+                            key: "{index}",
+                            variant: ButtonVariant::Outline,
+                            size: ButtonSize::Small,
+                            on_click: move |_| {
+                                action.on_click.call(());
+                                visible.set(false);
+                            },
+                            "{action.label}"
+                        }
+                    }
+                }
+            }
+
             Button {
                 variant: ButtonVariant::Ghost,
                 is_icon_button: true,
@@ -218,18 +593,70 @@ pub struct ToastOptions {
     pub description: Option<String>,
     pub duration: Option<Duration>,
     pub permanent: bool,
+    pub actions: Vec<ToastAction>,
+    // Showing a toast with the same key refreshes the existing one instead of adding another
+    pub dedupe_key: Option<String>,
+    pub priority: ToastPriority,
 }
 
-// Simplified toast API
-#[derive(Clone, Copy)]
-pub struct Toasts;
+// Handle to the toast queue of the nearest ToastProvider
+#[derive(Clone, Copy, PartialEq)]
+pub struct Toasts {
+    toasts: Signal<Vec<ToastItem>>,
+    next_id: Signal<usize>,
+    max_toasts: Signal<usize>,
+    rate_limit: Signal<Option<ToastRateLimit>>,
+    // Types of the toasts shown within the current rate limit window
+    recent: Signal<Vec<ToastType>>,
+    // Dismissed toasts, newest first. Disabled when max_history is 0
+    history: Signal<Vec<NotificationEntry>>,
+    max_history: Signal<usize>,
+}
 
 impl Toasts {
-    // Show a toast with the given type and options
-    pub fn show(&self, title: String, toast_type: ToastType, options: ToastOptions) {
-        let mut next_id = NEXT_ID.write();
-        let id = *next_id;
-        *next_id += 1;
+    // Show a toast with the given type and options, returning its id
+    pub fn show(&self, title: String, toast_type: ToastType, options: ToastOptions) -> ToastId {
+        let mut queue = self.toasts;
+
+        // Refresh an existing toast with the same dedupe key instead of adding a copy
+        if let Some(key) = &options.dedupe_key {
+            let existing = queue
+                .peek()
+                .iter()
+                .find(|t| t.dedupe_key.as_ref() == Some(key))
+                .map(|t| t.id);
+
+            if let Some(id) = existing {
+                if let Some(toast) = queue.write().iter_mut().find(|t| t.id == id) {
+                    apply_options(toast, title, toast_type, options);
+                    toast.count += 1;
+                    toast.revision += 1;
+                    toast.visible = true;
+                }
+                return id;
+            }
+        }
+
+        // Over the rate limit, fold the toast into the newest one of the same type
+        if !self.take_rate_limit_slot(toast_type) {
+            let newest = queue
+                .peek()
+                .iter()
+                .rev()
+                .find(|t| t.toast_type == toast_type)
+                .map(|t| t.id);
+
+            if let Some(id) = newest {
+                if let Some(toast) = queue.write().iter_mut().find(|t| t.id == id) {
+                    toast.count += 1;
+                }
+                return id;
+            }
+        }
+
+        let mut next_id = self.next_id;
+        let id = ToastId(*next_id.peek());
+        next_id += 1;
 
         let toast = ToastItem {
             id,
@@ -243,41 +670,257 @@ impl Toasts {
             },
             permanent: options.permanent,
             visible: true,
+            actions: options.actions,
+            dedupe_key: options.dedupe_key,
+            priority: options.priority,
+            count: 1,
+            revision: 0,
         };
 
-        let mut toasts = TOASTS.write();
+        let max_toasts = *self.max_toasts.peek();
+        let mut toasts = queue.write();
+
+        // Make room before adding, so the new toast is never the one evicted
+        while !toasts.is_empty() && toasts.len() >= max_toasts {
+            // Evict the lowest priority first, preferring non-permanent and then older toasts
+            let evicted = toasts
+                .iter()
+                .enumerate()
+                .min_by_key(|(index, t)| (t.priority, t.permanent, *index))
+                .map(|(index, _)| index)
+                .unwrap_or(0);
+            self.archive(toasts.remove(evicted));
+        }
         toasts.push(toast);
 
-        // Limit the number of toasts
-        while toasts.len() > 10 {
-            // Try to remove non-permanent toasts first
-            if let Some(pos) = toasts.iter().position(|t| !t.permanent) {
-                toasts.remove(pos);
-            } else {
-                toasts.remove(0);
+        id
+    }
+
+    // Replace the content of a toast that is still on screen, restarting its timer.
+    // Returns false if the toast has already been dismissed.
+    pub fn update(
+        &self,
+        id: ToastId,
+        title: String,
+        toast_type: ToastType,
+        options: ToastOptions,
+    ) -> bool {
+        // The provider may have unmounted while an async caller was still running
+        let mut queue = self.toasts;
+        let Ok(mut toasts) = queue.try_write() else {
+            return false;
+        };
+        let Some(toast) = toasts.iter_mut().find(|t| t.id == id) else {
+            return false;
+        };
+
+        apply_options(toast, title, toast_type, options);
+        toast.revision += 1;
+
+        true
+    }
+
+    // Record a toast of the given type against the rate limit, returning false if it is exhausted
+    fn take_rate_limit_slot(&self, toast_type: ToastType) -> bool {
+        let Some(limit) = *self.rate_limit.peek() else {
+            return true;
+        };
+
+        let mut recent = self.recent;
+        let shown = recent.peek().iter().filter(|t| **t == toast_type).count();
+        if shown >= limit.max {
+            return false;
+        }
+        recent.write().push(toast_type);
+
+        // Free the slot again once the window has passed
+        spawn_forever(async move {
+            sleep(limit.per).await;
+            if let Ok(mut recent) = recent.try_write()
+                && let Some(pos) = recent.iter().position(|t| *t == toast_type)
+            {
+                recent.remove(pos);
             }
+        });
+
+        true
+    }
+
+    // Start the exit animation of a toast, removing it once the animation ends
+    pub fn dismiss(&self, id: ToastId) {
+        let mut queue = self.toasts;
+        let Ok(mut toasts) = queue.try_write() else {
+            return;
+        };
+        if let Some(toast) = toasts.iter_mut().find(|t| t.id == id) {
+            toast.visible = false;
+        }
+    }
+
+    // Remove a toast immediately, without an exit animation
+    fn remove(&self, id: ToastId) {
+        let mut queue = self.toasts;
+        let Ok(mut toasts) = queue.try_write() else {
+            return;
+        };
+        if let Some(pos) = toasts.iter().position(|t| t.id == id) {
+            self.archive(toasts.remove(pos));
+        }
+    }
+
+    // Move a removed toast into the notification history, if enabled
+    fn archive(&self, toast: ToastItem) {
+        let max_history = *self.max_history.peek();
+        if max_history == 0 {
+            return;
         }
+
+        let mut history = self.history;
+        let Ok(mut entries) = history.try_write() else {
+            return;
+        };
+        entries.insert(
+            0,
+            NotificationEntry {
+                id: toast.id,
+                title: toast.title,
+                description: toast.description,
+                toast_type: toast.toast_type,
+                count: toast.count,
+                read: false,
+                timestamp: None,
+                time_label: None,
+            },
+        );
+        entries.truncate(max_history);
+        drop(entries);
+
+        // Only the browser knows the wall-clock time and the user's locale
+        let id = toast.id;
+        spawn_forever(async move {
+            let time = document::eval(
+                r#"return [Date.now(), new Date().toLocaleTimeString([], { hour: "numeric", minute: "2-digit" })];"#,
+            )
+            .join::<(f64, String)>()
+            .await;
+
+            if let Ok((timestamp, time_label)) = time
+                && let Ok(mut entries) = history.try_write()
+                && let Some(entry) = entries.iter_mut().find(|e| e.id == id)
+            {
+                entry.timestamp = Some(timestamp);
+                entry.time_label = Some(time_label);
+            }
+        });
+    }
+
+    // Dismissed toasts kept by the provider, newest first
+    pub fn history(&self) -> Vec<NotificationEntry> {
+        self.history.read().clone()
+    }
+
+    // Number of history entries that have not been marked as read
+    pub fn unread_count(&self) -> usize {
+        self.history.read().iter().filter(|e| !e.read).count()
+    }
+
+    pub fn mark_read(&self, id: ToastId) {
+        let mut history = self.history;
+        if let Some(entry) = history.write().iter_mut().find(|e| e.id == id) {
+            entry.read = true;
+        }
+    }
+
+    pub fn mark_all_read(&self) {
+        let mut history = self.history;
+        for entry in history.write().iter_mut() {
+            entry.read = true;
+        }
+    }
+
+    pub fn clear_history(&self) {
+        let mut history = self.history;
+        history.write().clear();
+    }
+
+    // Show a loading toast while the future runs, then turn it into a success or error toast
+    pub fn promise<F, T, E>(
+        &self,
+        future: F,
+        loading: String,
+        success: impl FnOnce(&T) -> String + 'static,
+        error: impl FnOnce(&E) -> String + 'static,
+    ) -> ToastId
+    where
+        F: Future<Output = Result<T, E>> + 'static,
+        T: 'static,
+        E: 'static,
+    {
+        let toasts = *self;
+        let id = toasts.show(
+            loading,
+            ToastType::Loading,
+            ToastOptions {
+                permanent: true,
+                ..Default::default()
+            },
+        );
+
+        // Spawned at the root so the toast still resolves if the calling component unmounts
+        spawn_forever(async move {
+            match future.await {
+                Ok(value) => {
+                    toasts.update(id, success(&value), ToastType::Success, Default::default());
+                }
+                Err(err) => {
+                    toasts.update(id, error(&err), ToastType::Error, Default::default());
+                }
+            }
+        });
+
+        id
     }
 
     // Convenience methods for different toast types
-    pub fn success(&self, title: String, options: Option<ToastOptions>) {
-        self.show(title, ToastType::Success, options.unwrap_or_default());
+    pub fn success(&self, title: String, options: Option<ToastOptions>) -> ToastId {
+        self.show(title, ToastType::Success, options.unwrap_or_default())
     }
 
-    pub fn error(&self, title: String, options: Option<ToastOptions>) {
-        self.show(title, ToastType::Error, options.unwrap_or_default());
+    pub fn error(&self, title: String, options: Option<ToastOptions>) -> ToastId {
+        self.show(title, ToastType::Error, options.unwrap_or_default())
     }
 
-    pub fn warning(&self, title: String, options: Option<ToastOptions>) {
-        self.show(title, ToastType::Warning, options.unwrap_or_default());
+    pub fn warning(&self, title: String, options: Option<ToastOptions>) -> ToastId {
+        self.show(title, ToastType::Warning, options.unwrap_or_default())
     }
 
-    pub fn info(&self, title: String, options: Option<ToastOptions>) {
-        self.show(title, ToastType::Info, options.unwrap_or_default());
+    pub fn info(&self, title: String, options: Option<ToastOptions>) -> ToastId {
+        self.show(title, ToastType::Info, options.unwrap_or_default())
     }
 }
 
-// Hook to use the toast API
+// Copy the user-facing content of a toast from its options
+fn apply_options(
+    toast: &mut ToastItem,
+    title: String,
+    toast_type: ToastType,
+    options: ToastOptions,
+) {
+    toast.title = title;
+    toast.description = options.description;
+    toast.toast_type = toast_type;
+    toast.duration = if options.permanent {
+        None
+    } else {
+        options.duration
+    };
+    toast.permanent = options.permanent;
+    toast.actions = options.actions;
+    toast.dedupe_key = options.dedupe_key;
+    toast.priority = options.priority;
+}
+
+// Hook to use the toast API of the nearest ToastProvider
 pub fn use_toast() -> Toasts {
-    Toasts
+    use_context::<Toasts>()
 }
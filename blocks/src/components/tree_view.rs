@@ -0,0 +1,1095 @@
+use crate::components::checkbox::{CheckboxSize, checkbox_classes};
+use crate::components::context_menu::{ContextMenu, ContextMenuTrigger};
+use crate::use_unique_id;
+use dioxus_lib::prelude::*;
+use dioxus_time::sleep;
+use lucide_dioxus::{Check, ChevronRight, LoaderCircle, Minus};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::time::Duration;
+
+/// A node of a TreeView
+#[derive(Clone, PartialEq)]
+pub struct TreeNode {
+    /// Identifies the node in the expanded, selected and checked sets
+    pub id: String,
+    /// The text shown for the node, also used for typeahead
+    pub label: String,
+    /// Optional icon shown before the label
+    pub icon: Option<Element>,
+    /// The child nodes
+    pub children: Vec<TreeNode>,
+    /// Whether the children are loaded with the TreeView's `load_children` when first expanded
+    pub lazy: bool,
+    /// Whether the node can be selected or checked
+    pub disabled: bool,
+}
+
+impl TreeNode {
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            icon: None,
+            children: Vec::new(),
+            lazy: false,
+            disabled: false,
+        }
+    }
+
+    /// Set the child nodes
+    pub fn children(mut self, children: Vec<TreeNode>) -> Self {
+        self.children = children;
+        self
+    }
+
+    /// Load the children when the node is first expanded
+    pub fn lazy(mut self) -> Self {
+        self.lazy = true;
+        self
+    }
+
+    /// Set the icon shown before the label
+    pub fn icon(mut self, icon: Element) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Keep the node from being selected or checked
+    pub fn disabled(mut self) -> Self {
+        self.disabled = true;
+        self
+    }
+
+    fn is_expandable(&self) -> bool {
+        self.lazy || !self.children.is_empty()
+    }
+}
+
+impl fmt::Debug for TreeNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TreeNode")
+            .field("id", &self.id)
+            .field("label", &self.label)
+            .field("children", &self.children)
+            .field("lazy", &self.lazy)
+            .field("disabled", &self.disabled)
+            .finish()
+    }
+}
+
+type LoadChildrenFn = dyn Fn(String) -> Pin<Box<dyn Future<Output = Vec<TreeNode>>>>;
+
+/// Loads the children of a lazy TreeNode, e.g. listing a directory or fetching from the backend
+#[derive(Clone)]
+pub struct TreeLoader(Rc<LoadChildrenFn>);
+
+impl TreeLoader {
+    pub fn new<F, Fut>(load: F) -> Self
+    where
+        F: Fn(String) -> Fut + 'static,
+        Fut: Future<Output = Vec<TreeNode>> + 'static,
+    {
+        Self(Rc::new(move |id| Box::pin(load(id))))
+    }
+
+    /// Load the children of the node with this id
+    pub async fn load(&self, id: String) -> Vec<TreeNode> {
+        (self.0)(id).await
+    }
+}
+
+impl PartialEq for TreeLoader {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for TreeLoader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TreeLoader")
+    }
+}
+
+/// How many nodes of a TreeView can be selected
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TreeSelectionMode {
+    None,
+    Single,
+    Multiple,
+}
+
+impl Default for TreeSelectionMode {
+    fn default() -> Self {
+        Self::Single
+    }
+}
+
+/// The checkbox state of a node. Parents are checked when all their children are.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TreeCheckState {
+    Checked,
+    Unchecked,
+    Mixed,
+}
+
+type LoadedChildren = HashMap<String, Vec<TreeNode>>;
+
+// The known children of a node, empty for lazy nodes that are not loaded yet
+fn node_children<'a>(node: &'a TreeNode, loaded: &'a LoadedChildren) -> &'a [TreeNode] {
+    if node.lazy {
+        loaded.get(&node.id).map(Vec::as_slice).unwrap_or(&[])
+    } else {
+        &node.children
+    }
+}
+
+fn find_node<'a>(
+    nodes: &'a [TreeNode],
+    loaded: &'a LoadedChildren,
+    id: &str,
+) -> Option<&'a TreeNode> {
+    nodes.iter().find_map(|node| {
+        if node.id == id {
+            Some(node)
+        } else {
+            find_node(node_children(node, loaded), loaded, id)
+        }
+    })
+}
+
+fn check_state(
+    node: &TreeNode,
+    loaded: &LoadedChildren,
+    checked: &HashSet<String>,
+) -> TreeCheckState {
+    let children = node_children(node, loaded);
+    if children.is_empty() {
+        return if checked.contains(&node.id) {
+            TreeCheckState::Checked
+        } else {
+            TreeCheckState::Unchecked
+        };
+    }
+    let states = children
+        .iter()
+        .map(|child| check_state(child, loaded, checked))
+        .collect::<Vec<_>>();
+    if states.iter().all(|state| *state == TreeCheckState::Checked) {
+        TreeCheckState::Checked
+    } else if states
+        .iter()
+        .all(|state| *state == TreeCheckState::Unchecked)
+    {
+        TreeCheckState::Unchecked
+    } else {
+        TreeCheckState::Mixed
+    }
+}
+
+// Check or clear a node and everything below it
+fn set_checked(
+    node: &TreeNode,
+    loaded: &LoadedChildren,
+    value: bool,
+    checked: &mut HashSet<String>,
+) {
+    if value {
+        checked.insert(node.id.clone());
+    } else {
+        checked.remove(&node.id);
+    }
+    for child in node_children(node, loaded) {
+        set_checked(child, loaded, value, checked);
+    }
+}
+
+// Store the loaded children of a lazy node. Children of a checked node start out checked, so they
+// are inserted first for the check to reach everything below them.
+fn insert_loaded(
+    id: &str,
+    children: Vec<TreeNode>,
+    loaded: &mut LoadedChildren,
+    checked: &mut HashSet<String>,
+) {
+    loaded.insert(id.to_string(), children);
+    if checked.contains(id) {
+        for child in &loaded[id] {
+            set_checked(child, loaded, true, checked);
+        }
+    }
+}
+
+// Keep parents in the checked set exactly when all their children are checked
+fn sync_parents(nodes: &[TreeNode], loaded: &LoadedChildren, checked: &mut HashSet<String>) {
+    for node in nodes {
+        let children = node_children(node, loaded);
+        if children.is_empty() {
+            continue;
+        }
+        sync_parents(children, loaded, checked);
+        if check_state(node, loaded, checked) == TreeCheckState::Checked {
+            checked.insert(node.id.clone());
+        } else {
+            checked.remove(&node.id);
+        }
+    }
+}
+
+// A visible node with its place in the tree
+#[derive(Clone)]
+struct TreeRow {
+    node: TreeNode,
+    level: usize,
+    parent: Option<String>,
+    position: usize,
+    set_size: usize,
+}
+
+fn flatten(
+    nodes: &[TreeNode],
+    loaded: &LoadedChildren,
+    expanded: &HashSet<String>,
+    level: usize,
+    parent: Option<&str>,
+    rows: &mut Vec<TreeRow>,
+) {
+    for (index, node) in nodes.iter().enumerate() {
+        rows.push(TreeRow {
+            node: node.clone(),
+            level,
+            parent: parent.map(str::to_string),
+            position: index + 1,
+            set_size: nodes.len(),
+        });
+        if expanded.contains(&node.id) {
+            flatten(
+                node_children(node, loaded),
+                loaded,
+                expanded,
+                level + 1,
+                Some(&node.id),
+                rows,
+            );
+        }
+    }
+}
+
+// Node ids can hold any text, so rows get DOM ids from a hash of them
+fn row_dom_id(tree_id: &str, node_id: &str) -> String {
+    let hash = node_id.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{}-node-{:x}", tree_id, hash)
+}
+
+/// Props for the TreeView component
+#[derive(Props, Clone, PartialEq)]
+pub struct TreeViewProps {
+    /// The top-level nodes
+    pub nodes: Vec<TreeNode>,
+
+    /// Loads the children of lazy nodes when they are first expanded
+    #[props(default)]
+    pub load_children: Option<TreeLoader>,
+
+    /// Ids of the expanded nodes. The tree updates this signal as nodes are expanded and collapsed.
+    /// Leave unset to let the tree manage its own state.
+    #[props(default)]
+    pub expanded: Option<Signal<HashSet<String>>>,
+
+    /// Ids of the nodes expanded at first when `expanded` is not provided
+    #[props(default)]
+    pub default_expanded: Vec<String>,
+
+    /// Callback with the expanded ids when they change
+    #[props(default)]
+    pub on_expanded_change: Option<EventHandler<HashSet<String>>>,
+
+    /// How many nodes can be selected
+    #[props(default)]
+    pub selection_mode: TreeSelectionMode,
+
+    /// Ids of the selected nodes. Leave unset to let the tree manage its own state.
+    #[props(default)]
+    pub selected: Option<Signal<HashSet<String>>>,
+
+    /// Callback with the selected ids when they change
+    #[props(default)]
+    pub on_selection_change: Option<EventHandler<HashSet<String>>>,
+
+    /// Callback with the id of a node activated with Enter or a double click
+    #[props(default)]
+    pub on_activate: Option<EventHandler<String>>,
+
+    /// Whether nodes have checkboxes. Parents show the mixed state when some children are checked.
+    #[props(default)]
+    pub checkable: bool,
+
+    /// Ids of the checked nodes. Leave unset to let the tree manage its own state.
+    #[props(default)]
+    pub checked: Option<Signal<HashSet<String>>>,
+
+    /// Callback with the checked ids when they change
+    #[props(default)]
+    pub on_checked_change: Option<EventHandler<HashSet<String>>>,
+
+    /// Renders the ContextMenuContent for a node, opened by right-clicking its row
+    #[props(default)]
+    pub context_menu: Option<Callback<String, Element>>,
+
+    /// Height of the scrolling area in pixels. Only the rows in view are rendered.
+    #[props(default = 320.0)]
+    pub height: f64,
+
+    /// Height of every row in pixels
+    #[props(default = 32.0)]
+    pub row_height: f64,
+
+    /// Indentation per level in pixels
+    #[props(default = 16.0)]
+    pub indent: f64,
+
+    /// Optional ID for the tree
+    #[props(default)]
+    pub id: Option<String>,
+
+    /// Accessible label for the tree
+    #[props(default)]
+    pub aria_label: Option<String>,
+
+    /// Optional additional classes for the scrolling area
+    #[props(default)]
+    pub class: Option<String>,
+
+    #[props(extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+}
+
+/// A hierarchical list following the WAI-ARIA tree pattern, with lazy loading, selection,
+/// tri-state checkboxes and virtualized rows
+#[component]
+pub fn TreeView(props: TreeViewProps) -> Element {
+    // Generate unique ID if not provided
+    let generated_id = use_unique_id();
+    let tree_id = props.id.clone().unwrap_or_else(|| generated_id());
+    let scroll_id = format!("{}-scroll", tree_id);
+
+    // Use the provided signals when controlled, otherwise keep the state internally
+    let internal_expanded = use_signal(|| {
+        props
+            .default_expanded
+            .iter()
+            .cloned()
+            .collect::<HashSet<_>>()
+    });
+    let mut expanded = props.expanded.unwrap_or(internal_expanded);
+    let internal_selected = use_signal(HashSet::new);
+    let mut selected = props.selected.unwrap_or(internal_selected);
+    let internal_checked = use_signal(HashSet::new);
+    let mut checked = props.checked.unwrap_or(internal_checked);
+
+    let mut loaded = use_signal(LoadedChildren::new);
+    let mut loading = use_signal(HashSet::<String>::new);
+    let mut focused = use_signal(|| None::<String>);
+    let mut focus_pending = use_signal(|| false);
+    let mut scroll_top = use_signal(|| 0.0);
+    let mut scroll_ref: Signal<Option<Rc<MountedData>>> = use_signal(|| None);
+    let mut typeahead = use_signal(String::new);
+    let mut typeahead_generation = use_signal(|| 0u64);
+
+    let mut rows = Vec::new();
+    flatten(
+        &props.nodes,
+        &loaded.read(),
+        &expanded.read(),
+        1,
+        None,
+        &mut rows,
+    );
+
+    // The focused node takes part in the tab order, defaulting to the first row
+    let focused_index = focused()
+        .and_then(|id| rows.iter().position(|row| row.node.id == id))
+        .unwrap_or(0);
+
+    // Expand or collapse a node, loading lazy children the first time
+    let nodes = props.nodes.clone();
+    let loader = props.load_children.clone();
+    let on_expanded_change = props.on_expanded_change;
+    let set_expanded = use_callback(move |(id, open): (String, bool)| {
+        let mut next = expanded.peek().clone();
+        let changed = if open {
+            next.insert(id.clone())
+        } else {
+            next.remove(&id)
+        };
+        if !changed {
+            return;
+        }
+        expanded.set(next.clone());
+        if let Some(handler) = &on_expanded_change {
+            handler.call(next);
+        }
+
+        let needs_loading = open
+            && find_node(&nodes, &loaded.peek(), &id).is_some_and(|node| node.lazy)
+            && !loaded.peek().contains_key(&id)
+            && !loading.peek().contains(&id);
+        if let (true, Some(loader)) = (needs_loading, loader.clone()) {
+            loading.write().insert(id.clone());
+            spawn(async move {
+                let children = loader.load(id.clone()).await;
+                let mut next = checked.peek().clone();
+                insert_loaded(&id, children, &mut loaded.write(), &mut next);
+                if *checked.peek() != next {
+                    checked.set(next);
+                }
+                loading.write().remove(&id);
+            });
+        }
+    });
+
+    let selection_mode = props.selection_mode;
+    let on_selection_change = props.on_selection_change;
+    let select = use_callback(move |(id, toggle): (String, bool)| {
+        let mut next = selected.peek().clone();
+        match selection_mode {
+            TreeSelectionMode::None => return,
+            TreeSelectionMode::Multiple if toggle => {
+                if !next.remove(&id) {
+                    next.insert(id);
+                }
+            }
+            _ => {
+                next.clear();
+                next.insert(id);
+            }
+        }
+        if *selected.peek() == next {
+            return;
+        }
+        selected.set(next.clone());
+        if let Some(handler) = &on_selection_change {
+            handler.call(next);
+        }
+    });
+
+    let check_nodes = props.nodes.clone();
+    let on_checked_change = props.on_checked_change;
+    let toggle_check = use_callback(move |id: String| {
+        let known = loaded.peek();
+        let Some(node) = find_node(&check_nodes, &known, &id) else {
+            return;
+        };
+        let mut next = checked.peek().clone();
+        let value = check_state(node, &known, &next) != TreeCheckState::Checked;
+        set_checked(node, &known, value, &mut next);
+        sync_parents(&check_nodes, &known, &mut next);
+        checked.set(next.clone());
+        if let Some(handler) = &on_checked_change {
+            handler.call(next);
+        }
+    });
+
+    // Move focus to a row, scrolling it into view first so it is rendered
+    let height = props.height;
+    let row_height = props.row_height;
+    let scroll_target = scroll_id.clone();
+    let focus_rows = rows
+        .iter()
+        .map(|row| row.node.id.clone())
+        .collect::<Vec<_>>();
+    let focus_row = use_callback(move |index: usize| {
+        let Some(id) = focus_rows.get(index).cloned() else {
+            return;
+        };
+        let top = index as f64 * row_height;
+        let current = *scroll_top.peek();
+        let next = if top < current {
+            top
+        } else if top + row_height > current + height {
+            top + row_height - height
+        } else {
+            current
+        };
+        if next != current {
+            scroll_top.set(next);
+            let _ = document::eval(&format!(
+                r#"const area = document.getElementById("{}"); if (area) {{ area.scrollTop = {}; }}"#,
+                scroll_target, next
+            ));
+        }
+        focused.set(Some(id));
+        focus_pending.set(true);
+    });
+
+    let focus_tree_id = tree_id.clone();
+    use_effect(move || {
+        if !focus_pending() {
+            return;
+        }
+        if let Some(id) = focused() {
+            let _ = document::eval(&format!(
+                r#"const row = document.getElementById("{}"); if (row) {{ row.focus(); }}"#,
+                row_dom_id(&focus_tree_id, &id)
+            ));
+        }
+        focus_pending.set(false);
+    });
+
+    let checkable = props.checkable;
+    let on_activate = props.on_activate;
+    let key_rows = rows.clone();
+    let handle_key_down = move |event: KeyboardEvent| {
+        let Some(row) = key_rows.get(focused_index) else {
+            return;
+        };
+        let id = row.node.id.clone();
+        let is_expanded = expanded.peek().contains(&id);
+        let has_children = row.node.is_expandable();
+        let modifiers = event.modifiers();
+        let is_toggle =
+            modifiers.contains(Modifiers::CONTROL) || modifiers.contains(Modifiers::META);
+
+        match event.key() {
+            Key::ArrowDown => {
+                focus_row.call((focused_index + 1).min(key_rows.len().saturating_sub(1)))
+            }
+            Key::ArrowUp => focus_row.call(focused_index.saturating_sub(1)),
+            Key::Home => focus_row.call(0),
+            Key::End => focus_row.call(key_rows.len().saturating_sub(1)),
+            Key::ArrowRight if has_children => {
+                if is_expanded {
+                    // Move into the first child once it is loaded
+                    let first_child = key_rows
+                        .get(focused_index + 1)
+                        .filter(|child| child.parent.as_deref() == Some(id.as_str()));
+                    if first_child.is_some() {
+                        focus_row.call(focused_index + 1);
+                    }
+                } else {
+                    set_expanded.call((id, true));
+                }
+            }
+            Key::ArrowLeft => {
+                if has_children && is_expanded {
+                    set_expanded.call((id, false));
+                } else if let Some(parent) = &row.parent
+                    && let Some(index) = key_rows.iter().position(|row| &row.node.id == parent)
+                {
+                    focus_row.call(index);
+                }
+            }
+            Key::Enter => {
+                if !row.node.disabled {
+                    select.call((id.clone(), is_toggle));
+                    if let Some(handler) = &on_activate {
+                        handler.call(id);
+                    }
+                }
+            }
+            Key::Character(text) if text == " " => {
+                if checkable && !row.node.disabled {
+                    toggle_check.call(id);
+                } else if !row.node.disabled {
+                    select.call((id, true));
+                }
+            }
+            // Expand every sibling of the focused node
+            Key::Character(text) if text == "*" => {
+                let siblings = key_rows
+                    .iter()
+                    .filter(|other| other.parent == row.parent && other.node.is_expandable())
+                    .map(|other| other.node.id.clone())
+                    .collect::<Vec<_>>();
+                for sibling in siblings {
+                    set_expanded.call((sibling, true));
+                }
+            }
+            // Typeahead: focus the next node whose label starts with the typed text
+            Key::Character(text)
+                if text.chars().count() == 1
+                    && !modifiers.contains(Modifiers::CONTROL)
+                    && !modifiers.contains(Modifiers::META)
+                    && !modifiers.contains(Modifiers::ALT) =>
+            {
+                let mut query = typeahead.peek().clone();
+                query.push_str(&text.to_lowercase());
+                typeahead.set(query.clone());
+
+                // A new search starts after the focused row, a longer one may stay on it
+                let start = if query.chars().count() == 1 {
+                    focused_index + 1
+                } else {
+                    focused_index
+                };
+                let count = key_rows.len();
+                let found = (0..count)
+                    .map(|offset| (start + offset) % count.max(1))
+                    .find(|index| {
+                        key_rows[*index]
+                            .node
+                            .label
+                            .to_lowercase()
+                            .starts_with(&query)
+                    });
+                if let Some(index) = found {
+                    focus_row.call(index);
+                }
+
+                let generation = *typeahead_generation.peek() + 1;
+                typeahead_generation.set(generation);
+                spawn(async move {
+                    sleep(Duration::from_millis(500)).await;
+                    if *typeahead_generation.peek() == generation {
+                        typeahead.set(String::new());
+                    }
+                });
+            }
+            _ => return,
+        }
+        event.prevent_default();
+    };
+
+    let handle_scroll = move |_: ScrollEvent| async move {
+        if let Some(area) = scroll_ref()
+            && let Ok(offset) = area.get_scroll_offset().await
+        {
+            scroll_top.set(offset.y);
+        }
+    };
+
+    // Render the rows in view, a few around them, and the focused row so Tab can reach the tree
+    let overscan = 5;
+    let total = rows.len();
+    let first = ((scroll_top() / row_height).floor().max(0.0) as usize)
+        .saturating_sub(overscan)
+        .min(total);
+    let last = (first + (height / row_height).ceil() as usize + overscan * 2).min(total);
+    let mut rendered = (first..last).collect::<Vec<_>>();
+    if focused_index < total && !(first..last).contains(&focused_index) {
+        rendered.push(focused_index);
+    }
+
+    let scroll_classes = vec![
+        "relative overflow-auto rounded border border-border bg-background",
+        props.class.as_deref().unwrap_or(""),
+    ]
+    .into_iter()
+    .filter(|s| !s.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ");
+    let scroll_style = format!("height: {}px;", height);
+    let content_style = format!("height: {}px;", total as f64 * row_height);
+    let is_multiple = props.selection_mode == TreeSelectionMode::Multiple;
+    let (_, icon_size) = checkbox_classes(CheckboxSize::Small, false, false);
+    let context_menu = props.context_menu;
+    let indent = props.indent;
+
+    rsx! {
+        div {
+            id: scroll_id.clone(),
+            class: scroll_classes,
+            style: scroll_style,
+            onmounted: move |event: MountedEvent| scroll_ref.set(Some(event.data())),
+            onscroll: handle_scroll,
+
+            div {
+                id: tree_id.clone(),
+                class: "relative",
+                style: content_style,
+                role: "tree",
+                aria_label: props.aria_label.clone(),
+                aria_multiselectable: is_multiple.then_some("true"),
+                onkeydown: handle_key_down,
+                ..props.attributes,
+
+                for index in rendered {
+                    {
+                        let row = rows[index].clone();
+                        let node = row.node.clone();
+                        let id = node.id.clone();
+                        let is_expandable = node.is_expandable();
+                        let is_expanded = is_expandable && expanded.read().contains(&id);
+                        let is_loading = loading.read().contains(&id);
+                        let is_selected = selected.read().contains(&id);
+                        let is_focused = index == focused_index;
+                        let check = checkable.then(|| check_state(&node, &loaded.read(), &checked.read()));
+                        let (checkbox_class, _) = checkbox_classes(
+                            CheckboxSize::Small,
+                            check.is_some_and(|state| state != TreeCheckState::Unchecked),
+                            node.disabled,
+                        );
+                        let row_style = format!(
+                            "top: {}px; height: {}px;",
+                            index as f64 * row_height,
+                            row_height
+                        );
+                        let padding = format!("padding-left: {}px;", 8.0 + (row.level - 1) as f64 * indent);
+                        let row_classes = vec![
+                            "flex h-full w-full items-center gap-1.5 rounded-sm pr-2 text-sm select-none",
+                            "focus:outline-none focus-visible:ring-2 focus-visible:ring-inset focus-visible:ring-ring",
+                            if is_selected { "bg-accent text-accent-foreground" } else { "hover:bg-muted" },
+                            if node.disabled { "opacity-50 cursor-not-allowed" } else { "cursor-pointer" },
+                        ]
+                        .join(" ");
+                        let label = node.label.clone();
+
+                        let item = rsx! {
+                            div {
+                                id: row_dom_id(&tree_id, &id),
+                                class: row_classes,
+                                style: padding,
+                                role: "treeitem",
+                                tabindex: if is_focused { "0" } else { "-1" },
+                                aria_level: row.level.to_string(),
+                                aria_setsize: row.set_size.to_string(),
+                                aria_posinset: row.position.to_string(),
+                                aria_expanded: is_expandable.then(|| is_expanded.to_string()),
+                                aria_selected: (selection_mode != TreeSelectionMode::None).then(|| is_selected.to_string()),
+                                "aria-checked": check.map(|state| match state {
+                                    TreeCheckState::Checked => "true",
+                                    TreeCheckState::Unchecked => "false",
+                                    TreeCheckState::Mixed => "mixed",
+                                }),
+                                aria_disabled: node.disabled.then_some("true"),
+                                aria_busy: is_loading.then_some("true"),
+                                onfocus: {
+                                    let id = id.clone();
+                                    move |_| focused.set(Some(id.clone()))
+                                },
+                                onclick: {
+                                    let id = id.clone();
+                                    let disabled = node.disabled;
+                                    move |event: MouseEvent| {
+                                        focused.set(Some(id.clone()));
+                                        if disabled {
+                                            return;
+                                        }
+                                        let modifiers = event.modifiers();
+                                        let is_toggle = modifiers.contains(Modifiers::CONTROL)
+                                            || modifiers.contains(Modifiers::META);
+                                        if selection_mode == TreeSelectionMode::None && checkable {
+                                            toggle_check.call(id.clone());
+                                        } else {
+                                            select.call((id.clone(), is_toggle));
+                                        }
+                                    }
+                                },
+                                ondoubleclick: {
+                                    let id = id.clone();
+                                    let disabled = node.disabled;
+                                    move |_| {
+                                        if disabled {
+                                            return;
+                                        }
+                                        if is_expandable {
+                                            set_expanded.call((id.clone(), !is_expanded));
+                                        }
+                                        if let Some(handler) = &on_activate {
+                                            handler.call(id.clone());
+                                        }
+                                    }
+                                },
+                                // Right-clicking a node targets it, like in file explorers
+                                oncontextmenu: {
+                                    let id = id.clone();
+                                    move |_| {
+                                        focused.set(Some(id.clone()));
+                                        if !is_selected {
+                                            select.call((id.clone(), false));
+                                        }
+                                    }
+                                },
+
+                                span {
+                                    class: "flex h-4 w-4 shrink-0 items-center justify-center text-muted-foreground",
+                                    aria_hidden: "true",
+                                    onclick: {
+                                        let id = id.clone();
+                                        move |event: MouseEvent| {
+                                            if is_expandable {
+                                                event.stop_propagation();
+                                                set_expanded.call((id.clone(), !is_expanded));
+                                            }
+                                        }
+                                    },
+                                    if is_loading {
+                                        LoaderCircle { size: 14, class: "animate-spin" }
+                                    } else if is_expandable {
+                                        ChevronRight {
+                                            size: 14,
+                                            class: if is_expanded { "rotate-90 transition-transform" } else { "transition-transform" },
+                                        }
+                                    }
+                                }
+
+                                if let Some(state) = check {
+                                    span {
+                                        class: checkbox_class,
+                                        aria_hidden: "true",
+                                        onclick: {
+                                            let id = id.clone();
+                                            let disabled = node.disabled;
+                                            move |event: MouseEvent| {
+                                                event.stop_propagation();
+                                                focused.set(Some(id.clone()));
+                                                if !disabled {
+                                                    toggle_check.call(id.clone());
+                                                }
+                                            }
+                                        },
+                                        match state {
+                                            TreeCheckState::Checked => rsx! {
+                                                div { class: "flex items-center justify-center {icon_size}",
+                                                    Check { class: "text-primary-foreground" }
+                                                }
+                                            },
+                                            TreeCheckState::Mixed => rsx! {
+                                                div { class: "flex items-center justify-center {icon_size}",
+                                                    Minus { class: "text-primary-foreground" }
+                                                }
+                                            },
+                                            TreeCheckState::Unchecked => rsx! {},
+                                        }
+                                    }
+                                }
+
+                                if let Some(icon) = node.icon.clone() {
+                                    span {
+                                        class: "flex shrink-0 items-center text-muted-foreground",
+                                        aria_hidden: "true",
+                                        {icon}
+                                    }
+                                }
+
+                                span { class: "truncate", "{label}" }
+                            }
+                        };
+
+                        rsx! {
+                            div {
+                                key: "{id}",
+                                role: "none",
+                                class: "absolute left-0 right-0",
+                                style: row_style,
+                                if let Some(menu) = context_menu {
+                                    ContextMenu {
+                                        ContextMenuTrigger { {item} }
+                                        {menu.call(id.clone())}
+                                    }
+                                } else {
+                                    {item}
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if total == 0 {
+                p { class: "px-3 py-2 text-sm text-muted-foreground", "No items" }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(items: &[&str]) -> HashSet<String> {
+        items.iter().map(|item| item.to_string()).collect()
+    }
+
+    // fruit has apple and pear, vegetables is loaded lazily
+    fn tree() -> Vec<TreeNode> {
+        vec![
+            TreeNode::new("fruit", "Fruit").children(vec![
+                TreeNode::new("apple", "Apple"),
+                TreeNode::new("pear", "Pear"),
+            ]),
+            TreeNode::new("vegetables", "Vegetables").lazy(),
+        ]
+    }
+
+    #[test]
+    fn leaves_are_checked_when_in_the_set() {
+        let nodes = tree();
+        let loaded = LoadedChildren::new();
+        let apple = &nodes[0].children[0];
+        assert_eq!(
+            check_state(apple, &loaded, &ids(&["apple"])),
+            TreeCheckState::Checked
+        );
+        assert_eq!(
+            check_state(apple, &loaded, &ids(&[])),
+            TreeCheckState::Unchecked
+        );
+        // An unloaded lazy node is a leaf until its children arrive
+        assert_eq!(
+            check_state(&nodes[1], &loaded, &ids(&["vegetables"])),
+            TreeCheckState::Checked
+        );
+    }
+
+    #[test]
+    fn parents_are_mixed_until_all_children_are_checked() {
+        let nodes = tree();
+        let loaded = LoadedChildren::new();
+        assert_eq!(
+            check_state(&nodes[0], &loaded, &ids(&[])),
+            TreeCheckState::Unchecked
+        );
+        assert_eq!(
+            check_state(&nodes[0], &loaded, &ids(&["apple"])),
+            TreeCheckState::Mixed
+        );
+        assert_eq!(
+            check_state(&nodes[0], &loaded, &ids(&["apple", "pear"])),
+            TreeCheckState::Checked
+        );
+        // The parent's own entry does not matter, its children decide
+        assert_eq!(
+            check_state(&nodes[0], &loaded, &ids(&["fruit"])),
+            TreeCheckState::Unchecked
+        );
+    }
+
+    #[test]
+    fn set_checked_reaches_every_descendant() {
+        let nodes = tree();
+        let loaded = LoadedChildren::new();
+        let mut checked = HashSet::new();
+        set_checked(&nodes[0], &loaded, true, &mut checked);
+        assert_eq!(checked, ids(&["fruit", "apple", "pear"]));
+        set_checked(&nodes[0], &loaded, false, &mut checked);
+        assert!(checked.is_empty());
+    }
+
+    #[test]
+    fn sync_parents_follows_the_children() {
+        let nodes = tree();
+        let loaded = LoadedChildren::new();
+
+        let mut checked = ids(&["apple", "pear"]);
+        sync_parents(&nodes, &loaded, &mut checked);
+        assert_eq!(checked, ids(&["fruit", "apple", "pear"]));
+
+        checked.remove("pear");
+        sync_parents(&nodes, &loaded, &mut checked);
+        assert_eq!(checked, ids(&["apple"]));
+    }
+
+    #[test]
+    fn sync_parents_works_through_levels() {
+        let nodes = vec![TreeNode::new("root", "Root").children(vec![
+            TreeNode::new("branch", "Branch").children(vec![TreeNode::new("leaf", "Leaf")]),
+            TreeNode::new("other", "Other"),
+        ])];
+        let loaded = LoadedChildren::new();
+
+        let mut checked = ids(&["leaf"]);
+        sync_parents(&nodes, &loaded, &mut checked);
+        assert_eq!(checked, ids(&["leaf", "branch"]));
+        assert_eq!(
+            check_state(&nodes[0], &loaded, &checked),
+            TreeCheckState::Mixed
+        );
+
+        checked.insert("other".to_string());
+        sync_parents(&nodes, &loaded, &mut checked);
+        assert_eq!(checked, ids(&["root", "branch", "leaf", "other"]));
+    }
+
+    #[test]
+    fn loaded_children_of_a_checked_node_are_checked() {
+        let nodes = tree();
+        let mut loaded = LoadedChildren::new();
+        let mut checked = ids(&["vegetables"]);
+        let children = vec![
+            TreeNode::new("roots", "Roots").children(vec![TreeNode::new("carrot", "Carrot")]),
+            TreeNode::new("leek", "Leek"),
+        ];
+        insert_loaded("vegetables", children, &mut loaded, &mut checked);
+        assert_eq!(checked, ids(&["vegetables", "roots", "carrot", "leek"]));
+        assert_eq!(
+            check_state(&nodes[1], &loaded, &checked),
+            TreeCheckState::Checked
+        );
+    }
+
+    #[test]
+    fn loaded_children_of_an_unchecked_node_stay_unchecked() {
+        let mut loaded = LoadedChildren::new();
+        let mut checked = ids(&["fruit"]);
+        insert_loaded(
+            "vegetables",
+            vec![TreeNode::new("leek", "Leek")],
+            &mut loaded,
+            &mut checked,
+        );
+        assert_eq!(checked, ids(&["fruit"]));
+        assert!(loaded.contains_key("vegetables"));
+    }
+
+    #[test]
+    fn flatten_lists_expanded_nodes_in_order() {
+        let nodes = tree();
+        let mut loaded = LoadedChildren::new();
+        loaded.insert(
+            "vegetables".to_string(),
+            vec![TreeNode::new("leek", "Leek")],
+        );
+
+        let mut rows = Vec::new();
+        flatten(&nodes, &loaded, &ids(&[]), 1, None, &mut rows);
+        let order = rows
+            .iter()
+            .map(|row| row.node.id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(order, ["fruit", "vegetables"]);
+
+        let mut rows = Vec::new();
+        flatten(
+            &nodes,
+            &loaded,
+            &ids(&["fruit", "vegetables"]),
+            1,
+            None,
+            &mut rows,
+        );
+        let order = rows
+            .iter()
+            .map(|row| row.node.id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(order, ["fruit", "apple", "pear", "vegetables", "leek"]);
+
+        let pear = &rows[2];
+        assert_eq!(pear.level, 2);
+        assert_eq!(pear.parent.as_deref(), Some("fruit"));
+        assert_eq!((pear.position, pear.set_size), (2, 2));
+        let leek = &rows[4];
+        assert_eq!(leek.parent.as_deref(), Some("vegetables"));
+        assert_eq!((leek.position, leek.set_size), (1, 1));
+    }
+
+    #[test]
+    fn flatten_skips_children_of_unloaded_lazy_nodes() {
+        let nodes = tree();
+        let mut rows = Vec::new();
+        flatten(
+            &nodes,
+            &LoadedChildren::new(),
+            &ids(&["vegetables"]),
+            1,
+            None,
+            &mut rows,
+        );
+        assert_eq!(rows.len(), 2);
+    }
+}
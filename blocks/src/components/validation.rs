@@ -0,0 +1,433 @@
+use crate::components::form::{FieldValue, FormValues, ValidationResult};
+use regex::Regex;
+use std::fmt;
+use std::rc::Rc;
+use std::sync::LazyLock;
+
+static EMAIL_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[^\s@]+@[^\s@]+\.[^\s@]+$").expect("email pattern is valid"));
+
+/// Message templates for failed rules. Provide a localized copy with `use_context_provider`
+/// to translate every rule below it. Templates may use `{min}`, `{max}` and `{field}`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ValidationMessages {
+    pub required: String,
+    pub min_len: String,
+    pub max_len: String,
+    pub pattern: String,
+    pub email: String,
+    pub url: String,
+    pub range: String,
+    pub matches_field: String,
+}
+
+impl Default for ValidationMessages {
+    fn default() -> Self {
+        Self {
+            required: "This field is required".to_string(),
+            min_len: "Must be at least {min} characters".to_string(),
+            max_len: "Must be at most {max} characters".to_string(),
+            pattern: "Invalid format".to_string(),
+            email: "Please enter a valid email address".to_string(),
+            url: "Please enter a valid URL".to_string(),
+            range: "Must be between {min} and {max}".to_string(),
+            matches_field: "Must match {field}".to_string(),
+        }
+    }
+}
+
+type CustomRuleFn = dyn Fn(&FieldValue, &FormValues) -> ValidationResult;
+
+#[derive(Clone)]
+enum RuleKind {
+    Required,
+    MinLen(usize),
+    MaxLen(usize),
+    Pattern(Regex),
+    Email,
+    Url,
+    Range(f64, f64),
+    MatchesField(String),
+    Custom(Rc<CustomRuleFn>),
+}
+
+impl PartialEq for RuleKind {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (RuleKind::Required, RuleKind::Required) => true,
+            (RuleKind::MinLen(a), RuleKind::MinLen(b)) => a == b,
+            (RuleKind::MaxLen(a), RuleKind::MaxLen(b)) => a == b,
+            (RuleKind::Pattern(a), RuleKind::Pattern(b)) => a.as_str() == b.as_str(),
+            (RuleKind::Email, RuleKind::Email) => true,
+            (RuleKind::Url, RuleKind::Url) => true,
+            (RuleKind::Range(a_min, a_max), RuleKind::Range(b_min, b_max)) => {
+                a_min == b_min && a_max == b_max
+            }
+            (RuleKind::MatchesField(a), RuleKind::MatchesField(b)) => a == b,
+            (RuleKind::Custom(a), RuleKind::Custom(b)) => Rc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+/// A composable validation rule. Every rule except `required` passes on empty values,
+/// so optional fields are only checked once they are filled in.
+#[derive(Clone, PartialEq)]
+pub struct Rule {
+    kind: RuleKind,
+    message: Option<String>,
+}
+
+impl fmt::Debug for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match &self.kind {
+            RuleKind::Required => "required".to_string(),
+            RuleKind::MinLen(min) => format!("min_len({min})"),
+            RuleKind::MaxLen(max) => format!("max_len({max})"),
+            RuleKind::Pattern(regex) => format!("pattern({})", regex.as_str()),
+            RuleKind::Email => "email".to_string(),
+            RuleKind::Url => "url".to_string(),
+            RuleKind::Range(min, max) => format!("range({min}, {max})"),
+            RuleKind::MatchesField(field) => format!("matches_field({field})"),
+            RuleKind::Custom(_) => "custom".to_string(),
+        };
+        f.debug_struct("Rule")
+            .field("kind", &kind)
+            .field("message", &self.message)
+            .finish()
+    }
+}
+
+impl Rule {
+    fn new(kind: RuleKind) -> Self {
+        Self {
+            kind,
+            message: None,
+        }
+    }
+
+    /// Replace the message from `ValidationMessages` for this rule only
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// Check a value, using `values` for cross-field rules and `messages` for the error text
+    pub fn validate(
+        &self,
+        value: &FieldValue,
+        values: &FormValues,
+        messages: &ValidationMessages,
+    ) -> ValidationResult {
+        if value.is_empty() && !matches!(self.kind, RuleKind::Required) {
+            return Ok(());
+        }
+
+        let text = value.to_string();
+        let (valid, template) = match &self.kind {
+            RuleKind::Required => (!value.is_empty(), &messages.required),
+            RuleKind::MinLen(min) => (length(value) >= *min, &messages.min_len),
+            RuleKind::MaxLen(max) => (length(value) <= *max, &messages.max_len),
+            RuleKind::Pattern(regex) => (regex.is_match(&text), &messages.pattern),
+            RuleKind::Email => (EMAIL_REGEX.is_match(text.trim()), &messages.email),
+            RuleKind::Url => (is_url(text.trim()), &messages.url),
+            RuleKind::Range(min, max) => (
+                f64::try_from(value.clone()).is_ok_and(|n| n >= *min && n <= *max),
+                &messages.range,
+            ),
+            RuleKind::MatchesField(field) => {
+                (values.get(field) == Some(value), &messages.matches_field)
+            }
+            RuleKind::Custom(validate) => {
+                return validate(value, values)
+                    .map_err(|error| self.message.clone().unwrap_or(error));
+            }
+        };
+
+        if valid {
+            Ok(())
+        } else {
+            Err(self.message.clone().unwrap_or_else(|| self.fill(template)))
+        }
+    }
+
+    /// Check a standalone value with the default English messages
+    pub fn check(&self, value: impl Into<FieldValue>) -> ValidationResult {
+        self.validate(
+            &value.into(),
+            &FormValues::default(),
+            &ValidationMessages::default(),
+        )
+    }
+
+    // Substitute the rule parameters into a message template
+    fn fill(&self, template: &str) -> String {
+        match &self.kind {
+            RuleKind::MinLen(min) => template.replace("{min}", &min.to_string()),
+            RuleKind::MaxLen(max) => template.replace("{max}", &max.to_string()),
+            RuleKind::Range(min, max) => template
+                .replace("{min}", &min.to_string())
+                .replace("{max}", &max.to_string()),
+            RuleKind::MatchesField(field) => template.replace("{field}", field),
+            _ => template.to_string(),
+        }
+    }
+}
+
+// Characters for text, items for lists
+fn length(value: &FieldValue) -> usize {
+    match value {
+        FieldValue::List(items) => items.len(),
+        other => other.to_string().chars().count(),
+    }
+}
+
+fn is_url(text: &str) -> bool {
+    let Some((scheme, rest)) = text.split_once("://") else {
+        return false;
+    };
+    let host = rest.split(['/', '?', '#']).next().unwrap_or("");
+    matches!(scheme, "http" | "https") && !host.is_empty() && !text.contains(char::is_whitespace)
+}
+
+/// The value must not be empty
+pub fn required() -> Rule {
+    Rule::new(RuleKind::Required)
+}
+
+/// Text must have at least `min` characters, lists at least `min` items
+pub fn min_len(min: usize) -> Rule {
+    Rule::new(RuleKind::MinLen(min))
+}
+
+/// Text must have at most `max` characters, lists at most `max` items
+pub fn max_len(max: usize) -> Rule {
+    Rule::new(RuleKind::MaxLen(max))
+}
+
+/// The text must match a regular expression
+///
+/// # Panics
+///
+/// Panics if `pattern` is not a valid regular expression. Use `try_pattern` for patterns that
+/// are not known at compile time.
+pub fn pattern(pattern: &str) -> Rule {
+    try_pattern(pattern).expect("validation pattern must be a valid regular expression")
+}
+
+/// Like `pattern`, but returns an error for an invalid regular expression instead of panicking
+pub fn try_pattern(pattern: &str) -> Result<Rule, regex::Error> {
+    Regex::new(pattern).map(|regex| Rule::new(RuleKind::Pattern(regex)))
+}
+
+/// The text must look like an email address
+pub fn email() -> Rule {
+    Rule::new(RuleKind::Email)
+}
+
+/// The text must be an absolute http or https URL
+pub fn url() -> Rule {
+    Rule::new(RuleKind::Url)
+}
+
+/// The value must be a number between `min` and `max`, inclusive
+pub fn range(min: f64, max: f64) -> Rule {
+    Rule::new(RuleKind::Range(min, max))
+}
+
+/// The value must equal the value of another form field, e.g. a password confirmation
+pub fn matches_field(field: impl Into<String>) -> Rule {
+    Rule::new(RuleKind::MatchesField(field.into()))
+}
+
+/// A rule backed by a closure. Its error is used unless overridden with `Rule::message`.
+pub fn custom(validate: impl Fn(&FieldValue, &FormValues) -> ValidationResult + 'static) -> Rule {
+    Rule::new(RuleKind::Custom(Rc::new(validate)))
+}
+
+/// Check a value against rules in order, returning the first error
+pub fn validate_rules(
+    rules: &[Rule],
+    value: &FieldValue,
+    values: &FormValues,
+    messages: &ValidationMessages,
+) -> ValidationResult {
+    rules
+        .iter()
+        .try_for_each(|rule| rule.validate(value, values, messages))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn required_rejects_empty_values() {
+        assert_eq!(
+            required().check(""),
+            Err("This field is required".to_string())
+        );
+        assert!(required().check("   ").is_err());
+        assert!(required().check(false).is_err());
+        assert!(required().check(Vec::<String>::new()).is_err());
+        assert_eq!(required().check("x"), Ok(()));
+        assert_eq!(required().check(0), Ok(()));
+    }
+
+    #[test]
+    fn other_rules_skip_empty_values() {
+        for rule in [min_len(3), email(), url(), range(1.0, 2.0), pattern("^a$")] {
+            assert_eq!(rule.check(""), Ok(()), "{rule:?}");
+        }
+    }
+
+    #[test]
+    fn length_counts_characters_or_items() {
+        assert_eq!(
+            min_len(3).check("ab"),
+            Err("Must be at least 3 characters".to_string())
+        );
+        assert_eq!(min_len(3).check("äöü"), Ok(()));
+        assert_eq!(
+            max_len(2).check("abc"),
+            Err("Must be at most 2 characters".to_string())
+        );
+        assert_eq!(max_len(2).check("ab"), Ok(()));
+
+        let tags = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        assert!(max_len(2).check(tags.clone()).is_err());
+        assert_eq!(min_len(3).check(tags), Ok(()));
+    }
+
+    #[test]
+    fn email_and_url() {
+        assert_eq!(email().check("ada@example.com"), Ok(()));
+        assert!(email().check("ada@example").is_err());
+        assert!(email().check("ada @example.com").is_err());
+
+        assert_eq!(url().check("https://example.com/path?q=1"), Ok(()));
+        assert_eq!(url().check("http://localhost:8080"), Ok(()));
+        assert!(url().check("ftp://example.com").is_err());
+        assert!(url().check("https://").is_err());
+        assert!(url().check("example.com").is_err());
+        assert!(url().check("https://exa mple.com").is_err());
+    }
+
+    #[test]
+    fn range_is_inclusive_and_needs_a_number() {
+        let rule = range(18.0, 120.0);
+        assert_eq!(rule.check(18), Ok(()));
+        assert_eq!(rule.check(120.0), Ok(()));
+        assert_eq!(rule.check("42"), Ok(()));
+        assert_eq!(
+            rule.check(17),
+            Err("Must be between 18 and 120".to_string())
+        );
+        assert!(rule.check("abc").is_err());
+    }
+
+    #[test]
+    fn matches_field_compares_form_values() {
+        let values = [("password", "hunter22")]
+            .into_iter()
+            .collect::<FormValues>();
+        let messages = ValidationMessages::default();
+        let rule = matches_field("password");
+
+        assert_eq!(
+            rule.validate(&FieldValue::from("hunter22"), &values, &messages),
+            Ok(())
+        );
+        assert_eq!(
+            rule.validate(&FieldValue::from("hunter2"), &values, &messages),
+            Err("Must match password".to_string())
+        );
+    }
+
+    #[test]
+    fn patterns() {
+        assert_eq!(pattern(r"^\d+$").check("123"), Ok(()));
+        assert_eq!(
+            pattern(r"^\d+$").check("12a"),
+            Err("Invalid format".to_string())
+        );
+        assert!(try_pattern(r"^(\d+$").is_err());
+        assert!(try_pattern(r"(?=a)").is_err());
+        assert_eq!(try_pattern("^a$").map(|rule| rule.check("a")), Ok(Ok(())));
+    }
+
+    #[test]
+    fn message_templates() {
+        let messages = ValidationMessages {
+            min_len: "Mindestens {min} Zeichen".to_string(),
+            range: "Zwischen {min} und {max}".to_string(),
+            matches_field: "Muss {field} entsprechen".to_string(),
+            ..Default::default()
+        };
+        let values = [("email", "a@b.c")].into_iter().collect::<FormValues>();
+        let value = FieldValue::from("x");
+
+        assert_eq!(
+            min_len(2).validate(&value, &values, &messages),
+            Err("Mindestens 2 Zeichen".to_string())
+        );
+        assert_eq!(
+            range(1.5, 3.0).validate(&FieldValue::from(4), &values, &messages),
+            Err("Zwischen 1.5 und 3".to_string())
+        );
+        assert_eq!(
+            matches_field("email").validate(&value, &values, &messages),
+            Err("Muss email entsprechen".to_string())
+        );
+
+        // A rule's own message wins over the templates
+        assert_eq!(
+            min_len(2)
+                .message("Too short")
+                .validate(&value, &values, &messages),
+            Err("Too short".to_string())
+        );
+    }
+
+    #[test]
+    fn custom_rules() {
+        let rule = custom(|value, _| {
+            if value.to_string().contains('@') {
+                Ok(())
+            } else {
+                Err("Missing @".to_string())
+            }
+        });
+        assert_eq!(rule.check("a@b"), Ok(()));
+        assert_eq!(rule.check("ab"), Err("Missing @".to_string()));
+        assert_eq!(
+            rule.clone().message("Override").check("ab"),
+            Err("Override".to_string())
+        );
+
+        // Custom rules are equal only to clones of themselves
+        assert_eq!(rule, rule.clone());
+        assert_ne!(custom(|_, _| Ok(())), custom(|_, _| Ok(())));
+        assert_eq!(min_len(2), min_len(2));
+    }
+
+    #[test]
+    fn validate_rules_returns_the_first_error() {
+        let rules = [required(), min_len(8), pattern(r"\d")];
+        let check = |text: &str| {
+            validate_rules(
+                &rules,
+                &FieldValue::from(text),
+                &FormValues::default(),
+                &ValidationMessages::default(),
+            )
+        };
+        assert_eq!(check(""), Err("This field is required".to_string()));
+        assert_eq!(
+            check("short"),
+            Err("Must be at least 8 characters".to_string())
+        );
+        assert_eq!(check("longenough"), Err("Invalid format".to_string()));
+        assert_eq!(check("longenough1"), Ok(()));
+    }
+}
@@ -9,15 +9,13 @@ pub mod components;
 fn use_unique_id() -> Signal<String> {
     static NEXT_ID: GlobalSignal<usize> = Signal::global(|| 0);
 
-    let id = *NEXT_ID.peek();
-    let id_str = format!("dxc-{id}");
-
-    // Update the ID counter in an effect to avoid signal writes during rendering
-    use_effect(move || {
+    // Claim the id in the initializer, which runs once per component, so siblings rendered in the
+    // same pass get distinct ids. The counter is only peeked, so bumping it notifies nothing.
+    use_signal(|| {
+        let id = *NEXT_ID.peek();
         *NEXT_ID.write() += 1;
-    });
-
-    use_signal(|| id_str)
+        format!("dxc-{id}")
+    })
 }
 
 // Elements can only have one id so if the user provides their own, we must use it as the aria id.
@@ -2,8 +2,11 @@
 
 pub use basic::BasicCheckboxExample;
 pub use form_integration::FormIntegrationExample;
+pub use group::CheckboxGroupExample;
+pub use indeterminate::IndeterminateCheckboxExample;
 pub use sizes::CheckboxSizesExample;
 pub use states::CheckboxStatesExample;
+pub use uncontrolled::UncontrolledCheckboxExample;
 
 pub mod basic {
     // ANCHOR: basic
@@ -220,6 +223,138 @@ pub mod form_integration {
     // ANCHOR_END: form_integration
 }
 
+pub mod uncontrolled {
+    // ANCHOR: uncontrolled
+    use dioxus::prelude::*;
+    use lumen_blocks::components::checkbox::Checkbox;
+    use lumen_blocks::components::label::Label;
+
+    #[component]
+    pub fn UncontrolledCheckboxExample() -> Element {
+        let mut last_change = use_signal(|| None::<bool>);
+
+        rsx! {
+            div { class: "flex flex-col gap-4",
+                // Clicking the label toggles the checkbox
+                div { class: "flex items-center gap-2",
+                    Checkbox {
+                        id: Some("remember-me".to_string()),
+                        default_checked: true,
+                        on_checked_change: move |state| last_change.set(Some(state)),
+                    }
+                    Label { for_id: Some("remember-me".to_string()), class: "mb-0", "Remember me" }
+                }
+
+                div { class: "flex items-center gap-2",
+                    Checkbox { id: Some("marketing-emails".to_string()) }
+                    Label { for_id: Some("marketing-emails".to_string()), class: "mb-0", "Send me marketing emails" }
+                }
+
+                span { class: "text-xs text-muted-foreground",
+                    match last_change() {
+                        Some(true) => "Remember me was checked",
+                        Some(false) => "Remember me was unchecked",
+                        None => "Tab to a checkbox and press Space to toggle it",
+                    }
+                }
+            }
+        }
+    }
+    // ANCHOR_END: uncontrolled
+}
+
+pub mod indeterminate {
+    // ANCHOR: indeterminate
+    use dioxus::prelude::*;
+    use lumen_blocks::components::checkbox::Checkbox;
+
+    #[component]
+    pub fn IndeterminateCheckboxExample() -> Element {
+        let items = [
+            use_signal(|| true),
+            use_signal(|| false),
+            use_signal(|| false),
+        ];
+        let labels = ["Photos", "Videos", "Documents"];
+
+        // Derive the header state from the items
+        let mut all_checked = use_signal(|| false);
+        use_effect(move || all_checked.set(items.iter().all(|checked| checked())));
+        let some_checked = items.iter().any(|checked| checked());
+
+        rsx! {
+            div { class: "flex flex-col gap-2",
+                div { class: "flex items-center gap-2",
+                    Checkbox {
+                        checked: all_checked,
+                        indeterminate: some_checked && !all_checked(),
+                        on_checked_change: move |state| {
+                            for mut item in items {
+                                item.set(state);
+                            }
+                        },
+                        aria_label: Some(String::from("Select all media")),
+                    }
+                    span { class: "text-sm font-medium", "All media" }
+                }
+
+                for (index, label) in labels.iter().enumerate() {
+                    div { class: "flex items-center gap-2 pl-6",
+                        Checkbox {
+                            checked: items[index],
+                            on_checked_change: move |state| {
+                                let mut item = items[index];
+                                item.set(state);
+                            },
+                            aria_label: Some(label.to_string()),
+                        }
+                        span { class: "text-sm", "{label}" }
+                    }
+                }
+            }
+        }
+    }
+    // ANCHOR_END: indeterminate
+}
+
+pub mod group {
+    // ANCHOR: group
+    use dioxus::prelude::*;
+    use lumen_blocks::components::checkbox::{Checkbox, CheckboxGroup};
+
+    #[component]
+    pub fn CheckboxGroupExample() -> Element {
+        let permissions = use_signal(|| vec!["read".to_string()]);
+
+        rsx! {
+            div { class: "space-y-4",
+                CheckboxGroup {
+                    value: permissions,
+                    name: Some("permissions".to_string()),
+
+                    // Derives its checked and mixed state from the items of the group
+                    div { class: "flex items-center gap-2",
+                        Checkbox { select_all: true, aria_label: Some(String::from("All permissions")) }
+                        span { class: "text-sm font-medium", "All permissions" }
+                    }
+
+                    for (value, label) in [("read", "Read"), ("write", "Write"), ("delete", "Delete"), ("admin", "Administer")] {
+                        div { class: "flex items-center gap-2 pl-6",
+                            Checkbox { value: value.to_string(), aria_label: Some(label.to_string()) }
+                            span { class: "text-sm", "{label}" }
+                        }
+                    }
+                }
+
+                div { class: "p-2 bg-muted rounded text-sm",
+                    code { "Selected: {permissions:?}" }
+                }
+            }
+        }
+    }
+    // ANCHOR_END: group
+}
+
 // This maintains the original example for backward compatibility
 pub mod example {
     use dioxus::prelude::*;
@@ -0,0 +1,108 @@
+#![allow(non_snake_case)]
+
+pub use basic::BasicColorPickerExample;
+pub use swatches::ColorPickerSwatchesExample;
+pub use theme_editor::ThemeEditorExample;
+
+pub mod basic {
+    // ANCHOR: basic
+    use dioxus::prelude::*;
+    use lumen_blocks::components::color::Color;
+    use lumen_blocks::components::color_picker::ColorPicker;
+
+    #[component]
+    pub fn BasicColorPickerExample() -> Element {
+        let color = use_signal(|| Color::from_rgb(59, 130, 246));
+        let css = color().to_css_rgb();
+
+        rsx! {
+            div { class: "flex flex-col gap-3",
+                ColorPicker {
+                    value: color,
+                    aria_label: Some("Accent color".to_string()),
+                }
+                span { class: "text-xs text-muted-foreground", "Selected: {css}" }
+            }
+        }
+    }
+    // ANCHOR_END: basic
+}
+
+pub mod swatches {
+    // ANCHOR: swatches
+    use dioxus::prelude::*;
+    use lumen_blocks::components::color::Color;
+    use lumen_blocks::components::color_picker::{ColorFormat, ColorPicker};
+
+    #[component]
+    pub fn ColorPickerSwatchesExample() -> Element {
+        let color = use_signal(|| Color::from_rgb(34, 197, 94));
+        let swatches = [
+            "#ef4444", "#f97316", "#eab308", "#22c55e", "#06b6d4", "#3b82f6", "#8b5cf6", "#ec4899",
+        ]
+        .iter()
+        .filter_map(|hex| hex.parse::<Color>().ok())
+        .collect::<Vec<_>>();
+
+        rsx! {
+            ColorPicker {
+                value: color,
+                swatches: swatches,
+                format: ColorFormat::Rgb,
+                show_alpha: false,
+                aria_label: Some("Label color".to_string()),
+            }
+        }
+    }
+    // ANCHOR_END: swatches
+}
+
+pub mod theme_editor {
+    // ANCHOR: theme_editor
+    use dioxus::prelude::*;
+    use lumen_blocks::components::button::{Button, ButtonVariant};
+    use lumen_blocks::components::color::Color;
+    use lumen_blocks::components::color_picker::ColorPicker;
+
+    #[component]
+    pub fn ThemeEditorExample() -> Element {
+        let primary = use_signal(|| Color::from_rgb(23, 23, 23));
+
+        // The theme's CSS variables hold space separated channels, e.g. `--primary: 23 23 23`
+        use_effect(move || {
+            let color = primary();
+            let foreground = if color.is_dark() {
+                Color::WHITE
+            } else {
+                Color::BLACK
+            };
+            let script = format!(
+                r#"
+                const root = document.getElementById("theme-preview");
+                if (root) {{
+                    root.style.setProperty("--primary", "{}");
+                    root.style.setProperty("--primary-foreground", "{}");
+                }}
+                "#,
+                color.to_css_variable(),
+                foreground.to_css_variable()
+            );
+            let _ = document::eval(&script);
+        });
+
+        rsx! {
+            div { class: "flex flex-wrap items-start gap-6",
+                ColorPicker {
+                    value: primary,
+                    show_alpha: false,
+                    aria_label: Some("Primary color".to_string()),
+                }
+                div { id: "theme-preview", class: "flex flex-col gap-2",
+                    Button { "Primary button" }
+                    Button { variant: ButtonVariant::Outline, "Outline button" }
+                }
+            }
+        }
+    }
+    // ANCHOR_END: theme_editor
+}
@@ -0,0 +1,263 @@
+#![allow(non_snake_case)]
+
+pub use accordion::SortableAccordionExample;
+pub use basic::BasicSortableExample;
+pub use kanban::KanbanBoardExample;
+pub use multiple::MultipleSortableExample;
+pub use table::SortableTableExample;
+
+pub mod basic {
+    // ANCHOR: basic
+    use dioxus::prelude::*;
+    use lumen_blocks::components::dnd::{Sortable, SortableItem, reorder};
+
+    #[component]
+    pub fn BasicSortableExample() -> Element {
+        let mut fruits = use_signal(|| {
+            vec!["Apples", "Bananas", "Cherries", "Dates", "Elderberries"]
+                .into_iter()
+                .map(String::from)
+                .collect::<Vec<_>>()
+        });
+
+        rsx! {
+            div { class: "w-64",
+                Sortable {
+                    aria_label: Some("Favorite fruits".to_string()),
+                    on_reorder: move |(from, to): (usize, usize)| reorder(&mut fruits.write(), from, to),
+
+                    for (index, fruit) in fruits().into_iter().enumerate() {
+                        SortableItem {
+                            key: "{fruit}",
+                            index: index,
+                            label: Some(fruit.clone()),
+                            class: "border border-border bg-background px-3 py-2 text-sm",
+                            "{fruit}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+    // ANCHOR_END: basic
+}
+
+pub mod multiple {
+    // ANCHOR: multiple
+    use dioxus::prelude::*;
+    use lumen_blocks::components::dnd::{Sortable, SortableItem, SortableOrientation, reorder};
+
+    #[component]
+    pub fn MultipleSortableExample() -> Element {
+        let mut priorities = use_signal(|| {
+            vec!["Performance", "Accessibility", "Design"]
+                .into_iter()
+                .map(String::from)
+                .collect::<Vec<_>>()
+        });
+        let mut tags = use_signal(|| {
+            vec!["rust", "web", "ui"]
+                .into_iter()
+                .map(String::from)
+                .collect::<Vec<_>>()
+        });
+
+        rsx! {
+            div { class: "flex flex-col gap-6",
+                div { class: "w-64",
+                    Sortable {
+                        aria_label: Some("Priorities".to_string()),
+                        on_reorder: move |(from, to): (usize, usize)| reorder(&mut priorities.write(), from, to),
+
+                        for (index, priority) in priorities().into_iter().enumerate() {
+                            SortableItem {
+                                key: "{priority}",
+                                index: index,
+                                label: Some(priority.clone()),
+                                class: "border border-border bg-background px-3 py-2 text-sm",
+                                "{priority}"
+                            }
+                        }
+                    }
+                }
+
+                // Each list keeps its own items, so moves never cross between them
+                Sortable {
+                    orientation: SortableOrientation::Horizontal,
+                    aria_label: Some("Tags".to_string()),
+                    on_reorder: move |(from, to): (usize, usize)| reorder(&mut tags.write(), from, to),
+
+                    for (index, tag) in tags().into_iter().enumerate() {
+                        SortableItem {
+                            key: "{tag}",
+                            index: index,
+                            label: Some(tag.clone()),
+                            class: "rounded-full border border-border bg-background px-3 py-1 text-sm",
+                            "{tag}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+    // ANCHOR_END: multiple
+}
+
+pub mod table {
+    // ANCHOR: table
+    use dioxus::prelude::*;
+    use lumen_blocks::components::dnd::{SortableHandle, SortableRow, SortableTableBody, reorder};
+
+    #[derive(Clone, PartialEq)]
+    struct Track {
+        title: String,
+        artist: String,
+        length: String,
+    }
+
+    #[component]
+    pub fn SortableTableExample() -> Element {
+        let mut tracks = use_signal(|| {
+            [
+                ("Intro", "The Band", "1:12"),
+                ("Morning Light", "Aurora Lane", "3:45"),
+                ("Night Drive", "The Band", "4:20"),
+                ("Outro", "Aurora Lane", "2:03"),
+            ]
+            .into_iter()
+            .map(|(title, artist, length)| Track {
+                title: title.to_string(),
+                artist: artist.to_string(),
+                length: length.to_string(),
+            })
+            .collect::<Vec<_>>()
+        });
+
+        rsx! {
+            table { class: "w-full max-w-lg text-sm",
+                thead {
+                    tr { class: "border-b border-border text-left text-muted-foreground",
+                        th { class: "w-8" }
+                        th { class: "py-2 font-medium", "Title" }
+                        th { class: "py-2 font-medium", "Artist" }
+                        th { class: "py-2 text-right font-medium", "Length" }
+                    }
+                }
+                SortableTableBody {
+                    on_reorder: move |(from, to): (usize, usize)| reorder(&mut tracks.write(), from, to),
+
+                    for (index, track) in tracks().into_iter().enumerate() {
+                        {
+                            let Track { title, artist, length } = track;
+                            rsx! {
+                                SortableRow {
+                                    key: "{title}",
+                                    index: index,
+                                    label: Some(title.clone()),
+                                    handle: true,
+                                    class: "border-b border-border bg-background",
+                                    td { class: "py-2", SortableHandle {} }
+                                    td { class: "py-2", "{title}" }
+                                    td { class: "py-2 text-muted-foreground", "{artist}" }
+                                    td { class: "py-2 text-right tabular-nums", "{length}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    // ANCHOR_END: table
+}
+
+pub mod accordion {
+    // ANCHOR: accordion
+    use dioxus::prelude::*;
+    use lumen_blocks::components::accordion::{
+        Accordion, AccordionContent, AccordionItem, AccordionTrigger,
+    };
+    use lumen_blocks::components::dnd::{Sortable, SortableHandle, SortableItem, reorder};
+
+    #[component]
+    pub fn SortableAccordionExample() -> Element {
+        let mut sections = use_signal(|| {
+            vec![
+                ("Shipping", "Orders ship within two business days."),
+                ("Returns", "Items can be returned within 30 days."),
+                ("Warranty", "Every product comes with a two-year warranty."),
+            ]
+        });
+
+        rsx! {
+            div { class: "w-full max-w-md",
+                Accordion {
+                    Sortable {
+                        class: "gap-0",
+                        aria_label: Some("FAQ sections".to_string()),
+                        on_reorder: move |(from, to): (usize, usize)| reorder(&mut sections.write(), from, to),
+
+                        for (index, (title, body)) in sections().into_iter().enumerate() {
+                            SortableItem {
+                                key: "{title}",
+                                index: index,
+                                label: Some(title.to_string()),
+                                handle: true,
+                                class: "flex items-start gap-2 bg-background",
+                                SortableHandle { class: "mt-3" }
+                                AccordionItem { index: index, class: "flex-1",
+                                    AccordionTrigger { "{title}" }
+                                    AccordionContent {
+                                        p { "{body}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    // ANCHOR_END: accordion
+}
+
+pub mod kanban {
+    // ANCHOR: kanban
+    use dioxus::prelude::*;
+    use lumen_blocks::components::dnd::{
+        DndPosition, KanbanBoard, KanbanCard, KanbanColumn, move_item,
+    };
+
+    #[component]
+    pub fn KanbanBoardExample() -> Element {
+        let titles = ["To do", "In progress", "Done"];
+        let mut columns = use_signal(|| {
+            vec![
+                vec!["Write release notes", "Update screenshots", "Triage issues"],
+                vec!["Fix flaky upload test"],
+                vec!["Set up CI", "Add dark mode"],
+            ]
+        });
+
+        rsx! {
+            KanbanBoard {
+                aria_label: Some("Release board".to_string()),
+                on_reorder: move |(from, to): (DndPosition, DndPosition)| move_item(&mut columns.write(), from, to),
+
+                for (column, title) in titles.into_iter().enumerate() {
+                    KanbanColumn { key: "{title}", index: column, title: title.to_string(),
+                        for (index, task) in columns()[column].clone().into_iter().enumerate() {
+                            KanbanCard {
+                                key: "{task}",
+                                index: index,
+                                label: Some(task.to_string()),
+                                "{task}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    // ANCHOR_END: kanban
+}
@@ -0,0 +1,91 @@
+#![allow(non_snake_case)]
+
+pub use basic::BasicFileDropzoneExample;
+pub use images::ImageFileDropzoneExample;
+pub use upload_progress::UploadProgressFileDropzoneExample;
+
+pub mod basic {
+    // ANCHOR: basic
+    use dioxus::prelude::*;
+    use lumen_blocks::components::file_dropzone::FileDropzone;
+
+    #[component]
+    pub fn BasicFileDropzoneExample() -> Element {
+        rsx! {
+            FileDropzone {
+                aria_label: Some("Attachments".to_string()),
+            }
+        }
+    }
+    // ANCHOR_END: basic
+}
+
+pub mod images {
+    // ANCHOR: images
+    use dioxus::prelude::*;
+    use lumen_blocks::components::file_dropzone::{FileDropzone, FileRejection};
+
+    #[component]
+    pub fn ImageFileDropzoneExample() -> Element {
+        let mut rejected = use_signal(|| 0usize);
+        let rejected_count = rejected();
+
+        rsx! {
+            div { class: "flex flex-col gap-2",
+                FileDropzone {
+                    accept: "image/*".to_string(),
+                    max_size: Some(2 * 1024 * 1024),
+                    max_files: Some(4),
+                    label: "Drop up to 4 photos".to_string(),
+                    aria_label: Some("Photos".to_string()),
+                    on_reject: move |rejections: Vec<FileRejection>| {
+                        rejected += rejections.len();
+                    },
+                }
+                span { class: "text-xs text-muted-foreground",
+                    "Files rejected so far: {rejected_count}"
+                }
+            }
+        }
+    }
+    // ANCHOR_END: images
+}
+
+pub mod upload_progress {
+    // ANCHOR: upload_progress
+    use dioxus::prelude::*;
+    use dioxus_time::sleep;
+    use lumen_blocks::components::file_dropzone::{DroppedFile, FileDropzone};
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    #[component]
+    pub fn UploadProgressFileDropzoneExample() -> Element {
+        let files = use_signal(Vec::<DroppedFile>::new);
+        let mut progress = use_signal(HashMap::<usize, f64>::new);
+
+        rsx! {
+            FileDropzone {
+                files: files,
+                progress: progress,
+                aria_label: Some("Documents".to_string()),
+                on_add: move |added: Vec<DroppedFile>| {
+                    for file in added {
+                        spawn(async move {
+                            // Read the file, then simulate sending it to a server
+                            let _bytes = file.read_bytes().await;
+                            for step in 0..=10 {
+                                progress.write().insert(file.id(), step as f64 * 10.0);
+                                sleep(Duration::from_millis(200)).await;
+                            }
+                        });
+                    }
+                },
+                on_remove: move |file: DroppedFile| {
+                    progress.write().remove(&file.id());
+                },
+            }
+        }
+    }
+    // ANCHOR_END: upload_progress
+}
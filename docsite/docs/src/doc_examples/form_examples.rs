@@ -2,10 +2,14 @@
 
 pub use basic::BasicInputExample;
 pub use complete::CompleteFormExample;
+pub use form_field::FormFieldExample;
+pub use form_state::UseFormExample;
 pub use icons::InputWithIconsExample;
 pub use label_sizes::LabelSizesExample;
 pub use labels::LabelExample;
 pub use required::RequiredFieldExample;
+pub use rules::ValidationRulesExample;
+pub use schema::SchemaFormExample;
 pub use sizes::InputSizesExample;
 pub use states::InputStatesExample;
 pub use validation::FormValidationExample;
@@ -296,19 +300,14 @@ pub mod required {
 pub mod validation {
     // ANCHOR: validation
     use dioxus::prelude::*;
-    use lucide_dioxus::CircleAlert;
-    use lumen_blocks::components::input::{Input, InputVariant};
+    use lumen_blocks::components::form::ValidationMode;
+    use lumen_blocks::components::input::Input;
     use lumen_blocks::components::label::Label;
+    use lumen_blocks::components::validation::{email, required};
 
     #[component]
     pub fn FormValidationExample() -> Element {
-        let mut email = use_signal(|| String::new());
-        let mut has_error = use_signal(|| false);
-
-        let validate_email = move |evt: FormEvent| {
-            email.set(evt.value().clone());
-            has_error.set(!evt.value().contains('@'));
-        };
+        let mut email_value = use_signal(|| String::new());
 
         rsx! {
             div { class: "w-full max-w-md",
@@ -318,22 +317,16 @@ pub mod validation {
                         "Email Address"
                     }
 
+                    // The input switches to the error variant and shows the message when a rule fails
                     Input {
                         id: Some("email-input".to_string()),
                         input_type: "email".to_string(),
                         placeholder: "Enter your email".to_string(),
                         full_width: true,
-                        variant: if has_error() { InputVariant::Error } else { InputVariant::Default },
-                        value: email,
-                        on_change: validate_email
-                    }
-
-                    if has_error() {
-                        div {
-                            class: "text-destructive text-xs flex items-center mt-1",
-                            CircleAlert { size: 14, class: "mr-1 text-destructive" }
-                            "Please enter a valid email address"
-                        }
+                        value: email_value,
+                        on_change: move |evt: FormEvent| email_value.set(evt.value().clone()),
+                        rules: vec![required(), email()],
+                        validation_mode: ValidationMode::OnChange,
                     }
                 }
             }
@@ -481,3 +474,389 @@ pub mod complete {
     }
     // ANCHOR_END: complete
 }
+
+pub mod form_state {
+    // ANCHOR: form_state
+    use dioxus::prelude::*;
+    use dioxus_time::sleep;
+    use lumen_blocks::components::button::{Button, ButtonVariant};
+    use lumen_blocks::components::form::{FieldConfig, FieldError, Form, FormValues, use_form};
+    use lumen_blocks::components::input::Input;
+    use lumen_blocks::components::label::Label;
+    use std::time::Duration;
+
+    #[component]
+    pub fn UseFormExample() -> Element {
+        let mut submitted = use_signal(|| None::<FormValues>);
+
+        let form = use_form(|| {
+            vec![
+                FieldConfig::new("username", "")
+                    .validate(|value, _| {
+                        if value.is_empty() {
+                            Err("Username is required".to_string())
+                        } else {
+                            Ok(())
+                        }
+                    })
+                    // Simulate a server-side availability check
+                    .validate_async(|value| async move {
+                        sleep(Duration::from_millis(500)).await;
+                        if value.to_string() == "admin" {
+                            Err("This username is already taken".to_string())
+                        } else {
+                            Ok(())
+                        }
+                    }),
+                FieldConfig::new("age", 18).validate(|value, _| {
+                    match f64::try_from(value.clone()) {
+                        Ok(age) if age >= 13.0 => Ok(()),
+                        Ok(_) => Err("You must be at least 13 years old".to_string()),
+                        Err(_) => Err("Please enter a number".to_string()),
+                    }
+                }),
+            ]
+        });
+
+        rsx! {
+            div { class: "w-full max-w-md p-4 border border-border rounded-md bg-card",
+                Form {
+                    form: form,
+                    on_submit: move |values| submitted.set(Some(values)),
+
+                    div { class: "space-y-1",
+                        Label { for_id: Some("form-username".to_string()), required: true, "Username" }
+                        // Inputs are bound to the field with the same name
+                        Input {
+                            id: Some("form-username".to_string()),
+                            name: "username".to_string(),
+                            placeholder: "Try \"admin\"".to_string(),
+                            full_width: true,
+                        }
+                        if form.is_validating("username") {
+                            div { class: "text-muted-foreground text-xs mt-1", "Checking availability..." }
+                        }
+                        FieldError { name: "username".to_string() }
+                    }
+
+                    div { class: "space-y-1",
+                        Label { for_id: Some("form-age".to_string()), "Age" }
+                        Input {
+                            id: Some("form-age".to_string()),
+                            name: "age".to_string(),
+                            input_type: "number".to_string(),
+                            full_width: true,
+                        }
+                        FieldError { name: "age".to_string() }
+                    }
+
+                    div { class: "flex gap-2",
+                        Button {
+                            variant: ButtonVariant::Primary,
+                            button_type: "submit".to_string(),
+                            loading: form.is_submitting(),
+                            "Create Account"
+                        }
+                        Button {
+                            variant: ButtonVariant::Outline,
+                            button_type: "reset".to_string(),
+                            disabled: !form.is_dirty(),
+                            on_click: move |_| submitted.set(None),
+                            "Reset"
+                        }
+                    }
+
+                    if let Some(username) = submitted().map(|values| values.text("username")) {
+                        div {
+                            class: "mt-2 p-2 bg-green-50 text-green-700 text-sm rounded",
+                            "Welcome, {username}!"
+                        }
+                    }
+                }
+            }
+        }
+    }
+    // ANCHOR_END: form_state
+}
+
+pub mod form_field {
+    // ANCHOR: form_field
+    use dioxus::prelude::*;
+    use lumen_blocks::components::button::{Button, ButtonVariant};
+    use lumen_blocks::components::checkbox::Checkbox;
+    use lumen_blocks::components::form::{
+        FieldConfig, Form, FormControl, FormDescription, FormField, FormLabel, FormMessage,
+        use_form,
+    };
+    use lumen_blocks::components::input::Input;
+    use lumen_blocks::components::switch::Switch;
+
+    #[component]
+    pub fn FormFieldExample() -> Element {
+        let mut accepted = use_signal(|| false);
+        let mut newsletter = use_signal(|| true);
+        let mut attempted = use_signal(|| false);
+
+        let form = use_form(|| {
+            vec![FieldConfig::new("email", "").validate(|value, _| {
+                if value.to_string().contains('@') {
+                    Ok(())
+                } else {
+                    Err("Please enter a valid email address".to_string())
+                }
+            })]
+        });
+
+        rsx! {
+            div { class: "w-full max-w-md p-4 border border-border rounded-md bg-card",
+                Form {
+                    form: form,
+                    on_submit: move |_| attempted.set(true),
+                    on_invalid: move |_| attempted.set(true),
+
+                    // Ids, aria-describedby and aria-invalid are wired automatically
+                    FormField { name: Some("email".to_string()), required: true,
+                        FormLabel { "Email Address" }
+                        FormControl {
+                            Input { placeholder: "you@example.com".to_string(), full_width: true }
+                        }
+                        FormDescription { "We'll never share your email." }
+                        FormMessage {}
+                    }
+
+                    FormField {
+                        required: true,
+                        error: if attempted() && !accepted() {
+                            Some("You must accept the terms".to_string())
+                        } else {
+                            None
+                        },
+                        div { class: "flex items-center gap-2",
+                            FormControl {
+                                Checkbox {
+                                    checked: accepted,
+                                    on_checked_change: move |state| accepted.set(state),
+                                }
+                            }
+                            FormLabel { class: "mb-0", "Accept terms and conditions" }
+                        }
+                        FormMessage {}
+                    }
+
+                    FormField {
+                        div { class: "flex items-center justify-between",
+                            FormLabel { class: "mb-0", "Newsletter" }
+                            FormControl {
+                                Switch {
+                                    checked: newsletter,
+                                    on_checked_change: move |state| newsletter.set(state),
+                                }
+                            }
+                        }
+                        FormDescription { "Receive product updates once a month." }
+                    }
+
+                    Button {
+                        variant: ButtonVariant::Primary,
+                        button_type: "submit".to_string(),
+                        full_width: true,
+                        "Subscribe"
+                    }
+                }
+            }
+        }
+    }
+    // ANCHOR_END: form_field
+}
+
+pub mod rules {
+    // ANCHOR: rules
+    use dioxus::prelude::*;
+    use lumen_blocks::components::button::{Button, ButtonVariant};
+    use lumen_blocks::components::form::{
+        FieldConfig, Form, FormControl, FormField, FormLabel, FormMessage, use_form,
+    };
+    use lumen_blocks::components::input::Input;
+    use lumen_blocks::components::validation::{
+        ValidationMessages, custom, matches_field, max_len, min_len, pattern, range, required, url,
+    };
+
+    #[component]
+    pub fn ValidationRulesExample() -> Element {
+        // Localized messages apply to every rule in the forms below
+        use_context_provider(|| ValidationMessages {
+            required: "Este campo es obligatorio".to_string(),
+            min_len: "Debe tener al menos {min} caracteres".to_string(),
+            matches_field: "Debe coincidir con {field}".to_string(),
+            ..Default::default()
+        });
+
+        rsx! {
+            RulesForm {}
+        }
+    }
+
+    #[component]
+    fn RulesForm() -> Element {
+        let mut saved = use_signal(|| false);
+
+        let form = use_form(|| {
+            vec![
+                FieldConfig::new("handle", "").rules([
+                    required(),
+                    min_len(3),
+                    max_len(20),
+                    pattern(r"^[a-z0-9_]+$").message("Use lowercase letters, numbers and _"),
+                ]),
+                FieldConfig::new("website", "").rule(url()),
+                FieldConfig::new("age", 30).rules([required(), range(18.0, 120.0)]),
+                FieldConfig::new("password", "").rules([
+                    required(),
+                    min_len(8),
+                    custom(|value, _| {
+                        if value.to_string().chars().any(|c| c.is_ascii_digit()) {
+                            Ok(())
+                        } else {
+                            Err("Include at least one number".to_string())
+                        }
+                    }),
+                ]),
+                FieldConfig::new("confirm", "").rules([required(), matches_field("password")]),
+            ]
+        });
+
+        rsx! {
+            div { class: "w-full max-w-md p-4 border border-border rounded-md bg-card",
+                Form {
+                    form: form,
+                    on_submit: move |_| saved.set(true),
+
+                    FormField { name: Some("handle".to_string()), required: true,
+                        FormLabel { "Handle" }
+                        FormControl { Input { full_width: true } }
+                        FormMessage {}
+                    }
+
+                    FormField { name: Some("website".to_string()),
+                        FormLabel { "Website" }
+                        FormControl { Input { input_type: "url".to_string(), full_width: true } }
+                        FormMessage {}
+                    }
+
+                    FormField { name: Some("age".to_string()), required: true,
+                        FormLabel { "Age" }
+                        FormControl { Input { input_type: "number".to_string(), full_width: true } }
+                        FormMessage {}
+                    }
+
+                    FormField { name: Some("password".to_string()), required: true,
+                        FormLabel { "Password" }
+                        FormControl { Input { input_type: "password".to_string(), full_width: true } }
+                        FormMessage {}
+                    }
+
+                    FormField { name: Some("confirm".to_string()), required: true,
+                        FormLabel { "Confirm Password" }
+                        FormControl { Input { input_type: "password".to_string(), full_width: true } }
+                        FormMessage {}
+                    }
+
+                    Button {
+                        variant: ButtonVariant::Primary,
+                        button_type: "submit".to_string(),
+                        full_width: true,
+                        "Save Profile"
+                    }
+
+                    if saved() {
+                        div {
+                            class: "mt-2 p-2 bg-green-50 text-green-700 text-sm rounded",
+                            "Profile saved!"
+                        }
+                    }
+                }
+            }
+        }
+    }
+    // ANCHOR_END: rules
+}
+
+pub mod schema {
+    // ANCHOR: schema
+    use dioxus::prelude::*;
+    use lumen_blocks::components::schema_form::SchemaForm;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    #[derive(Deserialize, Debug)]
+    struct Settings {
+        display_name: String,
+        email: String,
+        theme: String,
+        items_per_page: u32,
+        notifications: bool,
+        beta_features: bool,
+    }
+
+    #[component]
+    pub fn SchemaFormExample() -> Element {
+        let mut saved = use_signal(|| None::<String>);
+
+        let schema = json!({
+            "type": "object",
+            "order": ["display_name", "email", "theme", "items_per_page", "notifications", "beta_features"],
+            "required": ["display_name", "email"],
+            "properties": {
+                "display_name": { "type": "string", "title": "Display Name", "minLength": 2 },
+                "email": { "type": "string", "format": "email", "title": "Email" },
+                "theme": {
+                    "type": "string",
+                    "title": "Theme",
+                    "enum": ["light", "dark", "system"],
+                    "enumNames": ["Light", "Dark", "System"],
+                    "default": "system"
+                },
+                "items_per_page": {
+                    "type": "integer",
+                    "title": "Items per Page",
+                    "minimum": 10,
+                    "maximum": 100,
+                    "default": 25
+                },
+                "notifications": {
+                    "type": "boolean",
+                    "title": "Email Notifications",
+                    "widget": "switch",
+                    "default": true
+                },
+                "beta_features": {
+                    "type": "boolean",
+                    "title": "Enable beta features",
+                    "description": "Beta features may change without notice."
+                }
+            }
+        });
+
+        rsx! {
+            div { class: "w-full max-w-md p-4 border border-border rounded-md bg-card",
+                SchemaForm {
+                    schema: schema,
+                    submit_label: "Save Settings".to_string(),
+                    show_reset: true,
+                    on_submit: move |values| {
+                        // Deserialize the submitted JSON into a typed struct
+                        match serde_json::from_value::<Settings>(values) {
+                            Ok(settings) => saved.set(Some(format!("{settings:?}"))),
+                            Err(error) => saved.set(Some(error.to_string())),
+                        }
+                    },
+                }
+
+                if let Some(settings) = saved() {
+                    pre { class: "mt-4 p-2 bg-muted text-xs rounded whitespace-pre-wrap", "{settings}" }
+                }
+            }
+        }
+    }
+    // ANCHOR_END: schema
+}
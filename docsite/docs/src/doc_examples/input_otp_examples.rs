@@ -0,0 +1,100 @@
+#![allow(non_snake_case)]
+
+pub use alphanumeric::AlphanumericInputOTPExample;
+pub use basic::BasicInputOTPExample;
+pub use verification::InputOTPVerificationExample;
+
+pub mod basic {
+    // ANCHOR: basic
+    use dioxus::prelude::*;
+    use lumen_blocks::components::input_otp::InputOTP;
+
+    #[component]
+    pub fn BasicInputOTPExample() -> Element {
+        let code = use_signal(String::new);
+
+        rsx! {
+            div { class: "flex flex-col gap-2",
+                InputOTP {
+                    value: code,
+                    length: 6,
+                    groups: vec![3, 3],
+                    aria_label: Some("One-time code".to_string()),
+                }
+                span { class: "text-xs text-muted-foreground",
+                    if code().is_empty() { "Enter your one-time code." } else { "Code: {code}" }
+                }
+            }
+        }
+    }
+    // ANCHOR_END: basic
+}
+
+pub mod alphanumeric {
+    // ANCHOR: alphanumeric
+    use dioxus::prelude::*;
+    use lumen_blocks::components::input::InputSize;
+    use lumen_blocks::components::input_otp::{InputOTP, OtpPattern};
+
+    #[component]
+    pub fn AlphanumericInputOTPExample() -> Element {
+        rsx! {
+            InputOTP {
+                length: 8,
+                groups: vec![4, 4],
+                pattern: OtpPattern::Alphanumeric,
+                size: InputSize::Small,
+                aria_label: Some("Recovery code".to_string()),
+            }
+        }
+    }
+    // ANCHOR_END: alphanumeric
+}
+
+pub mod verification {
+    // ANCHOR: verification
+    use dioxus::prelude::*;
+    use dioxus_time::sleep;
+    use lumen_blocks::components::input::InputVariant;
+    use lumen_blocks::components::input_otp::InputOTP;
+    use std::time::Duration;
+
+    #[component]
+    pub fn InputOTPVerificationExample() -> Element {
+        let mut code = use_signal(String::new);
+        let mut status = use_signal(|| None::<bool>);
+        let mut verifying = use_signal(|| false);
+
+        rsx! {
+            div { class: "flex flex-col gap-2",
+                InputOTP {
+                    value: code,
+                    disabled: verifying(),
+                    variant: if status() == Some(false) { InputVariant::Error } else { InputVariant::Default },
+                    aria_label: Some("Verification code".to_string()),
+                    on_value_change: move |_| status.set(None),
+                    on_complete: move |entered: String| async move {
+                        // Simulate checking the code with a server
+                        verifying.set(true);
+                        sleep(Duration::from_millis(800)).await;
+                        verifying.set(false);
+                        let valid = entered == "123456";
+                        status.set(Some(valid));
+                        if !valid {
+                            code.set(String::new());
+                        }
+                    },
+                }
+                span { class: "text-xs text-muted-foreground",
+                    match (verifying(), status()) {
+                        (true, _) => "Verifying...",
+                        (_, Some(true)) => "Your email address is verified.",
+                        (_, Some(false)) => "That code is not valid. Try 123456.",
+                        _ => "We sent a 6-digit code to your email address.",
+                    }
+                }
+            }
+        }
+    }
+    // ANCHOR_END: verification
+}
@@ -0,0 +1,104 @@
+#![allow(non_snake_case)]
+
+pub use basic::BasicMaskedInputExample;
+pub use card::CardMaskedInputExample;
+pub use custom_tokens::CustomTokensMaskedInputExample;
+
+pub mod basic {
+    // ANCHOR: basic
+    use dioxus::prelude::*;
+    use lumen_blocks::components::label::Label;
+    use lumen_blocks::components::mask::Mask;
+    use lumen_blocks::components::masked_input::{MaskedInput, MaskedValue};
+
+    #[component]
+    pub fn BasicMaskedInputExample() -> Element {
+        let mut phone = use_signal(MaskedValue::default);
+        let MaskedValue { raw, formatted, .. } = phone();
+
+        rsx! {
+            div { class: "flex flex-col gap-2",
+                Label { for_id: Some("phone".to_string()), "Phone number" }
+                MaskedInput {
+                    id: Some("phone".to_string()),
+                    mask: Mask::new("(999) 999-9999"),
+                    on_value_change: move |value| phone.set(value),
+                    full_width: true,
+                }
+                span { class: "text-xs text-muted-foreground",
+                    "Raw: {raw} · Formatted: {formatted}"
+                }
+            }
+        }
+    }
+    // ANCHOR_END: basic
+}
+
+pub mod card {
+    // ANCHOR: card
+    use dioxus::prelude::*;
+    use lucide_dioxus::CreditCard;
+    use lumen_blocks::components::label::Label;
+    use lumen_blocks::components::mask::Mask;
+    use lumen_blocks::components::masked_input::MaskedInput;
+
+    #[component]
+    pub fn CardMaskedInputExample() -> Element {
+        let card_number = use_signal(String::new);
+        let expiry = use_signal(String::new);
+
+        rsx! {
+            div { class: "grid grid-cols-3 gap-4",
+                div { class: "col-span-2 flex flex-col",
+                    Label { for_id: Some("card-number".to_string()), "Card number" }
+                    MaskedInput {
+                        id: Some("card-number".to_string()),
+                        mask: Mask::new("9999 9999 9999 9999"),
+                        value: card_number,
+                        placeholder: "1234 5678 9012 3456".to_string(),
+                        icon_left: rsx! { CreditCard { size: 16 } },
+                        full_width: true,
+                    }
+                }
+                div { class: "flex flex-col",
+                    Label { for_id: Some("card-expiry".to_string()), "Expiry" }
+                    MaskedInput {
+                        id: Some("card-expiry".to_string()),
+                        mask: Mask::new("99/99"),
+                        value: expiry,
+                        placeholder: "MM/YY".to_string(),
+                        full_width: true,
+                    }
+                }
+            }
+        }
+    }
+    // ANCHOR_END: card
+}
+
+pub mod custom_tokens {
+    // ANCHOR: custom_tokens
+    use dioxus::prelude::*;
+    use lumen_blocks::components::label::Label;
+    use lumen_blocks::components::mask::Mask;
+    use lumen_blocks::components::masked_input::MaskedInput;
+
+    #[component]
+    pub fn CustomTokensMaskedInputExample() -> Element {
+        // `h` accepts a hexadecimal digit, and `\#` keeps the `#` literal. The mask is built once,
+        // since custom tokens only compare equal to themselves.
+        let mask = use_hook(|| Mask::new("\\#hhhhhh").token('h', |c| c.is_ascii_hexdigit()));
+
+        rsx! {
+            div { class: "flex flex-col gap-2",
+                Label { for_id: Some("brand-color".to_string()), "Brand color" }
+                MaskedInput {
+                    id: Some("brand-color".to_string()),
+                    mask: mask,
+                    default_value: "3b82f6".to_string(),
+                }
+            }
+        }
+    }
+    // ANCHOR_END: custom_tokens
+}
@@ -4,12 +4,24 @@ pub mod avatar_examples;
 pub mod button_examples;
 pub mod checkbox_examples;
 pub mod collapsible_examples;
+pub mod color_picker_examples;
 pub mod context_menu_examples;
+pub mod dnd_examples;
 pub mod dropdown_examples;
+pub mod file_dropzone_examples;
 pub mod form_examples;
 pub mod hover_card_examples;
+pub mod input_otp_examples;
+pub mod masked_input_examples;
 pub mod menubar_examples;
+pub mod number_input_examples;
+pub mod password_input_examples;
 pub mod progress_examples;
+pub mod rating_examples;
 pub mod side_sheet_examples;
+pub mod stepper_examples;
 pub mod switch_examples;
+pub mod tag_input_examples;
+pub mod timeline_examples;
 pub mod toast_examples;
+pub mod tree_view_examples;
@@ -0,0 +1,116 @@
+#![allow(non_snake_case)]
+
+pub use basic::BasicNumberInputExample;
+pub use formatting::NumberInputFormattingExample;
+pub use integer::IntegerNumberInputExample;
+
+pub mod basic {
+    // ANCHOR: basic
+    use dioxus::prelude::*;
+    use lumen_blocks::components::label::Label;
+    use lumen_blocks::components::number_input::NumberInput;
+
+    #[component]
+    pub fn BasicNumberInputExample() -> Element {
+        let weight = use_signal(|| Some(2.5));
+
+        rsx! {
+            div { class: "flex flex-col gap-2",
+                Label { for_id: Some("weight".to_string()), "Weight (kg)" }
+                NumberInput {
+                    id: Some("weight".to_string()),
+                    value: weight,
+                    min: 0.0,
+                    max: 50.0,
+                    step: 0.5,
+                }
+                span { class: "text-xs text-muted-foreground",
+                    match weight() {
+                        Some(weight) => format!("Value: {weight}"),
+                        None => "Value: empty".to_string(),
+                    }
+                }
+            }
+        }
+    }
+    // ANCHOR_END: basic
+}
+
+pub mod formatting {
+    // ANCHOR: formatting
+    use dioxus::prelude::*;
+    use lumen_blocks::components::label::Label;
+    use lumen_blocks::components::number_input::{NumberFormat, NumberInput};
+
+    #[component]
+    pub fn NumberInputFormattingExample() -> Element {
+        rsx! {
+            div { class: "grid grid-cols-1 gap-4 sm:grid-cols-3",
+                div { class: "flex flex-col",
+                    Label { for_id: Some("price-usd".to_string()), "Price (en-US)" }
+                    NumberInput {
+                        id: Some("price-usd".to_string()),
+                        default_value: 1249.5,
+                        min: 0.0,
+                        format: NumberFormat::Currency("USD".to_string()),
+                        show_steppers: false,
+                    }
+                }
+                div { class: "flex flex-col",
+                    Label { for_id: Some("price-eur".to_string()), "Preis (de-DE)" }
+                    NumberInput {
+                        id: Some("price-eur".to_string()),
+                        default_value: 1249.5,
+                        min: 0.0,
+                        format: NumberFormat::Currency("EUR".to_string()),
+                        locale: "de-DE".to_string(),
+                        show_steppers: false,
+                    }
+                }
+                div { class: "flex flex-col",
+                    Label { for_id: Some("discount".to_string()), "Discount" }
+                    NumberInput {
+                        id: Some("discount".to_string()),
+                        default_value: 15.0,
+                        min: 0.0,
+                        max: 100.0,
+                        step: 5.0,
+                        format: NumberFormat::Percent,
+                    }
+                }
+            }
+        }
+    }
+    // ANCHOR_END: formatting
+}
+
+pub mod integer {
+    // ANCHOR: integer
+    use dioxus::prelude::*;
+    use lumen_blocks::components::label::Label;
+    use lumen_blocks::components::number_input::NumberInput;
+
+    #[component]
+    pub fn IntegerNumberInputExample() -> Element {
+        let mut seats = use_signal(|| 5_i64);
+        let monthly_cost = seats() * 12;
+
+        rsx! {
+            div { class: "flex flex-col gap-2",
+                Label { for_id: Some("seats".to_string()), "Seats" }
+                NumberInput {
+                    id: Some("seats".to_string()),
+                    default_value: 5.0,
+                    min: 1.0,
+                    max: 10000.0,
+                    page_step: 100.0,
+                    on_integer_change: move |value: Option<i64>| seats.set(value.unwrap_or(1)),
+                }
+                span { class: "text-xs text-muted-foreground",
+                    "Monthly cost: ${monthly_cost}"
+                }
+            }
+        }
+    }
+    // ANCHOR_END: integer
+}
@@ -0,0 +1,87 @@
+#![allow(non_snake_case)]
+
+pub use basic::BasicPasswordInputExample;
+pub use custom_score::CustomScorePasswordInputExample;
+pub use strength::PasswordStrengthExample;
+
+pub mod basic {
+    // ANCHOR: basic
+    use dioxus::prelude::*;
+    use lumen_blocks::components::label::Label;
+    use lumen_blocks::components::password_input::PasswordInput;
+
+    #[component]
+    pub fn BasicPasswordInputExample() -> Element {
+        rsx! {
+            div { class: "flex flex-col gap-2",
+                Label { for_id: Some("login-password".to_string()), "Password" }
+                PasswordInput {
+                    id: Some("login-password".to_string()),
+                    placeholder: "Enter your password".to_string(),
+                    full_width: true,
+                }
+            }
+        }
+    }
+    // ANCHOR_END: basic
+}
+
+pub mod strength {
+    // ANCHOR: strength
+    use dioxus::prelude::*;
+    use lumen_blocks::components::label::Label;
+    use lumen_blocks::components::password_input::{PasswordInput, PasswordRequirement};
+
+    #[component]
+    pub fn PasswordStrengthExample() -> Element {
+        let password = use_signal(String::new);
+
+        rsx! {
+            div { class: "flex flex-col gap-2",
+                Label { for_id: Some("new-password".to_string()), "New password" }
+                PasswordInput {
+                    id: Some("new-password".to_string()),
+                    value: password,
+                    autocomplete: "new-password".to_string(),
+                    show_strength: true,
+                    requirements: vec![
+                        PasswordRequirement::min_length(12),
+                        PasswordRequirement::uppercase(),
+                        PasswordRequirement::digit(),
+                        PasswordRequirement::symbol(),
+                    ],
+                    full_width: true,
+                }
+            }
+        }
+    }
+    // ANCHOR_END: strength
+}
+
+pub mod custom_score {
+    // ANCHOR: custom_score
+    use dioxus::prelude::*;
+    use lumen_blocks::components::label::Label;
+    use lumen_blocks::components::password_input::PasswordInput;
+
+    #[component]
+    pub fn CustomScorePasswordInputExample() -> Element {
+        rsx! {
+            div { class: "flex flex-col gap-2",
+                Label { for_id: Some("passphrase".to_string()), "Passphrase" }
+                PasswordInput {
+                    id: Some("passphrase".to_string()),
+                    autocomplete: "new-password".to_string(),
+                    show_strength: true,
+                    // Score passphrases by their number of words
+                    score: move |passphrase: String| {
+                        passphrase.split_whitespace().count().min(4) as u8
+                    },
+                    placeholder: "correct horse battery staple".to_string(),
+                    full_width: true,
+                }
+            }
+        }
+    }
+    // ANCHOR_END: custom_score
+}
@@ -0,0 +1,112 @@
+#![allow(non_snake_case)]
+
+pub use basic::BasicRatingExample;
+pub use custom_icon::CustomIconRatingExample;
+pub use half_steps::HalfStepRatingExample;
+pub use read_only::ReadOnlyRatingExample;
+
+pub mod basic {
+    // ANCHOR: basic
+    use dioxus::prelude::*;
+    use lumen_blocks::components::rating::Rating;
+
+    #[component]
+    pub fn BasicRatingExample() -> Element {
+        let rating = use_signal(|| 3.0);
+        let current = rating();
+
+        rsx! {
+            div { class: "flex items-center gap-3",
+                Rating {
+                    value: rating,
+                    aria_label: Some("Product rating".to_string()),
+                }
+                span { class: "text-sm text-muted-foreground", "{current} / 5" }
+            }
+        }
+    }
+    // ANCHOR_END: basic
+}
+
+pub mod half_steps {
+    // ANCHOR: half_steps
+    use dioxus::prelude::*;
+    use lumen_blocks::components::rating::{Rating, RatingSize};
+
+    #[component]
+    pub fn HalfStepRatingExample() -> Element {
+        let rating = use_signal(|| 2.5);
+
+        rsx! {
+            div { class: "flex flex-col gap-3",
+                Rating {
+                    value: rating,
+                    allow_half: true,
+                    size: RatingSize::Small,
+                    aria_label: Some("Small rating".to_string()),
+                }
+                Rating {
+                    value: rating,
+                    allow_half: true,
+                    aria_label: Some("Medium rating".to_string()),
+                }
+                Rating {
+                    value: rating,
+                    allow_half: true,
+                    size: RatingSize::Large,
+                    aria_label: Some("Large rating".to_string()),
+                }
+            }
+        }
+    }
+    // ANCHOR_END: half_steps
+}
+
+pub mod read_only {
+    // ANCHOR: read_only
+    use dioxus::prelude::*;
+    use lumen_blocks::components::rating::{Rating, RatingSize};
+
+    #[component]
+    pub fn ReadOnlyRatingExample() -> Element {
+        rsx! {
+            div { class: "flex items-center gap-2",
+                Rating {
+                    default_value: 4.3,
+                    read_only: true,
+                    size: RatingSize::Small,
+                    aria_label: Some("Average rating".to_string()),
+                }
+                span { class: "text-sm font-medium", "4.3" }
+                span { class: "text-sm text-muted-foreground", "(128 reviews)" }
+            }
+        }
+    }
+    // ANCHOR_END: read_only
+}
+
+pub mod custom_icon {
+    // ANCHOR: custom_icon
+    use dioxus::prelude::*;
+    use lucide_dioxus::Heart;
+    use lumen_blocks::components::rating::{Rating, RatingIcon};
+
+    #[component]
+    pub fn CustomIconRatingExample() -> Element {
+        rsx! {
+            Rating {
+                default_value: 2.0,
+                count: 3,
+                aria_label: Some("How much did you like it?".to_string()),
+                icon: move |icon: RatingIcon| {
+                    if icon.filled {
+                        rsx! { Heart { size: icon.size, color: "#f43f5e", fill: "#f43f5e" } }
+                    } else {
+                        rsx! { Heart { size: icon.size } }
+                    }
+                },
+            }
+        }
+    }
+    // ANCHOR_END: custom_icon
+}
@@ -1,5 +1,6 @@
 #![allow(non_snake_case)]
 pub use basic::BasicSideSheetExample;
+pub use controlled::ControlledSideSheetExample;
 pub use positions::SideSheetPositionsExample;
 
 pub mod basic {
@@ -155,3 +156,76 @@ pub mod positions {
     }
     // ANCHOR_END: positions
 }
+
+pub mod controlled {
+    // ANCHOR: controlled
+    use dioxus::prelude::*;
+    use lumen_blocks::components::button::{Button, ButtonVariant};
+    use lumen_blocks::components::side_sheet::{
+        SideSheet, SideSheetBody, SideSheetCloseButton, SideSheetContent, SideSheetDescription,
+        SideSheetHeader, SideSheetTitle, SideSheetTrigger,
+    };
+
+    #[component]
+    pub fn ControlledSideSheetExample() -> Element {
+        let mut open = use_signal(|| false);
+        let mut close_count = use_signal(|| 0);
+
+        rsx! {
+            div { class: "flex flex-col gap-4",
+                div { class: "flex flex-wrap gap-4",
+                    SideSheet {
+                        open: Some(open()),
+                        on_open_change: move |new_open: bool| {
+                            if !new_open {
+                                close_count += 1;
+                            }
+                            open.set(new_open);
+                        },
+
+                        SideSheetTrigger {
+                            Button {
+                                variant: ButtonVariant::Primary,
+                                "Open with Trigger"
+                            }
+                        }
+
+                        SideSheetContent {
+                            class: "p-6 flex flex-col h-full",
+
+                            SideSheetCloseButton {}
+
+                            SideSheetHeader {
+                                SideSheetTitle {
+                                    "Controlled Side Sheet"
+                                }
+                                SideSheetDescription {
+                                    "This sheet's open state lives in the parent component. Press Escape to close it."
+                                }
+                            }
+
+                            SideSheetBody {
+                                class: "py-6",
+                                p {
+                                    "Focus returns to the trigger once the sheet closes."
+                                }
+                            }
+                        }
+                    }
+
+                    // Open the sheet programmatically from anywhere
+                    Button {
+                        variant: ButtonVariant::Outline,
+                        on_click: move |_| open.set(true),
+                        "Open Programmatically"
+                    }
+                }
+
+                span { class: "text-xs text-muted-foreground",
+                    "Closed {close_count} times"
+                }
+            }
+        }
+    }
+    // ANCHOR_END: controlled
+}
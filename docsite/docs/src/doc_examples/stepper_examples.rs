@@ -0,0 +1,118 @@
+#![allow(non_snake_case)]
+
+pub use basic::BasicStepperExample;
+pub use non_linear::NonLinearStepperExample;
+pub use vertical::VerticalStepperExample;
+
+pub mod basic {
+    // ANCHOR: basic
+    use dioxus::prelude::*;
+    use lumen_blocks::components::input::Input;
+    use lumen_blocks::components::label::Label;
+    use lumen_blocks::components::stepper::{Step, Stepper};
+
+    #[component]
+    pub fn BasicStepperExample() -> Element {
+        let mut workspace = use_signal(String::new);
+        let mut finished = use_signal(|| false);
+
+        let steps = vec![
+            Step::new("Workspace")
+                .description("Name your workspace")
+                .can_advance(move || !workspace.peek().trim().is_empty())
+                .content(rsx! {
+                    div { class: "flex flex-col gap-2",
+                        Label { for_id: Some("workspace-name".to_string()), "Workspace name" }
+                        Input {
+                            id: Some("workspace-name".to_string()),
+                            value: workspace(),
+                            placeholder: "Acme Inc.".to_string(),
+                            full_width: true,
+                            on_input: move |event: FormEvent| workspace.set(event.value()),
+                        }
+                    }
+                }),
+            Step::new("Invite")
+                .description("Add your team")
+                .optional()
+                .content(rsx! {
+                    p { class: "text-sm text-muted-foreground", "Invite teammates by email, or skip this for now." }
+                }),
+            Step::new("Review").content(rsx! {
+                p { class: "text-sm text-muted-foreground",
+                    if finished() { "All set, your workspace is ready." } else { "Check the details and press Finish." }
+                }
+            }),
+        ];
+
+        rsx! {
+            Stepper {
+                steps: steps,
+                aria_label: Some("Workspace setup".to_string()),
+                on_complete: move |_| finished.set(true),
+            }
+        }
+    }
+    // ANCHOR_END: basic
+}
+
+pub mod vertical {
+    // ANCHOR: vertical
+    use dioxus::prelude::*;
+    use lumen_blocks::components::stepper::{Step, Stepper, StepperOrientation};
+
+    #[component]
+    pub fn VerticalStepperExample() -> Element {
+        let active = use_signal(|| 1usize);
+
+        let steps =
+            vec![
+            Step::new("Order placed").description("March 3, 09:12").content(rsx! {
+                p { class: "text-sm text-muted-foreground", "We received your order." }
+            }),
+            Step::new("Payment").description("Card ending in 4242").content(rsx! {
+                p { class: "text-sm text-muted-foreground", "Confirm the payment to continue." }
+            }),
+            Step::new("Shipping").content(rsx! {
+                p { class: "text-sm text-muted-foreground", "Choose a delivery option." }
+            }),
+        ];
+
+        rsx! {
+            Stepper {
+                steps: steps,
+                active: active,
+                orientation: StepperOrientation::Vertical,
+                aria_label: Some("Checkout".to_string()),
+            }
+        }
+    }
+    // ANCHOR_END: vertical
+}
+
+pub mod non_linear {
+    // ANCHOR: non_linear
+    use dioxus::prelude::*;
+    use lumen_blocks::components::stepper::{Step, Stepper};
+
+    #[component]
+    pub fn NonLinearStepperExample() -> Element {
+        let steps = ["Profile", "Notifications", "Privacy", "Billing"]
+            .into_iter()
+            .map(|title| {
+                Step::new(title).content(rsx! {
+                    p { class: "text-sm text-muted-foreground", "{title} settings" }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        rsx! {
+            Stepper {
+                steps: steps,
+                linear: false,
+                aria_label: Some("Settings".to_string()),
+            }
+        }
+    }
+    // ANCHOR_END: non_linear
+}
@@ -1,6 +1,9 @@
 #![allow(non_snake_case)]
 
 pub use basic::BasicSwitchExample;
+pub use form_submission::SwitchFormSubmissionExample;
+pub use inline_labels::SwitchInlineLabelsExample;
+pub use loading::SwitchLoadingExample;
 pub use sizes::SwitchSizesExample;
 pub use states::SwitchStatesExample;
 pub use with_text::SwitchWithTextExample;
@@ -181,3 +184,114 @@ pub mod with_text {
     }
     // ANCHOR_END: with_text
 }
+
+pub mod inline_labels {
+    // ANCHOR: inline_labels
+    use dioxus::prelude::*;
+    use lucide_dioxus::{Moon, Sun};
+    use lumen_blocks::components::switch::{Switch, SwitchSize};
+
+    #[component]
+    pub fn SwitchInlineLabelsExample() -> Element {
+        let mut notifications = use_signal(|| true);
+        let mut dark_mode = use_signal(|| false);
+
+        rsx! {
+            div { class: "flex flex-col gap-4",
+                // Clicking the label toggles the switch
+                Switch {
+                    checked: notifications,
+                    on_checked_change: move |new_state| notifications.set(new_state),
+                    size: SwitchSize::Large,
+                    on_label: rsx! { "On" },
+                    off_label: rsx! { "Off" },
+                    label: "Email notifications".to_string(),
+                }
+                Switch {
+                    checked: dark_mode,
+                    on_checked_change: move |new_state| dark_mode.set(new_state),
+                    size: SwitchSize::Large,
+                    on_label: rsx! { Moon { size: 12 } },
+                    off_label: rsx! { Sun { size: 12 } },
+                    label: "Dark mode".to_string(),
+                }
+            }
+        }
+    }
+    // ANCHOR_END: inline_labels
+}
+
+pub mod loading {
+    // ANCHOR: loading
+    use dioxus::prelude::*;
+    use dioxus_time::sleep;
+    use lumen_blocks::components::switch::{Switch, SwitchAction};
+    use std::time::Duration;
+
+    #[component]
+    pub fn SwitchLoadingExample() -> Element {
+        let mut enabled = use_signal(|| false);
+        let mut saved = use_signal(|| None::<bool>);
+
+        rsx! {
+            div { class: "flex flex-col gap-2",
+                Switch {
+                    checked: enabled,
+                    on_checked_change_async: SwitchAction::new(move |new_state| async move {
+                        // Simulate saving the flag to a backend
+                        sleep(Duration::from_millis(1500)).await;
+                        saved.set(Some(new_state));
+                    }),
+                    label: "Beta dashboard".to_string(),
+                }
+                span { class: "text-xs text-muted-foreground",
+                    match saved() {
+                        Some(true) => "Saved: the beta dashboard is enabled",
+                        Some(false) => "Saved: the beta dashboard is disabled",
+                        None => "Toggling the switch saves the flag",
+                    }
+                }
+            }
+        }
+    }
+    // ANCHOR_END: loading
+}
+
+pub mod form_submission {
+    // ANCHOR: form_submission
+    use dioxus::prelude::*;
+    use lumen_blocks::components::button::{Button, ButtonVariant};
+    use lumen_blocks::components::switch::Switch;
+
+    #[component]
+    pub fn SwitchFormSubmissionExample() -> Element {
+        let mut newsletter = use_signal(|| false);
+        let mut submitted = use_signal(|| None::<String>);
+
+        rsx! {
+            form {
+                class: "flex flex-col gap-4",
+                onsubmit: move |event: FormEvent| {
+                    event.prevent_default();
+                    let enabled = event.values().contains_key("newsletter");
+                    submitted.set(Some(format!("newsletter: {}", if enabled { "yes" } else { "no" })));
+                },
+
+                Switch {
+                    checked: newsletter,
+                    on_checked_change: move |new_state| newsletter.set(new_state),
+                    name: "newsletter".to_string(),
+                    value: "yes".to_string(),
+                    label: "Subscribe to the newsletter".to_string(),
+                }
+                div {
+                    Button { variant: ButtonVariant::Primary, button_type: "submit".to_string(), "Save" }
+                }
+                if let Some(result) = submitted() {
+                    span { class: "text-xs text-muted-foreground", "Submitted {result}" }
+                }
+            }
+        }
+    }
+    // ANCHOR_END: form_submission
+}
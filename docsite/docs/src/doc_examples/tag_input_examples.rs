@@ -0,0 +1,107 @@
+#![allow(non_snake_case)]
+
+pub use basic::BasicTagInputExample;
+pub use recipients::RecipientsTagInputExample;
+pub use suggestions::TagInputSuggestionsExample;
+
+pub mod basic {
+    // ANCHOR: basic
+    use dioxus::prelude::*;
+    use lumen_blocks::components::label::Label;
+    use lumen_blocks::components::tag_input::TagInput;
+
+    #[component]
+    pub fn BasicTagInputExample() -> Element {
+        let keywords = use_signal(|| vec!["dioxus".to_string(), "rust".to_string()]);
+
+        rsx! {
+            div { class: "flex flex-col gap-2",
+                Label { for_id: Some("keywords".to_string()), "Keywords" }
+                TagInput {
+                    id: Some("keywords".to_string()),
+                    value: keywords,
+                    placeholder: "Add a keyword...".to_string(),
+                    full_width: true,
+                }
+                span { class: "text-xs text-muted-foreground",
+                    "Press Enter or type a comma to add a keyword, and Backspace to remove the last one."
+                }
+            }
+        }
+    }
+    // ANCHOR_END: basic
+}
+
+pub mod recipients {
+    // ANCHOR: recipients
+    use dioxus::prelude::*;
+    use lumen_blocks::components::label::Label;
+    use lumen_blocks::components::tag_input::TagInput;
+    use lumen_blocks::components::validation::email;
+
+    #[component]
+    pub fn RecipientsTagInputExample() -> Element {
+        let mut error = use_signal(|| None::<String>);
+
+        rsx! {
+            div { class: "flex flex-col gap-2",
+                Label { for_id: Some("recipients".to_string()), "Recipients" }
+                TagInput {
+                    id: Some("recipients".to_string()),
+                    placeholder: "name@example.com".to_string(),
+                    max_tags: 5,
+                    delimiters: vec![',', ';', ' '],
+                    validate: move |tag: String| email().check(tag).is_ok(),
+                    on_reject: move |tag: String| error.set(Some(format!("Could not add {tag}"))),
+                    on_value_change: move |_| error.set(None),
+                    full_width: true,
+                }
+                if let Some(error) = error() {
+                    span { class: "text-xs text-destructive", "{error}" }
+                } else {
+                    span { class: "text-xs text-muted-foreground",
+                        "Up to 5 email addresses. Paste a list separated by commas or spaces."
+                    }
+                }
+            }
+        }
+    }
+    // ANCHOR_END: recipients
+}
+
+pub mod suggestions {
+    // ANCHOR: suggestions
+    use dioxus::prelude::*;
+    use lumen_blocks::components::input::InputSize;
+    use lumen_blocks::components::label::Label;
+    use lumen_blocks::components::tag_input::TagInput;
+
+    #[component]
+    pub fn TagInputSuggestionsExample() -> Element {
+        let labels = vec![
+            "bug",
+            "documentation",
+            "enhancement",
+            "good first issue",
+            "help wanted",
+            "question",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect::<Vec<_>>();
+
+        rsx! {
+            div { class: "flex flex-col gap-2",
+                Label { for_id: Some("labels".to_string()), "Labels" }
+                TagInput {
+                    id: Some("labels".to_string()),
+                    suggestions: labels,
+                    size: InputSize::Small,
+                    placeholder: "Filter labels...".to_string(),
+                    full_width: true,
+                }
+            }
+        }
+    }
+    // ANCHOR_END: suggestions
+}
@@ -0,0 +1,165 @@
+#![allow(non_snake_case)]
+
+pub use activity_feed::ActivityFeedExample;
+pub use alternate::AlternateTimelineExample;
+pub use basic::BasicTimelineExample;
+pub use horizontal::HorizontalTimelineExample;
+
+pub mod basic {
+    // ANCHOR: basic
+    use dioxus::prelude::*;
+    use lucide_dioxus::{Check, CreditCard, Package, TriangleAlert};
+    use lumen_blocks::components::timeline::{Timeline, TimelineItem, TimelineVariant};
+
+    #[component]
+    pub fn BasicTimelineExample() -> Element {
+        let items = vec![
+            TimelineItem::new("Order placed")
+                .description("Order #1042 was received.")
+                .timestamp("09:12")
+                .datetime("2025-03-02T09:12")
+                .icon(rsx! { Check {} }),
+            TimelineItem::new("Payment failed")
+                .description("The card was declined, a new payment link was sent.")
+                .timestamp("09:15")
+                .icon(rsx! { TriangleAlert {} })
+                .variant(TimelineVariant::Destructive),
+            TimelineItem::new("Payment received")
+                .timestamp("10:40")
+                .icon(rsx! { CreditCard {} })
+                .variant(TimelineVariant::Success),
+            TimelineItem::new("Shipped")
+                .description("Handed over to the carrier.")
+                .timestamp("16:05")
+                .icon(rsx! { Package {} }),
+        ];
+
+        rsx! {
+            div { class: "w-full max-w-md",
+                Timeline {
+                    items: items,
+                    aria_label: Some("Order history".to_string()),
+                }
+            }
+        }
+    }
+    // ANCHOR_END: basic
+}
+
+pub mod alternate {
+    // ANCHOR: alternate
+    use dioxus::prelude::*;
+    use lumen_blocks::components::timeline::{Timeline, TimelineAlign, TimelineItem};
+
+    #[component]
+    pub fn AlternateTimelineExample() -> Element {
+        let items = vec![
+            TimelineItem::new("Project kickoff")
+                .description("Goals and scope agreed with the team.")
+                .timestamp("January"),
+            TimelineItem::new("First prototype")
+                .description("Core flows clickable end to end.")
+                .timestamp("March"),
+            TimelineItem::new("Public beta")
+                .description("Opened to the waiting list.")
+                .timestamp("June"),
+            TimelineItem::new("1.0 release").timestamp("September"),
+        ];
+
+        rsx! {
+            div { class: "w-full max-w-xl",
+                Timeline {
+                    items: items,
+                    align: TimelineAlign::Alternate,
+                    aria_label: Some("Roadmap".to_string()),
+                }
+            }
+        }
+    }
+    // ANCHOR_END: alternate
+}
+
+pub mod horizontal {
+    // ANCHOR: horizontal
+    use dioxus::prelude::*;
+    use lumen_blocks::components::timeline::{
+        Timeline, TimelineAlign, TimelineItem, TimelineOrientation,
+    };
+
+    #[component]
+    pub fn HorizontalTimelineExample() -> Element {
+        let items = vec![
+            TimelineItem::new("Founded").timestamp("2019"),
+            TimelineItem::new("Seed round").timestamp("2020"),
+            TimelineItem::new("First customer").timestamp("2021"),
+            TimelineItem::new("100 employees").timestamp("2023"),
+            TimelineItem::new("New office").timestamp("2024"),
+        ];
+
+        rsx! {
+            div { class: "w-full space-y-8",
+                Timeline {
+                    items: items.clone(),
+                    orientation: TimelineOrientation::Horizontal,
+                    aria_label: Some("Company history".to_string()),
+                }
+                Timeline {
+                    items: items,
+                    orientation: TimelineOrientation::Horizontal,
+                    align: TimelineAlign::Alternate,
+                    aria_label: Some("Company history, alternating".to_string()),
+                }
+            }
+        }
+    }
+    // ANCHOR_END: horizontal
+}
+
+pub mod activity_feed {
+    // ANCHOR: activity_feed
+    use dioxus::prelude::*;
+    use lumen_blocks::components::timeline::{Activity, ActivityFeed, format_day};
+
+    #[component]
+    pub fn ActivityFeedExample() -> Element {
+        let activities = vec![
+            Activity::new("Ada Lovelace", "commented on", "2025-03-02")
+                .target("Release checklist")
+                .time("14:30")
+                .content(rsx! {
+                    blockquote { class: "mt-2 rounded-md border border-border bg-muted/50 px-3 py-2 text-sm",
+                        "Docs are ready, only the changelog is left."
+                    }
+                }),
+            Activity::new("Grace Hopper", "merged", "2025-03-02")
+                .target("#128 Fix date parsing")
+                .time("11:02"),
+            Activity::new("Alan Turing", "opened", "2025-03-01")
+                .target("#131 Flaky upload test")
+                .time("17:45"),
+            Activity::new("Ada Lovelace", "joined the project", "2025-02-27").time("09:00"),
+        ];
+
+        // The newest day reads "Today" in this example
+        let day_label = move |date: String| {
+            if date == "2025-03-02" {
+                "Today".to_string()
+            } else if date == "2025-03-01" {
+                "Yesterday".to_string()
+            } else {
+                format_day(&date)
+            }
+        };
+
+        rsx! {
+            div { class: "w-full max-w-md",
+                ActivityFeed {
+                    activities: activities,
+                    day_label: day_label,
+                    aria_label: Some("Recent activity".to_string()),
+                }
+            }
+        }
+    }
+    // ANCHOR_END: activity_feed
+}
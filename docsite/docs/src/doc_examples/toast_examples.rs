@@ -1,9 +1,14 @@
 #![allow(non_snake_case)]
 
+pub use actions::ToastActionsExample;
 pub use advanced::AdvancedToastExample;
 pub use basic::BasicToastExample;
+pub use dedupe::ToastDedupeExample;
 pub use descriptions::ToastWithDescriptionsExample;
 pub use durations::CustomDurationToastExample;
+pub use notification_center::NotificationCenterExample;
+pub use positions::ToastPositionsExample;
+pub use promise::PromiseToastExample;
 pub use setup::ToastProviderSetupExample;
 
 pub mod setup {
@@ -260,7 +265,7 @@ pub mod advanced {
                             ToastOptions {
                                 description: Some("This toast was created using the show() method directly.".to_string()),
                                 duration: Some(Duration::from_secs(6)),
-                                permanent: false,
+                                ..Default::default()
                             }
                         );
                     },
@@ -323,6 +328,308 @@ pub mod advanced {
     // ANCHOR_END: advanced
 }
 
+pub mod actions {
+    // ANCHOR: actions
+    use dioxus::prelude::*;
+    use lumen_blocks::components::button::{Button, ButtonVariant};
+    use lumen_blocks::components::toast::{use_toast, ToastAction, ToastOptions};
+
+    #[component]
+    pub fn ToastActionsExample() -> Element {
+        let toasts = use_toast();
+        let mut archived = use_signal(|| 0);
+
+        rsx! {
+            div { class: "space-y-4",
+                Button {
+                    variant: ButtonVariant::Primary,
+                    full_width: true,
+                    on_click: move |_| {
+                        archived += 1;
+                        toasts.info(
+                            "Message archived".to_string(),
+                            Some(ToastOptions {
+                                actions: vec![ToastAction::new("Undo".to_string(), move |_| {
+                                    archived -= 1;
+                                })],
+                                ..Default::default()
+                            })
+                        );
+                    },
+                    "Archive Message"
+                }
+
+                p { class: "text-sm text-muted-foreground text-center",
+                    "Archived messages: {archived}"
+                }
+            }
+        }
+    }
+    // ANCHOR_END: actions
+}
+
+pub mod promise {
+    // ANCHOR: promise
+    use dioxus::prelude::*;
+    use dioxus_time::sleep;
+    use lumen_blocks::components::button::{Button, ButtonVariant};
+    use lumen_blocks::components::toast::{use_toast, ToastOptions, ToastType};
+    use std::time::Duration;
+
+    #[component]
+    pub fn PromiseToastExample() -> Element {
+        let toasts = use_toast();
+
+        rsx! {
+            div { class: "space-y-4",
+                Button {
+                    variant: ButtonVariant::Primary,
+                    full_width: true,
+                    on_click: move |_| {
+                        // Shows a loading toast, then updates it once the future resolves
+                        toasts.promise(
+                            async {
+                                sleep(Duration::from_secs(2)).await;
+                                Ok::<usize, String>(42)
+                            },
+                            "Saving changes...".to_string(),
+                            |count| format!("Saved {count} records"),
+                            |err| format!("Could not save: {err}"),
+                        );
+                    },
+                    "Save with Promise Toast"
+                }
+
+                Button {
+                    variant: ButtonVariant::Outline,
+                    full_width: true,
+                    on_click: move |_| {
+                        // Update a toast in place using the id returned by show()
+                        let id = toasts.show(
+                            "Uploading file...".to_string(),
+                            ToastType::Loading,
+                            ToastOptions {
+                                permanent: true,
+                                ..Default::default()
+                            },
+                        );
+
+                        spawn(async move {
+                            sleep(Duration::from_secs(2)).await;
+                            toasts.update(
+                                id,
+                                "Upload complete".to_string(),
+                                ToastType::Success,
+                                ToastOptions {
+                                    description: Some("report.pdf was uploaded.".to_string()),
+                                    ..Default::default()
+                                },
+                            );
+                        });
+                    },
+                    "Update Toast in Place"
+                }
+            }
+        }
+    }
+    // ANCHOR_END: promise
+}
+
+pub mod positions {
+    // ANCHOR: positions
+    use dioxus::prelude::*;
+    use lumen_blocks::components::button::{Button, ButtonVariant};
+    use lumen_blocks::components::toast::{use_toast, ToastOptions, ToastPosition, ToastProvider};
+
+    #[component]
+    pub fn ToastPositionsExample() -> Element {
+        let position = use_signal(|| ToastPosition::BottomRight);
+
+        rsx! {
+            // Stacked toasts collapse into a pile that expands on hover
+            ToastProvider {
+                position: position(),
+                stacked: true,
+
+                PositionButtons { position }
+            }
+        }
+    }
+
+    #[component]
+    fn PositionButtons(position: Signal<ToastPosition>) -> Element {
+        let toasts = use_toast();
+
+        let positions = [
+            ("Top Left", ToastPosition::TopLeft),
+            ("Top Center", ToastPosition::TopCenter),
+            ("Top Right", ToastPosition::TopRight),
+            ("Bottom Left", ToastPosition::BottomLeft),
+            ("Bottom Center", ToastPosition::BottomCenter),
+            ("Bottom Right", ToastPosition::BottomRight),
+        ];
+
+        rsx! {
+            div { class: "grid grid-cols-3 gap-2",
+                for (label, new_position) in positions {
+                    Button {
+                        variant: if position() == new_position { ButtonVariant::Primary } else { ButtonVariant::Outline },
+                        on_click: move |_| {
+                            position.set(new_position);
+                            toasts.info(
+                                format!("{label} toast"),
+                                Some(ToastOptions {
+                                    description: Some("Swipe towards the edge to dismiss.".to_string()),
+                                    ..Default::default()
+                                })
+                            );
+                        },
+                        "{label}"
+                    }
+                }
+            }
+        }
+    }
+    // ANCHOR_END: positions
+}
+
+pub mod dedupe {
+    // ANCHOR: dedupe
+    use dioxus::prelude::*;
+    use lumen_blocks::components::button::{Button, ButtonVariant};
+    use lumen_blocks::components::toast::{
+        use_toast, ToastOptions, ToastPriority, ToastProvider, ToastRateLimit,
+    };
+    use std::time::Duration;
+
+    #[component]
+    pub fn ToastDedupeExample() -> Element {
+        rsx! {
+            // At most 2 toasts of each type every 5 seconds
+            ToastProvider {
+                max_toasts: 4,
+                rate_limit: Some(ToastRateLimit { max: 2, per: Duration::from_secs(5) }),
+
+                DedupeButtons {}
+            }
+        }
+    }
+
+    #[component]
+    fn DedupeButtons() -> Element {
+        let toasts = use_toast();
+
+        rsx! {
+            div { class: "space-y-4",
+                Button {
+                    variant: ButtonVariant::Destructive,
+                    full_width: true,
+                    on_click: move |_| {
+                        // Repeated errors refresh one toast and increment its count badge
+                        toasts.error(
+                            "Sync failed".to_string(),
+                            Some(ToastOptions {
+                                description: Some("Retrying in the background...".to_string()),
+                                dedupe_key: Some("background-sync".to_string()),
+                                ..Default::default()
+                            })
+                        );
+                    },
+                    "Fail Background Sync"
+                }
+
+                Button {
+                    variant: ButtonVariant::Primary,
+                    full_width: true,
+                    on_click: move |_| {
+                        // High priority toasts are evicted last when the queue is full
+                        toasts.warning(
+                            "Session expires soon".to_string(),
+                            Some(ToastOptions {
+                                priority: ToastPriority::High,
+                                ..Default::default()
+                            })
+                        );
+                    },
+                    "High Priority Warning"
+                }
+
+                Button {
+                    variant: ButtonVariant::Outline,
+                    full_width: true,
+                    on_click: move |_| {
+                        // Past the rate limit, info toasts are folded into the newest one
+                        toasts.info(
+                            "New message".to_string(),
+                            Some(ToastOptions {
+                                priority: ToastPriority::Low,
+                                ..Default::default()
+                            })
+                        );
+                    },
+                    "Low Priority Info"
+                }
+            }
+        }
+    }
+    // ANCHOR_END: dedupe
+}
+
+pub mod notification_center {
+    // ANCHOR: notification_center
+    use dioxus::prelude::*;
+    use lumen_blocks::components::button::{Button, ButtonVariant};
+    use lumen_blocks::components::notification_center::NotificationCenter;
+    use lumen_blocks::components::toast::{use_toast, ToastOptions, ToastProvider};
+
+    #[component]
+    pub fn NotificationCenterExample() -> Element {
+        rsx! {
+            // Dismissed toasts are kept in the provider's history
+            ToastProvider {
+                history: true,
+                max_history: 20,
+
+                NotificationCenterDemo {}
+            }
+        }
+    }
+
+    #[component]
+    fn NotificationCenterDemo() -> Element {
+        let toasts = use_toast();
+
+        rsx! {
+            div { class: "flex items-center gap-4",
+                Button {
+                    variant: ButtonVariant::Primary,
+                    on_click: move |_| {
+                        toasts.success(
+                            "Report exported".to_string(),
+                            Some(ToastOptions {
+                                description: Some("monthly-report.pdf is ready to download.".to_string()),
+                                ..Default::default()
+                            })
+                        );
+                    },
+                    "Export Report"
+                }
+
+                Button {
+                    variant: ButtonVariant::Outline,
+                    on_click: move |_| {
+                        toasts.info("New comment on your post".to_string(), None);
+                    },
+                    "Receive Comment"
+                }
+
+                NotificationCenter {}
+            }
+        }
+    }
+    // ANCHOR_END: notification_center
+}
+
 // This is for backward compatibility with any existing examples
 pub mod example {
     use dioxus::prelude::*;
@@ -0,0 +1,233 @@
+#![allow(non_snake_case)]
+
+pub use basic::BasicTreeViewExample;
+pub use checkboxes::CheckboxTreeViewExample;
+pub use context_menu::ContextMenuTreeViewExample;
+pub use lazy_loading::LazyTreeViewExample;
+
+pub mod basic {
+    // ANCHOR: basic
+    use dioxus::prelude::*;
+    use lucide_dioxus::{File, Folder};
+    use lumen_blocks::components::tree_view::{TreeNode, TreeView};
+    use std::collections::HashSet;
+
+    fn folder(id: &str, label: &str, children: Vec<TreeNode>) -> TreeNode {
+        TreeNode::new(id, label)
+            .icon(rsx! { Folder { size: 16 } })
+            .children(children)
+    }
+
+    fn file(id: &str, label: &str) -> TreeNode {
+        TreeNode::new(id, label).icon(rsx! { File { size: 16 } })
+    }
+
+    #[component]
+    pub fn BasicTreeViewExample() -> Element {
+        let selected = use_signal(HashSet::<String>::new);
+        let current = selected().into_iter().next().unwrap_or_default();
+
+        let nodes = vec![
+            folder(
+                "src",
+                "src",
+                vec![
+                    folder(
+                        "src/components",
+                        "components",
+                        vec![
+                            file("src/components/button.rs", "button.rs"),
+                            file("src/components/checkbox.rs", "checkbox.rs"),
+                            file("src/components/tree_view.rs", "tree_view.rs"),
+                        ],
+                    ),
+                    file("src/lib.rs", "lib.rs"),
+                    file("src/main.rs", "main.rs"),
+                ],
+            ),
+            file("Cargo.toml", "Cargo.toml"),
+            file("README.md", "README.md"),
+        ];
+
+        rsx! {
+            div { class: "w-72 space-y-3",
+                TreeView {
+                    nodes: nodes,
+                    selected: selected,
+                    default_expanded: vec!["src".to_string()],
+                    height: 240.0,
+                    aria_label: Some("Project files".to_string()),
+                }
+                p { class: "text-sm text-muted-foreground",
+                    if current.is_empty() { "Nothing selected" } else { "Selected: {current}" }
+                }
+            }
+        }
+    }
+    // ANCHOR_END: basic
+}
+
+pub mod checkboxes {
+    // ANCHOR: checkboxes
+    use dioxus::prelude::*;
+    use lumen_blocks::components::tree_view::{TreeNode, TreeSelectionMode, TreeView};
+    use std::collections::HashSet;
+
+    #[component]
+    pub fn CheckboxTreeViewExample() -> Element {
+        let checked = use_signal(|| HashSet::from(["posts.read".to_string()]));
+        let mut granted = checked().into_iter().collect::<Vec<_>>();
+        granted.sort();
+        let summary = granted.join(", ");
+
+        let nodes = vec![
+            TreeNode::new("posts", "Posts").children(vec![
+                TreeNode::new("posts.read", "Read"),
+                TreeNode::new("posts.write", "Write"),
+                TreeNode::new("posts.delete", "Delete"),
+            ]),
+            TreeNode::new("users", "Users").children(vec![
+                TreeNode::new("users.read", "Read"),
+                TreeNode::new("users.invite", "Invite"),
+                TreeNode::new("users.remove", "Remove").disabled(),
+            ]),
+        ];
+
+        rsx! {
+            div { class: "w-72 space-y-3",
+                TreeView {
+                    nodes: nodes,
+                    checkable: true,
+                    checked: checked,
+                    selection_mode: TreeSelectionMode::None,
+                    default_expanded: vec!["posts".to_string(), "users".to_string()],
+                    height: 256.0,
+                    aria_label: Some("Permissions".to_string()),
+                }
+                p { class: "text-sm text-muted-foreground", "Granted: {summary}" }
+            }
+        }
+    }
+    // ANCHOR_END: checkboxes
+}
+
+pub mod lazy_loading {
+    // ANCHOR: lazy_loading
+    use dioxus::prelude::*;
+    use dioxus_time::sleep;
+    use lumen_blocks::components::tree_view::{TreeLoader, TreeNode, TreeView};
+    use std::time::Duration;
+
+    // Stands in for a request listing the contents of a folder
+    async fn list_folder(id: String) -> Vec<TreeNode> {
+        sleep(Duration::from_millis(800)).await;
+        (1..=3)
+            .map(|index| {
+                let child = format!("{}/folder-{}", id, index);
+                TreeNode::new(child.clone(), format!("Folder {}", index)).lazy()
+            })
+            .chain(std::iter::once(TreeNode::new(
+                format!("{}/notes.txt", id),
+                "notes.txt",
+            )))
+            .collect()
+    }
+
+    #[component]
+    pub fn LazyTreeViewExample() -> Element {
+        let nodes = vec![
+            TreeNode::new("drive", "My Drive").lazy(),
+            TreeNode::new("shared", "Shared with me").lazy(),
+        ];
+
+        rsx! {
+            div { class: "w-72",
+                TreeView {
+                    nodes: nodes,
+                    load_children: TreeLoader::new(list_folder),
+                    height: 256.0,
+                    aria_label: Some("Drive".to_string()),
+                }
+            }
+        }
+    }
+    // ANCHOR_END: lazy_loading
+}
+
+pub mod context_menu {
+    // ANCHOR: context_menu
+    use dioxus::prelude::*;
+    use lucide_dioxus::{CopyPlus, Pencil, Trash};
+    use lumen_blocks::components::context_menu::{
+        ContextMenuContent, ContextMenuItem, ContextMenuSeparator,
+    };
+    use lumen_blocks::components::tree_view::{TreeNode, TreeSelectionMode, TreeView};
+
+    #[component]
+    pub fn ContextMenuTreeViewExample() -> Element {
+        let mut last_action = use_signal(String::new);
+
+        // 100 groups of 100 items: only the rows in view are rendered
+        let nodes = (1..=100)
+            .map(|group| {
+                let children = (1..=100)
+                    .map(|item| {
+                        TreeNode::new(
+                            format!("{}-{}", group, item),
+                            format!("Item {}.{}", group, item),
+                        )
+                    })
+                    .collect();
+                TreeNode::new(group.to_string(), format!("Group {}", group)).children(children)
+            })
+            .collect::<Vec<_>>();
+
+        let menu = move |id: String| {
+            rsx! {
+                ContextMenuContent { width: "w-44",
+                    ContextMenuItem {
+                        value: format!("Rename {}", id),
+                        index: 0,
+                        icon: rsx! { Pencil { class: "h-4 w-4" } },
+                        on_select: move |value| last_action.set(value),
+                        "Rename"
+                    }
+                    ContextMenuItem {
+                        value: format!("Duplicate {}", id),
+                        index: 1,
+                        icon: rsx! { CopyPlus { class: "h-4 w-4" } },
+                        on_select: move |value| last_action.set(value),
+                        "Duplicate"
+                    }
+                    ContextMenuSeparator {}
+                    ContextMenuItem {
+                        value: format!("Delete {}", id),
+                        index: 2,
+                        destructive: true,
+                        icon: rsx! { Trash { class: "h-4 w-4" } },
+                        on_select: move |value| last_action.set(value),
+                        "Delete"
+                    }
+                }
+            }
+        };
+
+        rsx! {
+            div { class: "w-72 space-y-3",
+                TreeView {
+                    nodes: nodes,
+                    selection_mode: TreeSelectionMode::Multiple,
+                    default_expanded: vec!["1".to_string(), "2".to_string()],
+                    context_menu: menu,
+                    height: 320.0,
+                    row_height: 28.0,
+                    aria_label: Some("Large tree".to_string()),
+                }
+                if !last_action().is_empty() {
+                    p { class: "text-sm text-muted-foreground", "Last action: {last_action}" }
+                }
+            }
+        }
+    }
+    // ANCHOR_END: context_menu
+}
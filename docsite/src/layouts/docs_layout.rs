@@ -3,6 +3,7 @@ use dioxus::document;
 use dioxus::prelude::*;
 use dioxus_router::prelude::{use_route, Link, Outlet};
 use docs::docs::router_01::{BookRoute, LAZY_BOOK};
+use lumen_blocks::components::toast::ToastProvider;
 use mdbook_shared::SummaryItem;
 
 // Signal to control mobile sidebar visibility
@@ -263,8 +264,11 @@ fn DocsContent() -> Element {
                 [&_strong]:font-bold
             ",
 
-            // This is where the current route's content will be rendered
-            Outlet::<Route> {}
+            // Toast examples need a provider to show their toasts in
+            ToastProvider {
+                // This is where the current route's content will be rendered
+                Outlet::<Route> {}
+            }
         }
     }
 }